@@ -60,7 +60,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     }
 
     println!("\n4. Testing with huawei-dongle-api client...");
-    let config = Config::for_url(&format!("http://{}", device_ip))?;
+    let config = Config::for_url(format!("http://{}", device_ip))?;
     let client = Client::new(config)?;
     
     match client.device().information().await {
@@ -1,12 +1,58 @@
 //! Authentication API endpoints
 
 use crate::{
-    auth::PasswordEncoder,
+    auth::{PasswordEncoder, ScramLogin},
     client::Client,
+    encoding::read_text,
     error::{Error, Result},
     models::{auth::*, common::Response},
 };
-use tracing::{debug, trace};
+use std::sync::Arc;
+use tokio::runtime::Handle;
+use tracing::{debug, trace, warn};
+
+/// Login session that logs out when dropped, returned by
+/// [`AuthApi::login_scoped`]
+///
+/// `Drop` cannot await, so dropping the guard spawns a detached logout task
+/// on the runtime that was current at login. That task is not guaranteed to
+/// run, for example when the runtime shuts down right after the drop. Call
+/// [`logout`](Self::logout) for deterministic cleanup.
+#[derive(Debug)]
+pub struct ScopedLogin {
+    client: Arc<Client>,
+    runtime: Handle,
+    logged_out: bool,
+}
+
+impl ScopedLogin {
+    /// Client sharing the logged-in session
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Log out now and disarm the guard
+    pub async fn logout(mut self) -> Result<()> {
+        self.logged_out = true;
+        self.client.auth().logout().await
+    }
+}
+
+impl Drop for ScopedLogin {
+    fn drop(&mut self) {
+        if self.logged_out {
+            return;
+        }
+
+        debug!("Logging out on drop of scoped login");
+        let client = self.client.clone();
+        self.runtime.spawn(async move {
+            if let Err(e) = client.auth().logout().await {
+                warn!("Logout on drop failed: {}", e);
+            }
+        });
+    }
+}
 
 /// Authentication API for login/logout operations
 pub struct AuthApi<'a> {
@@ -24,12 +70,12 @@ impl<'a> AuthApi<'a> {
         debug!("Fetching login state");
 
         let response = self.client.get("/api/user/state-login").await?;
-        let text = response.text().await?;
+        let text = read_text(response).await?;
 
         trace!("Login state response: {}", text);
 
         let state: LoginState = serde_xml_rs::from_str(&text)
-            .map_err(|e| Error::generic(format!("Failed to parse login state: {}", e)))?;
+            .map_err(|e| Error::parse("/api/user/state-login", e, &text))?;
 
         debug!(
             "Login state: {} (password_type: {})",
@@ -42,6 +88,11 @@ impl<'a> AuthApi<'a> {
 
     /// This endpoint requires a valid CSRF token but not authentication.
     /// Password will be automatically encoded based on the device requirements.
+    /// A concurrent login that makes the device answer `108003` (already
+    /// logged in) counts as success, since the session is then authenticated.
+    /// Devices still on their default password may refuse with
+    /// [`Error::PasswordChangeRequired`]; see [`LoginState::must_change_password`]
+    /// and [`change_password`](Self::change_password).
     pub async fn login(&self, username: &str, password: &str) -> Result<()> {
         debug!("Attempting login for user: {}", username);
 
@@ -59,6 +110,25 @@ impl<'a> AuthApi<'a> {
             )));
         }
 
+        if self.client.config().prefer_scram {
+            match self.scram_login(username, password).await {
+                Ok(()) => {
+                    self.client.session().mark_authenticated(username).await;
+                    debug!("SCRAM login successful for user: {}", username);
+                    return Ok(());
+                }
+                Err(Error::AlreadyLoggedIn) => {
+                    self.client.session().mark_authenticated(username).await;
+                    debug!("Device reports user {} already logged in", username);
+                    return Ok(());
+                }
+                Err(Error::Api { code: 404, .. }) => {
+                    debug!("SCRAM challenge endpoint not available, falling back to simple login");
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
         let encoded_password = PasswordEncoder::encode_password(password, &login_state);
         
         let request = LoginRequest::new(
@@ -70,27 +140,29 @@ impl<'a> AuthApi<'a> {
         let xml = serde_xml_rs::to_string(&request)
             .map_err(|e| Error::generic(format!("Failed to serialize login request: {}", e)))?;
 
-        trace!("Login request XML: {}", xml);
-
         let response = self.client.post_xml("/api/user/login", &xml).await?;
-        let text = response.text().await?;
+        let text = read_text(response).await?;
 
         trace!("Login response: {}", text);
 
-        let result: Response = serde_xml_rs::from_str(&text)
-            .map_err(|e| Error::generic(format!("Failed to parse login response: {}", e)))?;
+        match self.client.check_xml_for_errors(&text).await {
+            Ok(()) => {}
+            Err(Error::AlreadyLoggedIn) => {
+                self.client.session().mark_authenticated(username).await;
+                debug!("Device reports user {} already logged in", username);
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        }
+
+        let result = Response::parse(&text)
+            .map_err(|e| Error::parse("/api/user/login", e, &text))?;
 
         if !result.is_success() {
-            let error_code = result.error_code().unwrap_or(-1);
-            let error_message = match error_code {
-                108001 => "Username wrong".to_string(),
-                108002 => "Password wrong".to_string(),
-                108006 => "Username or password wrong".to_string(),
-                108007 => "Too many login attempts".to_string(),
-                _ => result.error_message().unwrap_or("Login failed").to_string(),
-            };
-
-            return Err(Error::api(error_code, error_message));
+            return Err(Error::api(
+                result.error_code().unwrap_or(-1),
+                result.error_message().unwrap_or("Login failed").to_string(),
+            ));
         }
 
         self.client.session().mark_authenticated(username).await;
@@ -99,6 +171,119 @@ impl<'a> AuthApi<'a> {
         Ok(())
     }
 
+    /// Log in like [`login`](Self::login) and return a guard that logs out
+    /// when dropped. The guard holds a client sharing this client's session.
+    pub async fn login_scoped(&self, username: &str, password: &str) -> Result<ScopedLogin> {
+        self.login(username, password).await?;
+
+        Ok(ScopedLogin {
+            client: Arc::new(self.client.shared()),
+            runtime: Handle::current(),
+            logged_out: false,
+        })
+    }
+
+    /// This endpoint requires a valid CSRF token.
+    /// It may be called without a full login while the device reports that a
+    /// password change is required (`115002`).
+    /// Passwords are encoded the same way as for login. On success the session is
+    /// invalidated, so the caller must log in again with the new password.
+    pub async fn change_password(&self, current: &str, new: &str) -> Result<()> {
+        debug!("Changing password");
+
+        let login_state = self.state_login().await?;
+        let request = password_change_request(current, new, &login_state);
+
+        let xml = serde_xml_rs::to_string(&request).map_err(|e| {
+            Error::generic(format!("Failed to serialize password change request: {}", e))
+        })?;
+
+        let response = self.client.post_xml("/api/user/password", &xml).await?;
+        let text = read_text(response).await?;
+
+        trace!("Password change response: {}", text);
+
+        self.client.check_xml_for_errors(&text).await?;
+
+        let result = Response::parse(&text)
+            .map_err(|e| Error::parse("/api/user/password", e, &text))?;
+
+        if !result.is_success() {
+            return Err(Error::api(
+                result.error_code().unwrap_or(-1),
+                result
+                    .error_message()
+                    .unwrap_or("Password change failed")
+                    .to_string(),
+            ));
+        }
+
+        self.client.update_stored_password(new);
+        self.client.session().invalidate_session().await;
+
+        debug!("Password changed successfully");
+        Ok(())
+    }
+
+    /// Perform the SCRAM challenge/authentication exchange and verify the server signature.
+    async fn scram_login(&self, username: &str, password: &str) -> Result<()> {
+        debug!("Attempting SCRAM login for user: {}", username);
+
+        let scram = ScramLogin::new()?;
+        let request = ChallengeLoginRequest::new(username, scram.client_nonce());
+        let xml = serde_xml_rs::to_string(&request)
+            .map_err(|e| Error::generic(format!("Failed to serialize challenge request: {}", e)))?;
+
+        let response = self.client.post_xml("/api/user/challenge_login", &xml).await?;
+        let text = read_text(response).await?;
+
+        trace!("Challenge login response: {}", text);
+
+        self.client.check_xml_for_errors(&text).await?;
+
+        let challenge: ChallengeLoginResponse = serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/user/challenge_login", e, &text))?;
+
+        if !challenge.server_nonce.starts_with(scram.client_nonce()) {
+            return Err(Error::authentication("SCRAM server nonce does not extend client nonce"));
+        }
+
+        let salt = hex::decode(challenge.salt.trim())
+            .map_err(|e| Error::generic(format!("Invalid SCRAM salt: {}", e)))?;
+        let iterations: u32 = challenge
+            .iterations
+            .trim()
+            .parse()
+            .map_err(|e| Error::generic(format!("Invalid SCRAM iteration count: {}", e)))?;
+
+        let proof = scram.proof(password, &salt, iterations, &challenge.server_nonce);
+
+        let request = AuthenticationLoginRequest {
+            client_proof: proof.client_proof.clone(),
+            final_nonce: challenge.server_nonce.clone(),
+        };
+        let xml = serde_xml_rs::to_string(&request).map_err(|e| {
+            Error::generic(format!("Failed to serialize authentication request: {}", e))
+        })?;
+
+        let response = self.client.post_xml("/api/user/authentication_login", &xml).await?;
+        let text = read_text(response).await?;
+
+        trace!("Authentication login response: {}", text);
+
+        self.client.check_xml_for_errors(&text).await?;
+
+        let result: AuthenticationLoginResponse = serde_xml_rs::from_str(&text).map_err(|e| {
+            Error::parse("/api/user/authentication_login", e, &text)
+        })?;
+
+        if !proof.verify_server_signature(&result.server_signature) {
+            return Err(Error::authentication("SCRAM server signature mismatch"));
+        }
+
+        Ok(())
+    }
+
     /// This endpoint requires authentication and a valid CSRF token.
     pub async fn logout(&self) -> Result<()> {
         debug!("Attempting logout");
@@ -108,12 +293,12 @@ impl<'a> AuthApi<'a> {
             .map_err(|e| Error::generic(format!("Failed to serialize logout request: {}", e)))?;
 
         let response = self.client.post_xml("/api/user/logout", &xml).await?;
-        let text = response.text().await?;
+        let text = read_text(response).await?;
 
         trace!("Logout response: {}", text);
 
-        let result: Response = serde_xml_rs::from_str(&text)
-            .map_err(|e| Error::generic(format!("Failed to parse logout response: {}", e)))?;
+        let result = Response::parse(&text)
+            .map_err(|e| Error::parse("/api/user/logout", e, &text))?;
 
         if !result.is_success() {
             return Err(Error::api(
@@ -132,9 +317,18 @@ impl<'a> AuthApi<'a> {
     }
 }
 
+/// Build a password change request encoded for the device's password type
+fn password_change_request(current: &str, new: &str, login_state: &LoginState) -> PasswordChangeRequest {
+    PasswordChangeRequest {
+        current_password: PasswordEncoder::encode_password(current, login_state),
+        new_password: PasswordEncoder::encode_password(new, login_state),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::{login_state_xml, token_mock};
     use crate::config::Config;
 
     #[test]
@@ -169,4 +363,199 @@ mod tests {
         let xml = serde_xml_rs::to_string(&request).unwrap();
         assert!(xml.contains("<Logout>1</Logout>"));
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_scram_falls_back_when_challenge_missing() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+
+        server
+            .mock("GET", "/api/user/state-login")
+            .with_body(login_state_xml("4"))
+            .create_async()
+            .await;
+
+        let challenge = server
+            .mock("POST", "/api/user/challenge_login")
+            .with_status(404)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let login = server
+            .mock("POST", "/api/user/login")
+            .with_body("<response>OK</response>")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let config = Config::builder()
+            .base_url(server.url())
+            .prefer_scram(true)
+            .build()
+            .unwrap();
+        let client = crate::Client::new(config).unwrap();
+
+        client.auth().login("admin", "admin").await.unwrap();
+        assert!(client.session().is_authenticated().await);
+
+        challenge.assert_async().await;
+        login.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_login_already_logged_in_is_success() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+
+        server
+            .mock("GET", "/api/user/state-login")
+            .with_body(login_state_xml("4"))
+            .create_async()
+            .await;
+
+        let login = server
+            .mock("POST", "/api/user/login")
+            .with_body("<error><code>108003</code><message></message></error>")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = crate::Client::for_url(server.url()).unwrap();
+
+        client.auth().login("admin", "admin").await.unwrap();
+        assert!(client.session().is_authenticated().await);
+
+        login.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_login_wrong_password_fails() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+        server
+            .mock("GET", "/api/user/state-login")
+            .with_body(login_state_xml("4"))
+            .create_async()
+            .await;
+        server
+            .mock("POST", "/api/user/login")
+            .with_body("<error><code>108002</code><message></message></error>")
+            .create_async()
+            .await;
+
+        let client = crate::Client::for_url(server.url()).unwrap();
+
+        let result = client.auth().login("admin", "wrong").await;
+        assert!(matches!(result, Err(Error::InvalidPassword)));
+        assert!(!client.session().is_authenticated().await);
+    }
+
+    async fn scoped_login_server() -> (mockito::ServerGuard, mockito::Mock) {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+        server
+            .mock("GET", "/api/user/state-login")
+            .with_body(login_state_xml("4"))
+            .create_async()
+            .await;
+        server
+            .mock("POST", "/api/user/login")
+            .with_body("<response>OK</response>")
+            .create_async()
+            .await;
+        let logout = server
+            .mock("POST", "/api/user/logout")
+            .with_body("<response>OK</response>")
+            .expect(1)
+            .create_async()
+            .await;
+
+        (server, logout)
+    }
+
+    #[tokio::test]
+    async fn test_scoped_login_explicit_logout_clears_session() {
+        let (server, logout) = scoped_login_server().await;
+        let client = crate::Client::for_url(server.url()).unwrap();
+
+        let guard = client.auth().login_scoped("admin", "admin").await.unwrap();
+        assert!(guard.client().session().is_authenticated().await);
+
+        guard.logout().await.unwrap();
+        assert!(!client.session().is_authenticated().await);
+
+        logout.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_scoped_login_logs_out_on_drop() {
+        let (server, logout) = scoped_login_server().await;
+        let client = crate::Client::for_url(server.url()).unwrap();
+
+        let guard = client.auth().login_scoped("admin", "admin").await.unwrap();
+        drop(guard);
+
+        for _ in 0..50 {
+            if !client.session().is_authenticated().await {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+
+        assert!(!client.session().is_authenticated().await);
+        logout.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_login_password_change_required() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+        server
+            .mock("GET", "/api/user/state-login")
+            .with_body(login_state_xml("4"))
+            .create_async()
+            .await;
+        server
+            .mock("POST", "/api/user/login")
+            .with_body("<error><code>115002</code><message></message></error>")
+            .create_async()
+            .await;
+
+        let client = crate::Client::for_url(server.url()).unwrap();
+
+        let result = client.auth().login("admin", "admin").await;
+        assert!(matches!(result, Err(Error::PasswordChangeRequired)));
+        assert!(!client.session().is_authenticated().await);
+    }
+
+    fn login_state_with_type(password_type: &str) -> LoginState {
+        serde_xml_rs::from_str(&login_state_xml(password_type)).unwrap()
+    }
+
+    #[test]
+    fn test_password_change_request_base64() {
+        let request = password_change_request("admin", "n3wPass", &login_state_with_type("0"));
+
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+        assert!(xml.contains("<CurrentPassword>YWRtaW4=</CurrentPassword>"));
+        assert!(xml.contains("<NewPassword>bjN3UGFzcw==</NewPassword>"));
+    }
+
+    #[test]
+    fn test_password_change_request_sha256() {
+        let request = password_change_request("admin", "n3wPass", &login_state_with_type("4"));
+
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+        assert!(xml.contains(
+            "<CurrentPassword>8c6976e5b5410415bde908bd4dee15dfb167a9c873fc4bb8a81f6f2ab448a918</CurrentPassword>"
+        ));
+        assert_eq!(request.new_password.len(), 64);
+        assert_ne!(request.new_password, request.current_password);
+    }
+}
@@ -1,12 +1,103 @@
 //! Device API endpoints
 
 use crate::{
+    circuit,
     client::Client,
-    error::{Error, Result},
-    models::{common::Response, device::*},
+    encoding::read_text,
+    error::{error_codes, Error, Result},
+    models::{common::parse_generic_ok, device::*, enums::{AntennaType, DeviceControlType}},
 };
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
 use tracing::{debug, trace};
 
+const INFORMATION_PATH: &str = "/api/device/information";
+
+/// Endpoint accepting a configuration backup for restore
+const RESTORE_PATH: &str = "/api/device/restore";
+
+/// Backup file written by `/api/device/control` with `Control` 3
+const BACKUP_DOWNLOAD_PATH: &str = "/nvram.bak";
+
+/// Airplane mode switch used by the HiLink web UI
+const FLY_MODE_PATH: &str = "/api/net/flymode";
+
+const UPDATE_STATUS_PATH: &str = "/api/online-update/status";
+
+/// Interval between `/api/device/information` polls while the device reboots
+const REBOOT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Errors expected while the device is down: refused connections,
+/// timeouts, 5xx responses from a web server that is still starting and
+/// the circuit breaker fast-failing requests after those
+fn is_down_error(error: &Error) -> bool {
+    match error {
+        Error::Http(_) => true,
+        Error::Api { code, .. } => (500..600).contains(code),
+        Error::Generic { message } => message == circuit::OPEN_MESSAGE,
+        _ => false,
+    }
+}
+
+/// Fetch device information bypassing the read cache, which would otherwise
+/// keep answering with the pre-reboot response
+async fn poll_information(client: &Client) -> Result<DeviceInformation> {
+    let text = client.get_uncached_text(INFORMATION_PATH).await?;
+    parse_information(&text)
+}
+
+fn parse_information(text: &str) -> Result<DeviceInformation> {
+    trace!("Device information response: {}", text);
+    serde_xml_rs::from_str(text).map_err(|e| Error::parse(INFORMATION_PATH, e, text))
+}
+
+/// Wait for a rebooting device to go down and come back, returning its
+/// information once it answers again
+async fn wait_for_reboot(client: &Client, timeout: Duration, poll: Duration) -> Result<DeviceInformation> {
+    let deadline = Instant::now() + timeout;
+    wait_until_down(client, timeout, poll).await?;
+    wait_until_up(client, deadline.saturating_duration_since(Instant::now()), poll).await
+}
+
+/// Poll until the device stops answering, so that a poll made before the
+/// reboot takes effect is not mistaken for the device being back
+async fn wait_until_down(client: &Client, timeout: Duration, poll: Duration) -> Result<()> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        match poll_information(client).await {
+            Ok(_) => trace!("Device not down yet"),
+            Err(e) if is_down_error(&e) => {
+                debug!("Device is down: {}", e);
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        }
+
+        if Instant::now() >= deadline {
+            return Err(Error::generic("Timed out waiting for device to go down for reboot"));
+        }
+        sleep(poll).await;
+    }
+}
+
+/// Poll until the device answers again, treating transient errors as
+/// "still rebooting"
+async fn wait_until_up(client: &Client, timeout: Duration, poll: Duration) -> Result<DeviceInformation> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        match poll_information(client).await {
+            Ok(info) => return Ok(info),
+            Err(e) if is_down_error(&e) => trace!("Device still rebooting: {}", e),
+            Err(e) => return Err(e),
+        }
+
+        if Instant::now() >= deadline {
+            return Err(Error::generic("Timed out waiting for device to come back after reboot"));
+        }
+        sleep(poll).await;
+    }
+}
+
 /// Device API for device information and control operations
 pub struct DeviceApi<'a> {
     client: &'a Client,
@@ -20,78 +111,301 @@ impl<'a> DeviceApi<'a> {
     pub async fn information(&self) -> Result<DeviceInformation> {
         debug!("Fetching device information");
 
-        let response = self.client.get("/api/device/information").await?;
-        let text = response.text().await?;
+        let text = self.client.get_cached_text(INFORMATION_PATH).await?;
+        parse_information(&text)
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns the SIM identifiers, state and slot. The converged status is
+    /// optional: devices without it leave `sim_slot` unset.
+    pub async fn sim_info(&self) -> Result<SimInfo> {
+        debug!("Fetching SIM information");
 
-        trace!("Device information response: {}", text);
+        let info = self.information().await?;
+        let pin = self.client.pin().status().await?;
+        let converged = match self.client.monitoring().converged_status().await {
+            Ok(status) => Some(status),
+            Err(Error::Api { code, .. }) if code == error_codes::NO_SUPPORT || code == 404 => None,
+            Err(e) => return Err(e),
+        };
 
-        self.client.check_xml_for_errors(&text).await?;
+        Ok(SimInfo::from_sources(Some(&info), Some(&pin), converged.as_ref()))
+    }
+
+    /// This endpoint requires authentication.
+    /// Reports the online firmware update state without starting an update.
+    /// Devices without online update support reply with `100002`, which is
+    /// passed through as [`Error::Api`].
+    pub async fn update_status(&self) -> Result<UpdateStatus> {
+        debug!("Fetching firmware update status");
+
+        self.client.get_authenticated_with_retry(UPDATE_STATUS_PATH, |text| {
+            trace!("Update status response: {}", text);
+            serde_xml_rs::from_str(text).map_err(|e| Error::parse(UPDATE_STATUS_PATH, e, text))
+        }).await
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns detailed radio metrics (RSRP, RSRQ, SINR, cell and band information).
+    pub async fn signal(&self) -> Result<SignalInfo> {
+        debug!("Fetching signal information");
 
-        let device_info: DeviceInformation = serde_xml_rs::from_str(&text)
-            .map_err(|e| Error::generic(format!("Failed to parse device information: {}", e)))?;
+        self.client.get_authenticated_with_retry("/api/device/signal", |text| {
+            trace!("Signal information response: {}", text);
+            let signal: SignalInfo = serde_xml_rs::from_str(text)
+                .map_err(|e| Error::parse("/api/device/signal", e, text))?;
 
-        Ok(device_info)
+            debug!(
+                "Signal parsed: rsrp={:?}, sinr={:?}, band={:?}",
+                signal.rsrp_dbm(),
+                signal.sinr_db(),
+                signal.band
+            );
+
+            Ok(signal)
+        }).await
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns the serving cell identity and, where the device supports
+    /// `/api/net/cell-info`, its neighbouring cells. Devices without the
+    /// neighbour endpoint yield an empty neighbour list.
+    pub async fn cell_info(&self) -> Result<CellInfo> {
+        debug!("Fetching cell information");
+
+        let signal = self.signal().await?;
+        let plmn = self.client.network().current_plmn().await?.numeric;
+
+        let neighbors = match self
+            .client
+            .get_authenticated_with_retry("/api/net/cell-info", |text| {
+                trace!("Cell info response: {}", text);
+                serde_xml_rs::from_str::<NeighborCellList>(text)
+                    .map_err(|e| Error::parse("/api/net/cell-info", e, text))
+            })
+            .await
+        {
+            Ok(list) => list.cells.cells,
+            Err(Error::Api { code, .. }) if code == error_codes::NO_SUPPORT || code == 404 => {
+                debug!("Device does not report neighbour cells");
+                Vec::new()
+            }
+            Err(e) => return Err(e),
+        };
+
+        Ok(CellInfo::new(&signal, plmn, neighbors))
     }
 
     pub async fn reboot(&self) -> Result<()> {
-        debug!("Rebooting device");
+        self.control(DeviceControlType::Reboot).await
+    }
 
-        let request = DeviceControlRequest::reboot();
-        let xml = serde_xml_rs::to_string(&request)
-            .map_err(|e| Error::generic(format!("Failed to serialize reboot request: {}", e)))?;
+    /// This endpoint requires authentication.
+    /// Returns the device clock and NTP settings.
+    pub async fn time(&self) -> Result<DeviceTime> {
+        debug!("Fetching device time");
 
-        let response = self.client.post_xml("/api/device/control", &xml).await?;
-        let text = response.text().await?;
+        self.client.get_authenticated_with_retry("/api/device/time", |text| {
+            trace!("Device time response: {}", text);
+            serde_xml_rs::from_str(text)
+                .map_err(|e| Error::parse("/api/device/time", e, text))
+        }).await
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Sets the clock manually or switches to NTP, see [`DeviceTimeRequest`].
+    pub async fn set_time(&self, request: &DeviceTimeRequest) -> Result<()> {
+        debug!("Setting device time (auto: {})", request.auto_time);
 
-        trace!("Device reboot response: {}", text);
+        let xml = serde_xml_rs::to_string(request)
+            .map_err(|e| Error::generic(format!("Failed to serialize device time request: {}", e)))?;
+
+        let response = self.client.post_xml("/api/device/time", &xml).await?;
+        let text = read_text(response).await?;
+
+        trace!("Set device time response: {}", text);
 
         self.client.check_xml_for_errors(&text).await?;
 
-        let result: Response = serde_xml_rs::from_str(&text)
-            .map_err(|e| Error::generic(format!("Failed to parse reboot response: {}", e)))?;
-
-        if !result.is_success() {
-            return Err(Error::api(
-                result.error_code().unwrap_or(-1),
-                result
-                    .error_message()
-                    .unwrap_or("Device reboot failed")
-                    .to_string(),
-            ));
-        }
+        parse_generic_ok("/api/device/time", &text, "Setting device time failed")?;
+
+        debug!("Device time updated successfully");
+        Ok(())
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns the antenna selection on routers with external antenna ports
+    /// (B525/B528). Other devices fail with `Error::Api` code `100002`.
+    pub async fn antenna_settings(&self) -> Result<AntennaSettings> {
+        debug!("Fetching antenna settings");
+
+        self.client.get_authenticated_with_retry("/api/device/antenna_settings", |text| {
+            trace!("Antenna settings response: {}", text);
+            serde_xml_rs::from_str(text)
+                .map_err(|e| Error::parse("/api/device/antenna_settings", e, text))
+        }).await
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Selects the internal, external or automatic antenna. Devices without
+    /// antenna selection fail with `Error::Api` code `100002`.
+    pub async fn set_antenna_type(&self, kind: AntennaType) -> Result<()> {
+        debug!("Setting antenna type to {}", kind);
 
-        debug!("Device reboot initiated successfully");
+        let xml = serde_xml_rs::to_string(&AntennaTypeRequest::new(kind))
+            .map_err(|e| Error::generic(format!("Failed to serialize antenna request: {}", e)))?;
+
+        let response = self.client.post_xml("/api/device/antenna_set_type", &xml).await?;
+        let text = read_text(response).await?;
+
+        trace!("Set antenna type response: {}", text);
+
+        self.client.check_xml_for_errors(&text).await?;
+
+        parse_generic_ok("/api/device/antenna_set_type", &text, "Setting antenna type failed")?;
+
+        debug!("Antenna type set successfully");
+        Ok(())
+    }
+
+    /// This endpoint requires authentication.
+    /// Reports whether airplane mode is on, from `/api/monitoring/status`.
+    pub async fn fly_mode_enabled(&self) -> Result<bool> {
+        let status = self.client.monitoring().status().await?;
+        Ok(status.is_fly_mode())
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Turns airplane mode on or off. Toggling it on and off again is a
+    /// quick way to reset the radio without rebooting. Firmware without
+    /// airplane mode fails with `Error::Api` code `100002`.
+    pub async fn set_fly_mode(&self, enabled: bool) -> Result<()> {
+        debug!("Setting airplane mode {}", if enabled { "on" } else { "off" });
+
+        let xml = serde_xml_rs::to_string(&FlyModeRequest::new(enabled))
+            .map_err(|e| Error::generic(format!("Failed to serialize airplane mode request: {}", e)))?;
+
+        let response = self.client.post_xml(FLY_MODE_PATH, &xml).await?;
+        let text = read_text(response).await?;
+
+        trace!("Set airplane mode response: {}", text);
+
+        self.client.check_xml_for_errors(&text).await?;
+
+        parse_generic_ok(FLY_MODE_PATH, &text, "Setting airplane mode failed")?;
+
+        // The radio goes down or comes back, so the cached operator is stale
+        self.client.clear_read_cache();
+
+        debug!("Airplane mode set successfully");
         Ok(())
     }
 
+    /// Reboot the device and wait until it answers again, returning the
+    /// fresh device information. The device must first be seen going down,
+    /// so an answer from before the reboot takes effect is not mistaken for
+    /// it being back. Connection errors, timeouts and an open circuit while
+    /// the device is down are expected; other errors abort the wait.
+    pub async fn reboot_and_wait(&self, timeout: Duration) -> Result<DeviceInformation> {
+        let deadline = Instant::now() + timeout;
+        self.reboot().await?;
+
+        let info = wait_for_reboot(
+            self.client,
+            deadline.saturating_duration_since(Instant::now()),
+            REBOOT_POLL_INTERVAL,
+        )
+        .await?;
+
+        debug!("Device is back after reboot");
+        Ok(info)
+    }
+
     pub async fn power_off(&self) -> Result<()> {
-        debug!("Powering off device");
+        self.control(DeviceControlType::PowerOff).await
+    }
+
+    /// Reset the device to factory settings. All configuration, including
+    /// APN profiles and the admin password, is lost and the device reboots.
+    /// Confirming the operation is left to the caller.
+    pub async fn factory_reset(&self) -> Result<()> {
+        self.control(DeviceControlType::FactoryReset).await
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Asks the device to write a backup of its configuration and downloads
+    /// it. The backup is a firmware-specific XML blob; its text can be passed
+    /// back to [`restore_configuration`](Self::restore_configuration).
+    pub async fn backup_configuration(&self) -> Result<Vec<u8>> {
+        self.control(DeviceControlType::BackupConfiguration).await?;
+
+        let response = self.client.get_authenticated(BACKUP_DOWNLOAD_PATH, None).await?;
+        let backup = response.bytes().await?.to_vec();
 
-        let request = DeviceControlRequest::power_off();
+        // An error instead of the file comes back as an XML body
+        if let Ok(text) = std::str::from_utf8(&backup) {
+            self.client.check_xml_for_errors(text).await?;
+        }
+        if backup.is_empty() {
+            return Err(Error::generic("Device returned an empty configuration backup"));
+        }
+
+        debug!("Downloaded configuration backup ({} bytes)", backup.len());
+        Ok(backup)
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Restores a configuration previously saved with
+    /// [`backup_configuration`](Self::backup_configuration). The device
+    /// reboots afterwards; firmware without restore support replies with
+    /// `100002`.
+    pub async fn restore_configuration(&self, xml_blob: &str) -> Result<()> {
+        debug!("Restoring device configuration ({} bytes)", xml_blob.len());
+
+        let response = self.client.post_xml(RESTORE_PATH, xml_blob).await?;
+        let text = read_text(response).await?;
+
+        trace!("Configuration restore response: {}", text);
+
+        self.client.check_xml_for_errors(&text).await?;
+
+        parse_generic_ok(RESTORE_PATH, &text, "Configuration restore failed")?;
+
+        debug!("Configuration restore initiated successfully");
+        Ok(())
+    }
+
+    /// Send a control operation to `/api/device/control`.
+    ///
+    /// This endpoint requires authentication and a valid CSRF token. The
+    /// named wrappers ([`reboot`](Self::reboot), [`power_off`](Self::power_off),
+    /// ...) are shorthands for this method.
+    pub async fn control(&self, op: DeviceControlType) -> Result<()> {
+        let action = match op {
+            DeviceControlType::Reboot => "Device reboot",
+            DeviceControlType::FactoryReset => "Factory reset",
+            DeviceControlType::BackupConfiguration => "Configuration backup",
+            DeviceControlType::PowerOff => "Device power off",
+        };
+        debug!("Sending device control: {:?}", op);
+
+        let request = DeviceControlRequest::new(op);
         let xml = serde_xml_rs::to_string(&request)
-            .map_err(|e| Error::generic(format!("Failed to serialize power off request: {}", e)))?;
+            .map_err(|e| Error::generic(format!("Failed to serialize {} request: {}", action, e)))?;
 
         let response = self.client.post_xml("/api/device/control", &xml).await?;
-        let text = response.text().await?;
+        let text = read_text(response).await?;
 
-        trace!("Device power off response: {}", text);
+        trace!("{} response: {}", action, text);
 
         self.client.check_xml_for_errors(&text).await?;
 
-        let result: Response = serde_xml_rs::from_str(&text)
-            .map_err(|e| Error::generic(format!("Failed to parse power off response: {}", e)))?;
-
-        if !result.is_success() {
-            return Err(Error::api(
-                result.error_code().unwrap_or(-1),
-                result
-                    .error_message()
-                    .unwrap_or("Device power off failed")
-                    .to_string(),
-            ));
-        }
+        parse_generic_ok("/api/device/control", &text, &format!("{} failed", action))?;
 
-        debug!("Device power off initiated successfully");
+        // Reboots and restores change what the device reports
+        self.client.clear_read_cache();
+
+        debug!("{} initiated successfully", action);
         Ok(())
     }
 }
@@ -99,6 +413,7 @@ impl<'a> DeviceApi<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::{device_information_xml, token_mock};
 
     #[tokio::test]
     async fn test_device_control_serialization() {
@@ -111,5 +426,463 @@ mod tests {
         let xml = serde_xml_rs::to_string(&power_off_request).unwrap();
 
         assert!(xml.contains("<Control>4</Control>"));
+
+        let xml = serde_xml_rs::to_string(&DeviceControlRequest::factory_reset()).unwrap();
+        assert!(xml.contains("<Control>2</Control>"));
+
+        let xml = serde_xml_rs::to_string(&DeviceControlRequest::backup_configuration()).unwrap();
+        assert!(xml.contains("<Control>3</Control>"));
+    }
+
+    async fn assert_control_sends(op: DeviceControlType, code: u8) {
+        let mut server = mockito::Server::new_async().await;
+        token_mock(&mut server).await;
+        let mock = server
+            .mock("POST", "/api/device/control")
+            .match_body(mockito::Matcher::Regex(format!(
+                "<request><Control>{}</Control></request>",
+                code
+            )))
+            .with_body("<response>OK</response>")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        DeviceApi::new(&client).control(op).await.unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_control_reboot() {
+        assert_control_sends(DeviceControlType::Reboot, 1).await;
+    }
+
+    #[tokio::test]
+    async fn test_control_factory_reset() {
+        assert_control_sends(DeviceControlType::FactoryReset, 2).await;
+    }
+
+    #[tokio::test]
+    async fn test_control_backup_configuration() {
+        assert_control_sends(DeviceControlType::BackupConfiguration, 3).await;
+    }
+
+    #[tokio::test]
+    async fn test_control_power_off() {
+        assert_control_sends(DeviceControlType::PowerOff, 4).await;
+    }
+
+    #[tokio::test]
+    async fn test_antenna_not_supported_passes_through() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+        server
+            .mock("GET", "/api/device/antenna_settings")
+            .with_body("<error><code>100002</code><message></message></error>")
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        let result = client.device().antenna_settings().await;
+        assert!(matches!(result, Err(Error::Api { code: 100002, .. })));
+    }
+
+    #[tokio::test]
+    async fn test_restore_configuration_posts_blob() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+
+        let blob = "<?xml version=\"1.0\" encoding=\"UTF-8\"?><config><item>1</item></config>";
+        let mock = server
+            .mock("POST", RESTORE_PATH)
+            .match_body(blob)
+            .with_body("<response>OK</response>")
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        client.device().restore_configuration(blob).await.unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_backup_configuration_downloads_file() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+
+        let control = server
+            .mock("POST", "/api/device/control")
+            .match_body(mockito::Matcher::Regex("<Control>3</Control>".to_string()))
+            .with_body("<response>OK</response>")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let backup: Vec<u8> = vec![0x1f, 0x8b, 0x08, 0x00, 0xff, 0x00, 0x42];
+        let download = server
+            .mock("GET", BACKUP_DOWNLOAD_PATH)
+            .with_body(backup.clone())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        assert_eq!(client.device().backup_configuration().await.unwrap(), backup);
+
+        control.assert_async().await;
+        download.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_backup_configuration_download_error() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+        server
+            .mock("POST", "/api/device/control")
+            .with_body("<response>OK</response>")
+            .create_async()
+            .await;
+        server
+            .mock("GET", BACKUP_DOWNLOAD_PATH)
+            .with_body("<error><code>100003</code><message></message></error>")
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        let result = client.device().backup_configuration().await;
+        assert!(matches!(result, Err(Error::LoginRequired)));
+    }
+
+    #[tokio::test]
+    async fn test_parse_error_includes_endpoint_and_body() {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/api/device/information")
+            .with_body("<response><DeviceName>E3372</Device></response>")
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        let error = client.device().information().await.unwrap_err();
+
+        match &error {
+            Error::Parse { endpoint, body_snippet, .. } => {
+                assert_eq!(endpoint, "/api/device/information");
+                assert!(body_snippet.contains("<DeviceName>E3372"));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert!(error.to_string().contains("/api/device/information"));
+        assert!(!error.to_string().contains("E3372"));
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_up_tolerates_down_window() {
+        let mut server = mockito::Server::new_async().await;
+
+        let down = server
+            .mock("GET", "/api/device/information")
+            .with_status(503)
+            .expect(3)
+            .create_async()
+            .await;
+        let up = server
+            .mock("GET", "/api/device/information")
+            .with_body(device_information_xml().build())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let config = crate::Config::builder()
+            .base_url(server.url())
+            .max_retries(1)
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+
+        let info = wait_until_up(&client, Duration::from_secs(5), Duration::from_millis(1))
+            .await
+            .unwrap();
+        assert_eq!(info.device_name, "E3372");
+
+        down.assert_async().await;
+        up.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_reboot_waits_for_down_state() {
+        let mut server = mockito::Server::new_async().await;
+
+        // Still answering right after the reboot request
+        let before = server
+            .mock("GET", "/api/device/information")
+            .with_body(device_information_xml().field("SoftwareVersion", "OLD").build())
+            .expect(2)
+            .create_async()
+            .await;
+        let down = server
+            .mock("GET", "/api/device/information")
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+        let after = server
+            .mock("GET", "/api/device/information")
+            .with_body(device_information_xml().build())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let config = crate::Config::builder()
+            .base_url(server.url())
+            .max_retries(1)
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+
+        let info = wait_for_reboot(&client, Duration::from_secs(5), Duration::from_millis(1))
+            .await
+            .unwrap();
+        assert_eq!(info.software_version, "SW");
+
+        before.assert_async().await;
+        down.assert_async().await;
+        after.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_reboot_bypasses_read_cache() {
+        let mut server = mockito::Server::new_async().await;
+
+        let before = server
+            .mock("GET", "/api/device/information")
+            .with_body(device_information_xml().field("SoftwareVersion", "OLD").build())
+            .expect(1)
+            .create_async()
+            .await;
+        let down = server
+            .mock("GET", "/api/device/information")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+        let after = server
+            .mock("GET", "/api/device/information")
+            .with_body(device_information_xml().build())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let config = crate::Config::builder()
+            .base_url(server.url())
+            .max_retries(1)
+            .read_cache_ttl(Duration::from_secs(60))
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+
+        let info = wait_for_reboot(&client, Duration::from_secs(5), Duration::from_millis(1))
+            .await
+            .unwrap();
+        assert_eq!(info.software_version, "SW");
+
+        before.assert_async().await;
+        down.assert_async().await;
+        after.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_down_times_out_while_up() {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/api/device/information")
+            .with_body(device_information_xml().build())
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        let result = wait_until_down(&client, Duration::ZERO, Duration::from_millis(1)).await;
+        assert!(matches!(result, Err(Error::Generic { message }) if message.contains("go down")));
+    }
+
+    #[test]
+    fn test_open_circuit_counts_as_down() {
+        assert!(is_down_error(&Error::generic(circuit::OPEN_MESSAGE)));
+        assert!(!is_down_error(&Error::generic("something else")));
+        assert!(!is_down_error(&Error::LoginRequired));
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_up_times_out() {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/api/device/information")
+            .with_status(503)
+            .create_async()
+            .await;
+
+        let config = crate::Config::builder()
+            .base_url(server.url())
+            .max_retries(1)
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+
+        let result = wait_until_up(&client, Duration::ZERO, Duration::from_millis(1)).await;
+        assert!(matches!(result, Err(Error::Generic { message }) if message.contains("Timed out")));
+    }
+
+    #[tokio::test]
+    async fn test_information_with_bom_and_blank_lines() {
+        let mut server = mockito::Server::new_async().await;
+
+        for prefix in ["\u{feff}", "\r\n\n  ", "\u{feff}\n"] {
+            let mock = server
+                .mock("GET", "/api/device/information")
+                .with_body(format!(
+                    "{}<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n{}",
+                    prefix,
+                    device_information_xml().build()
+                ))
+                .create_async()
+                .await;
+
+            let client = Client::for_url(server.url()).unwrap();
+            let info = client.device().information().await.unwrap();
+            assert_eq!(info.device_name, "E3372");
+
+            mock.remove_async().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cell_info_without_neighbor_endpoint() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+
+        server
+            .mock("GET", "/api/device/signal")
+            .with_body("<response><cell_id>27447297</cell_id><tac>51401</tac></response>")
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", "/api/net/current-plmn")
+            .with_body("<response><State>0</State><Numeric>26001</Numeric></response>")
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", "/api/net/cell-info")
+            .with_body("<error><code>100002</code><message></message></error>")
+            .create_async()
+            .await;
+
+        let client = crate::Client::for_url(server.url()).unwrap();
+        let info = client.device().cell_info().await.unwrap();
+
+        assert_eq!(info.enb_id, Some(107216));
+        assert_eq!(info.local_cell_id, Some(1));
+        assert_eq!(info.plmn.as_deref(), Some("26001"));
+        assert_eq!(info.tac.as_deref(), Some("51401"));
+        assert!(info.neighbors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sim_info_without_converged_status() {
+        // Newer firmware answers 100002, older 4G sticks a plain 404
+        for (status, body) in [(200, "<error><code>100002</code><message></message></error>"), (404, "")] {
+            let mut server = mockito::Server::new_async().await;
+
+            token_mock(&mut server).await;
+            server
+                .mock("GET", "/api/device/information")
+                .with_body(device_information_xml().field("Imsi", "260011234567890").build())
+                .create_async()
+                .await;
+            server
+                .mock("GET", "/api/pin/status")
+                .with_body(
+                    "<response><SimState>257</SimState><SimPinTimes>3</SimPinTimes>\
+<SimPukTimes>10</SimPukTimes></response>",
+                )
+                .create_async()
+                .await;
+            server
+                .mock("GET", "/api/monitoring/converged-status")
+                .with_status(status)
+                .with_body(body)
+                .create_async()
+                .await;
+
+            let client = Client::for_url(server.url()).unwrap();
+            let sim = client.device().sim_info().await.unwrap();
+
+            assert!(sim.is_sim_present());
+            assert_eq!(sim.imsi.as_deref(), Some("260011234567890"));
+            assert_eq!(sim.msisdn, None);
+            assert_eq!(sim.sim_slot, None, "converged status HTTP {}", status);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_set_fly_mode_not_supported_passes_through() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+        let fly_mode = server
+            .mock("POST", "/api/net/flymode")
+            .match_body("<?xml version=\"1.0\" encoding=\"UTF-8\"?><request><flymode>1</flymode></request>")
+            .with_body("<error><code>100002</code><message></message></error>")
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        let result = client.device().set_fly_mode(true).await;
+
+        assert!(matches!(result, Err(Error::Api { code: 100002, .. })));
+        fly_mode.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_set_fly_mode_accepts_empty_body() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+        let fly_mode = server
+            .mock("POST", FLY_MODE_PATH)
+            .with_body("")
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        client.device().set_fly_mode(false).await.unwrap();
+        fly_mode.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_update_status_not_supported_passes_through() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+        server
+            .mock("GET", UPDATE_STATUS_PATH)
+            .with_body("<error><code>100002</code><message></message></error>")
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        let result = client.device().update_status().await;
+
+        assert!(matches!(result, Err(Error::Api { code: 100002, .. })));
     }
 }
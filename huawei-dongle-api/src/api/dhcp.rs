@@ -1,40 +1,55 @@
 //! DHCP API endpoints
 
 use crate::{
-    client::Client,
     error::{Error, Result},
-    models::{common::Response, dhcp::*},
+    models::{common::parse_generic_ok, dhcp::*},
+    transport::Transport,
 };
+use super::wlan::WlanApi;
+use std::net::Ipv4Addr;
 use tracing::{debug, trace};
 
 /// DHCP API for DHCP configuration management
 pub struct DhcpApi<'a> {
-    client: &'a Client,
+    transport: &'a dyn Transport,
 }
 
 impl<'a> DhcpApi<'a> {
-    pub fn new(client: &'a Client) -> Self {
-        Self { client }
+    /// Create the API over any [`Transport`], usually a [`Client`](crate::Client)
+    pub fn new(transport: &'a dyn Transport) -> Self {
+        Self { transport }
     }
 
     /// This endpoint requires authentication and a valid session.
     pub async fn settings(&self) -> Result<DhcpSettings> {
         debug!("Fetching DHCP settings");
 
-        let response = self.client.get("/api/dhcp/settings").await?;
-        let text = response.text().await?;
+        let text = self.transport.get("/api/dhcp/settings").await?;
 
         trace!("DHCP settings response: {}", text);
 
-        self.client.check_xml_for_errors(&text).await?;
-
         let settings: DhcpSettings = serde_xml_rs::from_str(&text)
-            .map_err(|e| Error::generic(format!("Failed to parse DHCP settings: {}", e)))?;
+            .map_err(|e| Error::parse("/api/dhcp/settings", e, &text))?;
 
         debug!("DHCP gateway IP: {}", settings.dhcp_ip_address);
         Ok(settings)
     }
 
+    /// Fetch the DHCPv6 and IPv6 DNS settings.
+    ///
+    /// This endpoint requires authentication. IPv4-only firmware replies
+    /// with `100002` (not supported), which is passed through as [`Error::Api`].
+    pub async fn dhcpv6_settings(&self) -> Result<Dhcpv6Settings> {
+        debug!("Fetching DHCPv6 settings");
+
+        let text = self.transport.get_authenticated("/api/dhcp/dhcpv6-settings").await?;
+
+        trace!("DHCPv6 settings response: {}", text);
+
+        serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/dhcp/dhcpv6-settings", e, &text))
+    }
+
     /// This endpoint requires authentication and a valid CSRF token.
     /// **Warning**: This will change the device's network configuration and may temporarily disconnect clients.
     pub async fn set_settings(&self, request: &DhcpSettingsRequest) -> Result<()> {
@@ -47,35 +62,93 @@ impl<'a> DhcpApi<'a> {
             Error::generic(format!("Failed to serialize DHCP settings request: {}", e))
         })?;
 
-        let response = self.client.post_xml("/api/dhcp/settings", &xml).await?;
-        let text = response.text().await?;
+        let text = self.transport.post_xml("/api/dhcp/settings", &xml).await?;
 
         trace!("DHCP settings response: {}", text);
 
-        self.client.check_xml_for_errors(&text).await?;
+        parse_generic_ok("/api/dhcp/settings", &text, "DHCP settings change failed")?;
 
-        let result: Response = serde_xml_rs::from_str(&text)
-            .map_err(|e| Error::generic(format!("Failed to parse DHCP settings response: {}", e)))?;
+        debug!("DHCP settings changed successfully");
+        Ok(())
+    }
 
-        if !result.is_success() {
-            return Err(Error::api(
-                result.error_code().unwrap_or(-1),
-                result
-                    .error_message()
-                    .unwrap_or("DHCP settings change failed")
-                    .to_string(),
-            ));
-        }
+    /// This endpoint requires authentication.
+    /// Returns the static DHCP reservations.
+    pub async fn static_hosts(&self) -> Result<StaticHostList> {
+        debug!("Fetching DHCP static hosts");
 
-        debug!("DHCP settings changed successfully");
+        let text = self.transport.get_authenticated("/api/dhcp/static-addr-info").await?;
+
+        trace!("DHCP static hosts response: {}", text);
+
+        serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/dhcp/static-addr-info", e, &text))
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns the clients currently holding an address from the DHCP pool,
+    /// combining the DHCP settings with the connected host list. The
+    /// remaining lease time is only set on firmware that reports it.
+    pub async fn active_leases(&self) -> Result<Vec<DhcpLease>> {
+        debug!("Fetching active DHCP leases");
+
+        let settings = self.settings().await?;
+        let hosts = WlanApi::new(self.transport).host_list().await?;
+        let leases = DhcpLease::from_hosts(&settings, hosts.hosts());
+
+        debug!("Found {} active DHCP leases", leases.len());
+        Ok(leases)
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// The device replaces the whole reservation table with `hosts`.
+    /// Every address must fall within the current DHCP pool.
+    pub async fn set_static_hosts(&self, hosts: &[StaticHost]) -> Result<()> {
+        debug!("Setting {} DHCP static hosts", hosts.len());
+
+        let settings = self.settings().await?;
+        validate_static_hosts(&settings, hosts)?;
+
+        let request = StaticHostListRequest::new(hosts);
+        let xml = quick_xml::se::to_string(&request).map_err(|e| {
+            Error::generic(format!("Failed to serialize DHCP static hosts request: {}", e))
+        })?;
+
+        let text = self.transport.post_xml("/api/dhcp/static-addr-info", &xml).await?;
+
+        trace!("DHCP static hosts response: {}", text);
+
+        parse_generic_ok("/api/dhcp/static-addr-info", &text, "DHCP static hosts change failed")?;
+
+        debug!("DHCP static hosts changed successfully");
         Ok(())
     }
 }
 
+/// Ensure every reservation has a valid address inside the DHCP pool
+fn validate_static_hosts(settings: &DhcpSettings, hosts: &[StaticHost]) -> Result<()> {
+    for host in hosts {
+        let ip: Ipv4Addr = host.ip_address.trim().parse().map_err(|_| {
+            Error::config(format!("Invalid static host IP address: {}", host.ip_address))
+        })?;
+
+        if !settings.pool_contains(ip) {
+            return Err(Error::config(format!(
+                "Static host IP {} is outside the DHCP pool {}-{}",
+                ip, settings.dhcp_start_ip_address, settings.dhcp_end_ip_address
+            )));
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::{dhcp_settings, token_mock};
     use crate::config::Config;
+    use crate::Client;
 
     #[test]
     fn test_dhcp_api_creation() {
@@ -85,7 +158,47 @@ mod tests {
 
         assert_eq!(
             std::mem::size_of_val(&dhcp_api),
-            std::mem::size_of::<&Client>()
+            std::mem::size_of::<&dyn Transport>()
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_validate_static_hosts() {
+        let settings = dhcp_settings();
+
+        let valid = [
+            StaticHost::new("AA:BB:CC:DD:EE:01", "192.168.8.100", "nas"),
+            StaticHost::new("AA:BB:CC:DD:EE:02", "192.168.8.200", "printer"),
+        ];
+        assert!(validate_static_hosts(&settings, &valid).is_ok());
+
+        let outside = [StaticHost::new("AA:BB:CC:DD:EE:03", "192.168.8.50", "tv")];
+        assert!(matches!(
+            validate_static_hosts(&settings, &outside),
+            Err(Error::Config { .. })
+        ));
+
+        let invalid = [StaticHost::new("AA:BB:CC:DD:EE:04", "not-an-ip", "tv")];
+        assert!(matches!(
+            validate_static_hosts(&settings, &invalid),
+            Err(Error::Config { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_dhcpv6_not_supported_passes_through() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+        server
+            .mock("GET", "/api/dhcp/dhcpv6-settings")
+            .with_body("<error><code>100002</code><message></message></error>")
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        let result = client.dhcp().dhcpv6_settings().await;
+
+        assert!(matches!(result, Err(Error::Api { code: 100002, .. })));
+    }
+}
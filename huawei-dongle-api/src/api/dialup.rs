@@ -0,0 +1,223 @@
+//! Dial-up API endpoints
+
+use crate::{
+    error::{Error, Result},
+    models::{common::parse_generic_ok, dialup::*, monitoring::MonitoringStatus},
+    transport::Transport,
+};
+use super::{
+    monitoring::MonitoringApi,
+    network::{wait_until_connected, CONNECT_TIMEOUT_MESSAGE},
+};
+use std::time::Duration;
+use tracing::{debug, trace};
+
+/// Delay between `/api/monitoring/status` polls while waiting for a reconnect
+const RECONNECT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Dial-up API for controlling the mobile data connection
+pub struct DialupApi<'a> {
+    transport: &'a dyn Transport,
+}
+
+impl<'a> DialupApi<'a> {
+    /// Create the API over any [`Transport`], usually a [`Client`](crate::Client)
+    pub fn new(transport: &'a dyn Transport) -> Self {
+        Self { transport }
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns whether the cellular data connection is enabled.
+    pub async fn mobile_dataswitch(&self) -> Result<MobileDataSwitch> {
+        debug!("Fetching mobile data switch state");
+
+        let text = self.transport.get_authenticated("/api/dialup/mobile-dataswitch").await?;
+
+        trace!("Mobile data switch response: {}", text);
+
+        serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/dialup/mobile-dataswitch", e, &text))
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// **Warning**: Turning data off drops the cellular connection.
+    pub async fn data_switch(&self, on: bool) -> Result<()> {
+        debug!("Setting mobile data switch to: {}", if on { "on" } else { "off" });
+
+        let request = MobileDataSwitchRequest::new(on);
+        let xml = serde_xml_rs::to_string(&request).map_err(|e| {
+            Error::generic(format!("Failed to serialize data switch request: {}", e))
+        })?;
+
+        let text = self.transport.post_xml("/api/dialup/mobile-dataswitch", &xml).await?;
+
+        trace!("Data switch response: {}", text);
+
+        parse_generic_ok("/api/dialup/mobile-dataswitch", &text, "Mobile data switch failed")?;
+
+        debug!("Mobile data switch changed successfully");
+        Ok(())
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Turns mobile data off and on again to force a new data session.
+    ///
+    /// With `wait`, polls the connection status like
+    /// [`NetworkApi::wait_for_connection`](super::network::NetworkApi::wait_for_connection)
+    /// until the device is connected again, failing early if the device
+    /// reports a failed connection and with `Error::generic("reconnect timed
+    /// out")` once `wait` elapses. Returns the last observed status.
+    pub async fn reconnect(&self, wait: Option<Duration>) -> Result<MonitoringStatus> {
+        debug!("Reconnecting mobile data");
+
+        self.data_switch(false).await?;
+        self.data_switch(true).await?;
+
+        let Some(wait) = wait else {
+            return MonitoringApi::new(self.transport).status().await;
+        };
+
+        let status = match wait_until_connected(self.transport, wait, RECONNECT_POLL_INTERVAL).await {
+            Ok(status) => status,
+            Err(Error::Generic { message }) if message == CONNECT_TIMEOUT_MESSAGE => {
+                return Err(Error::generic("reconnect timed out"));
+            }
+            Err(e) => return Err(e),
+        };
+        debug!("Reconnected");
+        Ok(status)
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns all configured APN profiles and the index of the default one.
+    /// Stored passwords are never returned by the device.
+    pub async fn profiles(&self) -> Result<ProfileList> {
+        debug!("Fetching connection profiles");
+
+        let text = self.transport.get_authenticated("/api/dialup/profiles").await?;
+
+        trace!("Profiles response: {}", text);
+
+        let list: ProfileList = serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/dialup/profiles", e, &text))?;
+
+        debug!(
+            "Retrieved {} profiles, default: {}",
+            list.profiles.profiles.len(),
+            list.current_profile
+        );
+
+        Ok(list)
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// **Warning**: Changing the default profile reconnects the data session.
+    pub async fn set_default_profile(&self, index: u32) -> Result<()> {
+        debug!("Setting default profile to: {}", index);
+
+        self.post_profile_request(&ProfileRequest::set_default(index)).await
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Adds a new profile without changing the current default.
+    pub async fn create_profile(&self, profile: &Profile) -> Result<()> {
+        debug!("Creating profile '{}' (APN: {})", profile.name, profile.apn_name);
+
+        let current = self.profiles().await?;
+        let default_index = current.default_index().unwrap_or(0);
+
+        self.post_profile_request(&ProfileRequest::create(profile.clone(), default_index))
+            .await
+    }
+
+    async fn post_profile_request(&self, request: &ProfileRequest) -> Result<()> {
+        let xml = serde_xml_rs::to_string(request).map_err(|e| {
+            Error::generic(format!("Failed to serialize profile request: {}", e))
+        })?;
+
+        let text = self.transport.post_xml("/api/dialup/profiles", &xml).await?;
+
+        trace!("Profile response: {}", text);
+
+        parse_generic_ok("/api/dialup/profiles", &text, "Profile change failed")?;
+
+        debug!("Profile change applied successfully");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::token_mock;
+    use crate::config::Config;
+
+    #[test]
+    fn test_dialup_api_creation() {
+        let config = Config::default();
+        let client = crate::Client::new(config).unwrap();
+        let dialup_api = client.dialup();
+
+        assert_eq!(
+            std::mem::size_of_val(&dialup_api),
+            std::mem::size_of::<&dyn Transport>()
+        );
+    }
+
+    async fn reconnect_server(statuses: &[&str]) -> mockito::ServerGuard {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+
+        server
+            .mock("POST", "/api/dialup/mobile-dataswitch")
+            .with_body("<response>OK</response>")
+            .expect(2)
+            .create_async()
+            .await;
+
+        for status in statuses {
+            server
+                .mock("GET", "/api/monitoring/status")
+                .with_body(crate::testing::monitoring_status_xml(status))
+                .expect(1)
+                .create_async()
+                .await;
+        }
+
+        server
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_waits_for_connection() {
+        // Connecting, then Connected
+        let server = reconnect_server(&["900", "901"]).await;
+        let client = crate::Client::for_url(server.url()).unwrap();
+
+        let status = client
+            .dialup()
+            .reconnect(Some(Duration::from_secs(5)))
+            .await
+            .unwrap();
+        assert!(status.is_connected());
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_times_out() {
+        let server = reconnect_server(&["900"]).await;
+        let client = crate::Client::for_url(server.url()).unwrap();
+
+        let result = client.dialup().reconnect(Some(Duration::ZERO)).await;
+        assert!(matches!(result, Err(Error::Generic { message }) if message == "reconnect timed out"));
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_fails_fast_on_connect_failure() {
+        // Connecting, then ConnectFailed; the Connected status is never polled
+        let server = reconnect_server(&["900", "904"]).await;
+        let client = crate::Client::for_url(server.url()).unwrap();
+
+        let result = client.dialup().reconnect(Some(Duration::from_secs(5))).await;
+        assert!(matches!(result, Err(Error::Generic { message }) if message.contains("CONNECT_FAILED")));
+    }
+}
@@ -7,10 +7,15 @@
 //! 
 //! - [`auth`] - Authentication operations (login/logout)
 //! - [`device`] - Device information and control (reboot/power)
+//! - [`dialup`] - Mobile data connection control
 //! - [`dhcp`] - DHCP server configuration
 //! - [`monitoring`] - Connection and signal monitoring
 //! - [`network`] - Network mode and operator selection
+//! - [`pin`] - SIM PIN/PUK management
+//! - [`security`] - Port forwarding and firewall configuration
 //! - [`sms`] - SMS message management
+//! - [`ussd`] - USSD codes
+//! - [`wlan`] - WiFi access point configuration
 //! 
 //! # Usage Pattern
 //! 
@@ -37,6 +42,11 @@
 pub mod auth;
 pub mod device;
 pub mod dhcp;
+pub mod dialup;
 pub mod monitoring;
 pub mod network;
+pub mod pin;
+pub mod security;
 pub mod sms;
+pub mod ussd;
+pub mod wlan;
@@ -1,46 +1,275 @@
 //! Monitoring API endpoints
 
 use crate::{
-    client::Client,
     error::{Error, Result},
-    models::monitoring::MonitoringStatus,
+    models::{
+        common::parse_generic_ok,
+        enums::ConnectionStatus,
+        monitoring::{
+            ClearTrafficRequest, ConvergedStatus, DataLimit, DataLimitRequest, DataLimitSettings,
+            MonitoringStatus, MonthStatistics, Notifications, TrafficStatistics,
+        },
+    },
+    transport::Transport,
 };
+use futures_core::Stream;
+use futures_util::{stream, StreamExt};
+use std::future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::time::{Interval, MissedTickBehavior};
 use tracing::{debug, trace};
 
+const DATA_LIMIT_PATH: &str = "/api/monitoring/start_date";
+
+/// Stream of status readings returned by [`MonitoringApi::watch`]
+///
+/// A failed poll is yielded as an `Err` item and the stream keeps going, so
+/// a transient failure does not end it. Dropping the stream stops polling.
+pub struct StatusStream<'a> {
+    inner: Pin<Box<dyn Stream<Item = Result<MonitoringStatus>> + Send + 'a>>,
+}
+
+impl Stream for StatusStream<'_> {
+    type Item = Result<MonitoringStatus>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+/// Reduce a status stream to its connection status transitions
+///
+/// The first reading is always emitted; failed polls are skipped and do not
+/// reset the last seen value.
+fn connection_changes<'a, S>(statuses: S) -> impl Stream<Item = ConnectionStatus> + Send + 'a
+where
+    S: Stream<Item = Result<MonitoringStatus>> + Send + 'a,
+{
+    let mut last = None;
+    statuses.filter_map(move |status| {
+        let change = match status {
+            Ok(status) if last != Some(status.connection_status) => {
+                last = Some(status.connection_status);
+                Some(status.connection_status)
+            }
+            _ => None,
+        };
+        future::ready(change)
+    })
+}
+
 /// Monitoring API for status and signal monitoring
 pub struct MonitoringApi<'a> {
-    client: &'a Client,
+    transport: &'a dyn Transport,
 }
 
 impl<'a> MonitoringApi<'a> {
-    pub fn new(client: &'a Client) -> Self {
-        Self { client }
+    /// Create the API over any [`Transport`], usually a [`Client`](crate::Client)
+    pub fn new(transport: &'a dyn Transport) -> Self {
+        Self { transport }
     }
 
     pub async fn status(&self) -> Result<MonitoringStatus> {
         debug!("Fetching monitoring status");
 
-        self.client.get_authenticated_with_retry("/api/monitoring/status", |text| {
-            trace!("Monitoring status response: {}", text);
-            let status: MonitoringStatus = serde_xml_rs::from_str(text)
-                .map_err(|e| Error::generic(format!("Failed to parse monitoring status: {}", e)))?;
+        let text = self.transport.get_authenticated("/api/monitoring/status").await?;
+
+        trace!("Monitoring status response: {}", text);
+
+        let status: MonitoringStatus = serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/monitoring/status", e, &text))?;
+
+        debug!(
+            "Monitoring status parsed: connection={}, network={}, signal={}",
+            status.connection_status_text(),
+            status.network_type_text(),
+            status.signal_level().unwrap_or(0)
+        );
+
+        Ok(status)
+    }
+
+    /// Poll [`status`](Self::status) every `interval`, starting immediately.
+    /// A tick that comes due while a request is still running is delayed
+    /// rather than fired in a burst.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    pub fn watch(&self, interval: Duration) -> StatusStream<'a> {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let inner = stream::unfold(
+            (self.transport, ticker),
+            |(transport, mut ticker): (&'a dyn Transport, Interval)| async move {
+                ticker.tick().await;
+                let status = MonitoringApi::new(transport).status().await;
+                if let Err(e) = &status {
+                    debug!("Status poll failed: {}", e);
+                }
+                Some((status, (transport, ticker)))
+            },
+        );
+
+        StatusStream {
+            inner: Box::pin(inner),
+        }
+    }
+
+    /// Poll [`status`](Self::status) every `interval` like [`watch`](Self::watch),
+    /// but only yield the connection status when it changes, starting with
+    /// the first successful reading. Failed polls are logged and skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    pub fn on_status_change(&self, interval: Duration) -> impl Stream<Item = ConnectionStatus> + Send + 'a {
+        connection_changes(self.watch(interval))
+    }
+
+    /// Returns cumulative upload/download counters and current transfer rates.
+    pub async fn traffic_statistics(&self) -> Result<TrafficStatistics> {
+        debug!("Fetching traffic statistics");
 
-            debug!(
-                "Monitoring status parsed: connection={}, network={}, signal={}",
-                status.connection_status_text(),
-                status.network_type_text(),
-                status.signal_level().unwrap_or(0)
-            );
+        let text = self.transport.get_authenticated("/api/monitoring/traffic-statistics").await?;
 
-            Ok(status)
-        }).await
+        trace!("Traffic statistics response: {}", text);
+
+        let stats: TrafficStatistics = serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/monitoring/traffic-statistics", e, &text))?;
+
+        debug!(
+            "Traffic statistics parsed: total_download={}, total_upload={}",
+            stats.total_download_bytes(),
+            stats.total_upload_bytes()
+        );
+
+        Ok(stats)
+    }
+
+    /// Returns download/upload totals for the current billing month.
+    pub async fn month_statistics(&self) -> Result<MonthStatistics> {
+        debug!("Fetching month statistics");
+
+        let text = self.transport.get_authenticated("/api/monitoring/month_statistics").await?;
+
+        trace!("Month statistics response: {}", text);
+
+        serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/monitoring/month_statistics", e, &text))
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns unread SMS and storage flags. Cheap enough to poll for new messages.
+    pub async fn check_notifications(&self) -> Result<Notifications> {
+        debug!("Checking notifications");
+
+        let text = self.transport.get_authenticated("/api/monitoring/check-notifications").await?;
+
+        trace!("Notifications response: {}", text);
+
+        serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/monitoring/check-notifications", e, &text))
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns the converged status, including 5G NSA/SA details on devices
+    /// that report them. Devices without the endpoint fail with
+    /// `Error::Api` code `100002`.
+    pub async fn converged_status(&self) -> Result<ConvergedStatus> {
+        debug!("Fetching converged status");
+
+        let text = self.transport.get_authenticated("/api/monitoring/converged-status").await?;
+
+        trace!("Converged status response: {}", text);
+
+        serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/monitoring/converged-status", e, &text))
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Resets the cumulative traffic counters returned by [`traffic_statistics`](Self::traffic_statistics).
+    pub async fn clear_traffic_statistics(&self) -> Result<()> {
+        debug!("Clearing traffic statistics");
+
+        let request = ClearTrafficRequest::new();
+        let xml = serde_xml_rs::to_string(&request).map_err(|e| {
+            Error::generic(format!("Failed to serialize clear traffic request: {}", e))
+        })?;
+
+        let text = self.transport.post_xml("/api/monitoring/clear-traffic", &xml).await?;
+
+        trace!("Clear traffic response: {}", text);
+
+        parse_generic_ok("/api/monitoring/clear-traffic", &text, "Clearing traffic statistics failed")?;
+
+        debug!("Traffic statistics cleared successfully");
+        Ok(())
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns the monthly data cap, billing cycle start day and warning
+    /// threshold. Compare against [`month_statistics`](Self::month_statistics)
+    /// with [`DataLimitSettings::is_threshold_reached`].
+    pub async fn data_limit(&self) -> Result<DataLimitSettings> {
+        debug!("Fetching data limit settings");
+
+        let text = self.transport.get_authenticated(DATA_LIMIT_PATH).await?;
+
+        trace!("Data limit response: {}", text);
+
+        serde_xml_rs::from_str(&text).map_err(|e| Error::parse(DATA_LIMIT_PATH, e, &text))
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Start days outside 1-31 and thresholds above 100% are rejected with
+    /// [`Error::config`] before anything is sent.
+    pub async fn set_data_limit(&self, request: DataLimitRequest) -> Result<()> {
+        debug!("Setting data limit to {}", request.data_limit);
+
+        validate_data_limit(&request)?;
+
+        let xml = serde_xml_rs::to_string(&request).map_err(|e| {
+            Error::generic(format!("Failed to serialize data limit request: {}", e))
+        })?;
+
+        let text = self.transport.post_xml(DATA_LIMIT_PATH, &xml).await?;
+
+        trace!("Set data limit response: {}", text);
+
+        parse_generic_ok(DATA_LIMIT_PATH, &text, "Setting data limit failed")?;
+
+        debug!("Data limit set successfully");
+        Ok(())
     }
 }
 
+/// Check the fields the web UI would reject
+fn validate_data_limit(request: &DataLimitRequest) -> Result<()> {
+    if !matches!(request.start_day.trim().parse::<u8>(), Ok(1..=31)) {
+        return Err(Error::config(format!("Invalid billing start day: {}", request.start_day)));
+    }
+    if !matches!(request.month_threshold.trim().parse::<u8>(), Ok(0..=100)) {
+        return Err(Error::config(format!(
+            "Invalid data limit threshold: {}",
+            request.month_threshold
+        )));
+    }
+    if DataLimit::parse(&request.data_limit).is_none() {
+        return Err(Error::config(format!("Invalid data limit: {}", request.data_limit)));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::token_mock;
     use crate::config::Config;
+    use crate::Client;
 
     #[test]
     fn test_monitoring_api_creation() {
@@ -50,7 +279,99 @@ mod tests {
 
         assert_eq!(
             std::mem::size_of_val(&monitoring_api),
-            std::mem::size_of::<&Client>()
+            std::mem::size_of::<&dyn Transport>()
         );
     }
+
+    const STATUS_XML: &str = "<response><ConnectionStatus>901</ConnectionStatus>\
+<CurrentNetworkType>19</CurrentNetworkType><RoamingStatus>0</RoamingStatus>\
+<simlockStatus>0</simlockStatus><flymode>0</flymode><currenttotalwifiuser>0</currenttotalwifiuser>\
+<SimStatus>1</SimStatus><ServiceStatus>2</ServiceStatus><maxsignal>5</maxsignal>\
+<wifiindooronly>0</wifiindooronly><usbup>0</usbup><wifiswitchstatus>0</wifiswitchstatus></response>";
+
+    #[tokio::test]
+    async fn test_watch_yields_status_per_tick() {
+        use futures_util::StreamExt;
+
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+        let status = server
+            .mock("GET", "/api/monitoring/status")
+            .with_body(STATUS_XML)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        let statuses: Vec<_> = client
+            .monitoring()
+            .watch(Duration::from_millis(10))
+            .take(2)
+            .collect()
+            .await;
+
+        assert_eq!(statuses.len(), 2);
+        assert!(statuses.iter().all(|status| status.as_ref().unwrap().is_connected()));
+        status.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_watch_continues_after_error() {
+        use futures_util::StreamExt;
+
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+        server
+            .mock("GET", "/api/monitoring/status")
+            .with_body("<error><code>100002</code><message></message></error>")
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        let mut stream = client.monitoring().watch(Duration::from_millis(10));
+
+        assert!(matches!(stream.next().await, Some(Err(Error::Api { code: 100002, .. }))));
+        assert!(matches!(stream.next().await, Some(Err(Error::Api { code: 100002, .. }))));
+    }
+
+    fn status_with(code: &str) -> Result<MonitoringStatus> {
+        Ok(serde_xml_rs::from_str(&STATUS_XML.replace("901", code)).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_connection_changes_emits_transitions_only() {
+        let statuses = stream::iter(vec![
+            status_with("901"),
+            status_with("901"),
+            Err(Error::generic("poll failed")),
+            status_with("902"),
+            status_with("901"),
+        ]);
+
+        let changes: Vec<_> = connection_changes(statuses).collect().await;
+        assert_eq!(
+            changes,
+            vec![
+                ConnectionStatus::Connected,
+                ConnectionStatus::Disconnected,
+                ConnectionStatus::Connected,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_data_limit() {
+        assert!(validate_data_limit(&DataLimitRequest::new(1, DataLimit::Gigabytes(10), 90)).is_ok());
+        assert!(matches!(
+            validate_data_limit(&DataLimitRequest::new(0, DataLimit::Gigabytes(10), 90)),
+            Err(Error::Config { .. })
+        ));
+        assert!(matches!(
+            validate_data_limit(&DataLimitRequest::new(1, DataLimit::Gigabytes(10), 101)),
+            Err(Error::Config { .. })
+        ));
+    }
+
 }
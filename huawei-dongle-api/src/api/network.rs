@@ -2,11 +2,70 @@
 
 use crate::{
     client::Client,
+    encoding::read_text,
     error::{Error, Result},
-    models::{common::Response, network::*},
+    models::{
+        common::parse_generic_ok,
+        enums::{ConnectionStatus, PlmnSelectionMode},
+        monitoring::MonitoringStatus,
+        network::*,
+    },
+    transport::Transport,
 };
+use super::monitoring::MonitoringApi;
+use async_trait::async_trait;
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
 use tracing::{debug, trace};
 
+/// Operator scans take far longer than regular requests
+const PLMN_SCAN_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Message of the error [`wait_until_connected`] returns once its timeout elapses
+pub(crate) const CONNECT_TIMEOUT_MESSAGE: &str = "Timed out waiting for connection";
+
+/// Source of connection status snapshots polled by [`wait_until_connected`]
+#[async_trait]
+pub(crate) trait StatusPoller {
+    async fn poll_status(&self) -> Result<MonitoringStatus>;
+}
+
+#[async_trait]
+impl StatusPoller for dyn Transport + '_ {
+    async fn poll_status(&self) -> Result<MonitoringStatus> {
+        MonitoringApi::new(self).status().await
+    }
+}
+
+/// Poll until connected, failing early if the device reports a failed connection
+pub(crate) async fn wait_until_connected<P: StatusPoller + Sync + ?Sized>(
+    poller: &P,
+    timeout: Duration,
+    poll: Duration,
+) -> Result<MonitoringStatus> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let status = poller.poll_status().await?;
+        match status.connection_status {
+            ConnectionStatus::Connected => return Ok(status),
+            ConnectionStatus::ConnectFailed | ConnectionStatus::ConnectStatusError => {
+                return Err(Error::generic(format!(
+                    "Connection failed: {}",
+                    status.connection_status
+                )));
+            }
+            _ => {}
+        }
+
+        if Instant::now() >= deadline {
+            return Err(Error::generic(CONNECT_TIMEOUT_MESSAGE));
+        }
+
+        trace!("Waiting for connection, status: {}", status.connection_status);
+        sleep(poll).await;
+    }
+}
+
 /// Network API for network configuration and status
 pub struct NetworkApi<'a> {
     client: &'a Client,
@@ -23,14 +82,14 @@ impl<'a> NetworkApi<'a> {
         debug!("Fetching network mode configuration");
 
         let response = self.client.get("/api/net/net-mode").await?;
-        let text = response.text().await?;
+        let text = read_text(response).await?;
 
         trace!("Network mode response: {}", text);
 
         self.client.check_xml_for_errors(&text).await?;
 
         let mode: NetworkMode = serde_xml_rs::from_str(&text)
-            .map_err(|e| Error::generic(format!("Failed to parse network mode: {}", e)))?;
+            .map_err(|e| Error::parse("/api/net/net-mode", e, &text))?;
 
         debug!(
             "Current network mode: {} ({})",
@@ -47,7 +106,7 @@ impl<'a> NetworkApi<'a> {
             "Setting network mode to: {} ({})",
             request.network_mode,
             NetworkMode {
-                network_mode: request.network_mode.clone(),
+                network_mode: request.network_mode,
                 network_band: request.network_band.clone(),
                 lte_band: request.lte_band.clone(),
             }
@@ -59,43 +118,64 @@ impl<'a> NetworkApi<'a> {
         })?;
 
         let response = self.client.post_xml("/api/net/net-mode", &xml).await?;
-        let text = response.text().await?;
+        let text = read_text(response).await?;
 
         trace!("Network mode set response: {}", text);
 
         self.client.check_xml_for_errors(&text).await?;
 
-        let result: Response = serde_xml_rs::from_str(&text)
-            .map_err(|e| Error::generic(format!("Failed to parse network mode response: {}", e)))?;
-
-        if !result.is_success() {
-            return Err(Error::api(
-                result.error_code().unwrap_or(-1),
-                result
-                    .error_message()
-                    .unwrap_or("Network mode change failed")
-                    .to_string(),
-            ));
-        }
+        parse_generic_ok("/api/net/net-mode", &text, "Network mode change failed")?;
+
+        // The device re-registers, so the cached operator is stale
+        self.client.clear_read_cache();
 
         debug!("Network mode changed successfully");
         Ok(())
     }
 
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Switches to automatic mode selection, keeping the configured bands.
+    pub async fn set_auto(&self) -> Result<()> {
+        self.set_mode_keeping_bands(NetworkModeRequest::auto()).await
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Switches to 4G only, keeping the configured bands.
+    pub async fn set_lte_only(&self) -> Result<()> {
+        self.set_mode_keeping_bands(NetworkModeRequest::lte_only()).await
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Switches to 4G preferred with 3G fallback, keeping the configured bands.
+    pub async fn set_lte_preferred(&self) -> Result<()> {
+        self.set_mode_keeping_bands(NetworkModeRequest::lte_preferred()).await
+    }
+
+    /// Read the current bands first so a mode change does not reset a band lock
+    async fn set_mode_keeping_bands(&self, request: NetworkModeRequest) -> Result<()> {
+        let current = self.get_mode().await?;
+        self.set_mode(&request.with_bands_from(&current)).await
+    }
+
+    /// Polls the connection status every `poll` until the device is connected,
+    /// e.g. after a mode change. Fails early if the device reports a failed
+    /// connection, and with a timeout error once `timeout` elapses.
+    pub async fn wait_for_connection(&self, timeout: Duration, poll: Duration) -> Result<MonitoringStatus> {
+        debug!("Waiting up to {:?} for connection", timeout);
+        wait_until_connected(self.client as &dyn Transport, timeout, poll).await
+    }
+
     /// This endpoint does not require authentication.
     /// Returns information about the current cellular network operator.
     pub async fn current_plmn(&self) -> Result<CurrentPlmn> {
         debug!("Fetching current PLMN information");
 
-        let response = self.client.get("/api/net/current-plmn").await?;
-        let text = response.text().await?;
+        let text = self.client.get_cached_text("/api/net/current-plmn").await?;
 
         trace!("Current PLMN response: {}", text);
 
-        self.client.check_xml_for_errors(&text).await?;
-
         let plmn: CurrentPlmn = serde_xml_rs::from_str(&text)
-            .map_err(|e| Error::generic(format!("Failed to parse PLMN information: {}", e)))?;
+            .map_err(|e| Error::parse("/api/net/current-plmn", e, &text))?;
 
         if let Some(name) = plmn.operator_name() {
             debug!(
@@ -107,12 +187,109 @@ impl<'a> NetworkApi<'a> {
 
         Ok(plmn)
     }
+
+    /// This endpoint requires authentication.
+    /// Scans for available operators. The scan can take a minute or more, so the
+    /// request uses a longer timeout than `Config.timeout`.
+    pub async fn plmn_list(&self) -> Result<PlmnList> {
+        debug!("Scanning for available operators");
+
+        let list = self
+            .client
+            .get_authenticated_with_retry_timeout("/api/net/plmn-list", Some(PLMN_SCAN_TIMEOUT), |text| {
+                trace!("PLMN list response: {}", text);
+
+                serde_xml_rs::from_str::<PlmnList>(text)
+                    .map_err(|e| Error::parse("/api/net/plmn-list", e, text))
+            })
+            .await?;
+
+        debug!("Found {} operators", list.networks().len());
+        Ok(list)
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Registers with the operator `numeric` (e.g. "26001") on radio technology `rat`
+    /// (as reported by [`plmn_list`](Self::plmn_list)).
+    /// **Warning**: This will temporarily disconnect the device while it re-registers.
+    pub async fn set_plmn(&self, numeric: &str, rat: &str, mode: PlmnSelectionMode) -> Result<()> {
+        debug!("Registering with operator {} (rat {}, mode {})", numeric, rat, mode);
+
+        let request = PlmnRegisterRequest::new(numeric, rat, mode);
+        let xml = serde_xml_rs::to_string(&request).map_err(|e| {
+            Error::generic(format!("Failed to serialize PLMN register request: {}", e))
+        })?;
+
+        let response = self.client.post_xml("/api/net/register", &xml).await?;
+        let text = read_text(response).await?;
+
+        trace!("PLMN register response: {}", text);
+
+        self.client.check_xml_for_errors(&text).await?;
+
+        parse_generic_ok("/api/net/register", &text, "Operator registration failed")?;
+
+        self.client.clear_read_cache();
+
+        debug!("Operator registration requested successfully");
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::token_mock;
     use crate::config::Config;
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+
+    /// Replays a fixed sequence of connection states
+    struct ScriptedPoller {
+        statuses: Mutex<VecDeque<&'static str>>,
+    }
+
+    impl ScriptedPoller {
+        fn new(statuses: &[&'static str]) -> Self {
+            Self {
+                statuses: Mutex::new(statuses.iter().copied().collect()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl StatusPoller for ScriptedPoller {
+        async fn poll_status(&self) -> Result<MonitoringStatus> {
+            let code = self.statuses.lock().unwrap().pop_front().expect("script exhausted");
+            Ok(crate::testing::monitoring_status(code))
+        }
+    }
+
+    const POLL: Duration = Duration::from_millis(1);
+
+    #[tokio::test]
+    async fn test_wait_until_connected() {
+        // Disconnected, Connecting, Connected
+        let poller = ScriptedPoller::new(&["902", "900", "901"]);
+        let status = wait_until_connected(&poller, Duration::from_secs(5), POLL).await.unwrap();
+        assert!(status.is_connected());
+        assert!(poller.statuses.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_connected_fails_early() {
+        let poller = ScriptedPoller::new(&["900", "904", "901"]);
+        let result = wait_until_connected(&poller, Duration::from_secs(5), POLL).await;
+        assert!(matches!(result, Err(Error::Generic { message }) if message.contains("CONNECT_FAILED")));
+        assert_eq!(poller.statuses.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_connected_times_out() {
+        let poller = ScriptedPoller::new(&["900"]);
+        let result = wait_until_connected(&poller, Duration::ZERO, POLL).await;
+        assert!(matches!(result, Err(Error::Generic { message }) if message.contains("Timed out")));
+    }
 
     #[test]
     fn test_network_api_creation() {
@@ -125,4 +302,103 @@ mod tests {
             std::mem::size_of::<&Client>()
         );
     }
+
+    #[tokio::test]
+    async fn test_set_lte_only_preserves_bands() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+        server
+            .mock("GET", "/api/net/net-mode")
+            .with_body(
+                "<response><NetworkMode>00</NetworkMode><NetworkBand>3FFFFFFF</NetworkBand>\
+<LTEBand>4</LTEBand></response>",
+            )
+            .create_async()
+            .await;
+        let set_mode = server
+            .mock("POST", "/api/net/net-mode")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::Regex("<NetworkMode>03</NetworkMode>".to_string()),
+                mockito::Matcher::Regex("<NetworkBand>3FFFFFFF</NetworkBand>".to_string()),
+                mockito::Matcher::Regex("<LTEBand>4</LTEBand>".to_string()),
+            ]))
+            .with_body("<response>OK</response>")
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        client.network().set_lte_only().await.unwrap();
+        set_mode.assert_async().await;
+    }
+
+    async fn cached_plmn_server() -> (mockito::ServerGuard, mockito::Mock) {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+        let plmn = server
+            .mock("GET", "/api/net/current-plmn")
+            .with_body("<response><State>0</State><FullName>Orange</FullName><Numeric>26003</Numeric></response>")
+            .expect(2)
+            .create_async()
+            .await;
+
+        (server, plmn)
+    }
+
+    fn cached_client(server: &mockito::ServerGuard) -> Client {
+        let config = Config::builder()
+            .base_url(server.url())
+            .read_cache_ttl(Duration::from_secs(60))
+            .build()
+            .unwrap();
+        Client::new(config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_set_plmn_clears_read_cache() {
+        let (mut server, plmn) = cached_plmn_server().await;
+        server
+            .mock("POST", "/api/net/register")
+            .with_body("<response>OK</response>")
+            .create_async()
+            .await;
+
+        let client = cached_client(&server);
+        client.network().current_plmn().await.unwrap();
+        client.network().current_plmn().await.unwrap();
+        client
+            .network()
+            .set_plmn("26003", "7", PlmnSelectionMode::Manual)
+            .await
+            .unwrap();
+        client.network().current_plmn().await.unwrap();
+
+        plmn.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_set_mode_clears_read_cache() {
+        let (mut server, plmn) = cached_plmn_server().await;
+        server
+            .mock("GET", "/api/net/net-mode")
+            .with_body(
+                "<response><NetworkMode>00</NetworkMode><NetworkBand>3FFFFFFF</NetworkBand>\
+<LTEBand>7FFFFFFFFFFFFFFF</LTEBand></response>",
+            )
+            .create_async()
+            .await;
+        server
+            .mock("POST", "/api/net/net-mode")
+            .with_body("<response>OK</response>")
+            .create_async()
+            .await;
+
+        let client = cached_client(&server);
+        client.network().current_plmn().await.unwrap();
+        client.network().set_auto().await.unwrap();
+        client.network().current_plmn().await.unwrap();
+
+        plmn.assert_async().await;
+    }
 }
@@ -0,0 +1,130 @@
+//! PIN API endpoints
+
+use crate::{
+    error::{Error, Result},
+    models::{common::parse_generic_ok, pin::*},
+    transport::Transport,
+};
+use tracing::{debug, trace};
+
+/// PIN API for SIM PIN/PUK management
+pub struct PinApi<'a> {
+    transport: &'a dyn Transport,
+}
+
+impl<'a> PinApi<'a> {
+    /// Create the API over any [`Transport`], usually a [`Client`](crate::Client)
+    pub fn new(transport: &'a dyn Transport) -> Self {
+        Self { transport }
+    }
+
+    /// This endpoint does not require authentication.
+    /// Returns the SIM lock state and the remaining PIN/PUK attempts.
+    pub async fn status(&self) -> Result<PinStatus> {
+        debug!("Fetching PIN status");
+
+        let text = self.transport.get("/api/pin/status").await?;
+
+        trace!("PIN status response: {}", text);
+
+        let status: PinStatus = serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/pin/status", e, &text))?;
+
+        debug!(
+            "PIN status: sim_state={}, pin_times={}, puk_times={}",
+            status.sim_state, status.sim_pin_times, status.sim_puk_times
+        );
+        Ok(status)
+    }
+
+    /// Unlock the SIM with its PIN.
+    pub async fn enter_pin(&self, pin: &str) -> Result<()> {
+        self.operate(&PinOperateRequest::enter(pin)).await
+    }
+
+    /// Enable PIN verification on startup.
+    pub async fn enable_pin(&self, pin: &str) -> Result<()> {
+        self.operate(&PinOperateRequest::enable(pin)).await
+    }
+
+    /// Disable PIN verification on startup.
+    pub async fn disable_pin(&self, pin: &str) -> Result<()> {
+        self.operate(&PinOperateRequest::disable(pin)).await
+    }
+
+    /// Change the SIM PIN.
+    pub async fn change_pin(&self, old_pin: &str, new_pin: &str) -> Result<()> {
+        self.operate(&PinOperateRequest::change(old_pin, new_pin)).await
+    }
+
+    /// Unlock a PUK-locked SIM and set a new PIN.
+    pub async fn unlock_puk(&self, puk: &str, new_pin: &str) -> Result<()> {
+        self.operate(&PinOperateRequest::unlock_puk(puk, new_pin)).await
+    }
+
+    /// This endpoint requires a valid CSRF token.
+    /// Wrong codes are reported as [`Error::IncorrectPin`], [`Error::IncorrectPuk`]
+    /// or [`Error::PukExhausted`].
+    async fn operate(&self, request: &PinOperateRequest) -> Result<()> {
+        debug!("Performing PIN operation: {:?}", request.operate_type);
+
+        let xml = serde_xml_rs::to_string(request).map_err(|e| {
+            Error::generic(format!("Failed to serialize PIN operate request: {}", e))
+        })?;
+
+        let text = self.transport.post_xml("/api/pin/operate", &xml).await?;
+
+        trace!("PIN operate response: {}", text);
+
+        parse_generic_ok("/api/pin/operate", &text, "PIN operation failed")?;
+
+        debug!("PIN operation completed successfully");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn test_pin_api_creation() {
+        let config = Config::default();
+        let client = crate::Client::new(config).unwrap();
+        let pin_api = client.pin();
+
+        assert_eq!(
+            std::mem::size_of_val(&pin_api),
+            std::mem::size_of::<&dyn Transport>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_status_over_mock_transport() {
+        let transport = crate::testing::MockTransport::new().with_response(
+            "/api/pin/status",
+            "<response><SimState>257</SimState><PinOptState>258</PinOptState>\
+<SimPinTimes>3</SimPinTimes><SimPukTimes>10</SimPukTimes></response>",
+        );
+
+        let status = PinApi::new(&transport).status().await.unwrap();
+
+        assert_eq!(status.sim_state, "257");
+        assert_eq!(status.sim_puk_times, "10");
+        assert_eq!(transport.requests()[0].path, "/api/pin/status");
+    }
+
+    #[tokio::test]
+    async fn test_change_pin_posts_operate_request() {
+        let transport = crate::testing::MockTransport::new()
+            .with_response("/api/pin/operate", "<response>OK</response>");
+
+        PinApi::new(&transport).change_pin("1234", "4321").await.unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path, "/api/pin/operate");
+        assert!(requests[0].body.as_deref().unwrap_or_default().contains("<NewPin>4321</NewPin>"));
+    }
+}
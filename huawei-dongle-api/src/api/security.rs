@@ -0,0 +1,280 @@
+//! Security API endpoints
+
+use crate::{
+    error::{Error, Result},
+    models::{common::parse_generic_ok, dhcp::DhcpSettings, security::*},
+    transport::Transport,
+};
+use super::dhcp::DhcpApi;
+use std::net::Ipv4Addr;
+use tracing::{debug, trace};
+
+/// Security API for firewall and port forwarding configuration
+pub struct SecurityApi<'a> {
+    transport: &'a dyn Transport,
+}
+
+impl<'a> SecurityApi<'a> {
+    /// Create the API over any [`Transport`], usually a [`Client`](crate::Client)
+    pub fn new(transport: &'a dyn Transport) -> Self {
+        Self { transport }
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns the port forwarding (virtual server) rules.
+    pub async fn virtual_servers(&self) -> Result<Vec<VirtualServer>> {
+        debug!("Fetching virtual servers");
+
+        let text = self.transport.get_authenticated("/api/security/virtual-servers").await?;
+
+        trace!("Virtual servers response: {}", text);
+
+        let list: VirtualServerList = serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/security/virtual-servers", e, &text))?;
+
+        debug!("Found {} virtual servers", list.servers.len());
+        Ok(list.servers)
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// The device replaces the whole rule table with `servers`.
+    /// Ports must be in 1-65535 and every LAN IP a valid IPv4 host address.
+    pub async fn set_virtual_servers(&self, servers: &[VirtualServer]) -> Result<()> {
+        debug!("Setting {} virtual servers", servers.len());
+
+        validate_virtual_servers(servers)?;
+
+        let request = VirtualServerListRequest::new(servers);
+        let xml = quick_xml::se::to_string(&request).map_err(|e| {
+            Error::generic(format!("Failed to serialize virtual servers request: {}", e))
+        })?;
+
+        let text = self.transport.post_xml("/api/security/virtual-servers", &xml).await?;
+
+        trace!("Virtual servers response: {}", text);
+
+        parse_generic_ok("/api/security/virtual-servers", &text, "Virtual servers change failed")?;
+
+        debug!("Virtual servers changed successfully");
+        Ok(())
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns the DMZ host settings.
+    pub async fn dmz(&self) -> Result<DmzSettings> {
+        debug!("Fetching DMZ settings");
+
+        let text = self.transport.get_authenticated("/api/security/dmz").await?;
+
+        trace!("DMZ response: {}", text);
+
+        serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/security/dmz", e, &text))
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// When enabling, `host_ip` must be a host in the LAN subnet of the
+    /// DHCP gateway; otherwise the request is rejected with [`Error::config`].
+    pub async fn set_dmz(&self, enable: bool, host_ip: Ipv4Addr) -> Result<()> {
+        debug!("Setting DMZ {} for {}", if enable { "on" } else { "off" }, host_ip);
+
+        if enable {
+            let settings = DhcpApi::new(self.transport).settings().await?;
+            validate_dmz_host(&settings, host_ip)?;
+        }
+
+        let request = DmzRequest::new(enable, host_ip);
+        self.post_request("/api/security/dmz", &request, "DMZ change failed").await?;
+
+        debug!("DMZ changed successfully");
+        Ok(())
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns the firewall, IP filter and WAN ping switches.
+    pub async fn firewall_switch(&self) -> Result<FirewallSwitch> {
+        debug!("Fetching firewall switches");
+
+        let text = self.transport.get_authenticated("/api/security/firewall-switch").await?;
+
+        trace!("Firewall switch response: {}", text);
+
+        serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/security/firewall-switch", e, &text))
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    pub async fn set_firewall_switch(&self, request: &FirewallSwitchRequest) -> Result<()> {
+        debug!(
+            "Setting firewall switches: firewall={}, ip_filter={}, wan_ping={}",
+            request.firewall, request.ip_filter, request.wan_ping
+        );
+
+        self.post_request(
+            "/api/security/firewall-switch",
+            request,
+            "Firewall switch change failed",
+        )
+        .await?;
+
+        debug!("Firewall switches changed successfully");
+        Ok(())
+    }
+
+    /// Serialize and post a settings request, mapping a non-OK answer to
+    /// [`Error::Api`]
+    async fn post_request<T: serde::Serialize>(
+        &self,
+        path: &str,
+        request: &T,
+        failure: &str,
+    ) -> Result<()> {
+        let xml = serde_xml_rs::to_string(request).map_err(|e| {
+            Error::generic(format!("Failed to serialize {} request: {}", path, e))
+        })?;
+
+        let text = self.transport.post_xml(path, &xml).await?;
+
+        trace!("{} response: {}", path, text);
+
+        parse_generic_ok(path, &text, failure)?;
+
+        Ok(())
+    }
+}
+
+/// Ensure the DMZ host is a LAN host other than the gateway itself and the
+/// subnet's network and broadcast addresses
+fn validate_dmz_host(settings: &DhcpSettings, host_ip: Ipv4Addr) -> Result<()> {
+    if !settings.subnet_contains(host_ip) {
+        return Err(Error::config(format!(
+            "DMZ host {} is outside the LAN subnet {}/{}",
+            host_ip, settings.dhcp_ip_address, settings.dhcp_lan_netmask
+        )));
+    }
+
+    if settings.dhcp_ip_address.trim() == host_ip.to_string() {
+        return Err(Error::config(format!(
+            "DMZ host {} is the gateway address",
+            host_ip
+        )));
+    }
+
+    // subnet_contains already rejected an unparseable netmask
+    if let Ok(netmask) = settings.dhcp_lan_netmask.trim().parse::<Ipv4Addr>() {
+        let host_bits = !u32::from(netmask);
+        match u32::from(host_ip) & host_bits {
+            0 => {
+                return Err(Error::config(format!(
+                    "DMZ host {} is the network address of the LAN subnet",
+                    host_ip
+                )));
+            }
+            bits if bits == host_bits => {
+                return Err(Error::config(format!(
+                    "DMZ host {} is the broadcast address of the LAN subnet",
+                    host_ip
+                )));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Ensure every rule has non-zero ports and a usable LAN host address
+fn validate_virtual_servers(servers: &[VirtualServer]) -> Result<()> {
+    for server in servers {
+        if server.wan_port == 0 || server.lan_port == 0 {
+            return Err(Error::config(format!(
+                "Virtual server '{}' ports must be between 1 and 65535",
+                server.name
+            )));
+        }
+
+        let ip: Ipv4Addr = server.lan_ip.trim().parse().map_err(|_| {
+            Error::config(format!(
+                "Invalid LAN IP address for virtual server '{}': {}",
+                server.name, server.lan_ip
+            ))
+        })?;
+
+        if ip.is_unspecified() || ip.is_broadcast() || ip.is_multicast() {
+            return Err(Error::config(format!(
+                "Virtual server '{}' LAN IP {} is not a host address",
+                server.name, ip
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::models::Protocol;
+    use crate::testing::dhcp_settings;
+
+    #[test]
+    fn test_security_api_creation() {
+        let config = Config::default();
+        let client = crate::Client::new(config).unwrap();
+        let security_api = client.security();
+
+        assert_eq!(
+            std::mem::size_of_val(&security_api),
+            std::mem::size_of::<&dyn Transport>()
+        );
+    }
+
+    #[test]
+    fn test_validate_virtual_servers() {
+        let valid = VirtualServer::new("web", 8080, 80, "192.168.8.10", Protocol::Tcp);
+        assert!(validate_virtual_servers(std::slice::from_ref(&valid)).is_ok());
+
+        let zero_port = VirtualServer { wan_port: 0, ..valid.clone() };
+        assert!(matches!(
+            validate_virtual_servers(&[zero_port]),
+            Err(Error::Config { .. })
+        ));
+
+        let bad_ip = VirtualServer { lan_ip: "192.168.8".to_string(), ..valid.clone() };
+        assert!(matches!(
+            validate_virtual_servers(&[bad_ip]),
+            Err(Error::Config { .. })
+        ));
+
+        let broadcast = VirtualServer { lan_ip: "255.255.255.255".to_string(), ..valid };
+        assert!(matches!(
+            validate_virtual_servers(&[broadcast]),
+            Err(Error::Config { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_dmz_host() {
+        let settings = dhcp_settings();
+
+        assert!(validate_dmz_host(&settings, Ipv4Addr::new(192, 168, 8, 50)).is_ok());
+        assert!(validate_dmz_host(&settings, Ipv4Addr::new(192, 168, 8, 250)).is_ok());
+        assert!(matches!(
+            validate_dmz_host(&settings, Ipv4Addr::new(192, 168, 9, 50)),
+            Err(Error::Config { .. })
+        ));
+        assert!(matches!(
+            validate_dmz_host(&settings, Ipv4Addr::new(192, 168, 8, 1)),
+            Err(Error::Config { .. })
+        ));
+        assert!(matches!(
+            validate_dmz_host(&settings, Ipv4Addr::new(192, 168, 8, 0)),
+            Err(Error::Config { message }) if message.contains("network address")
+        ));
+        assert!(matches!(
+            validate_dmz_host(&settings, Ipv4Addr::new(192, 168, 8, 255)),
+            Err(Error::Config { message }) if message.contains("broadcast address")
+        ));
+    }
+}
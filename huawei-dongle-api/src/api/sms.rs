@@ -1,34 +1,80 @@
 //! SMS API endpoints
 
 use crate::{
-    client::Client,
-    error::{Error, Result},
-    models::{common::Response, sms::*},
+    error::{error_codes, Error, Result},
+    models::{common::parse_generic_ok, sms::*, SmsBoxType},
+    transport::Transport,
 };
+use futures_core::Stream;
+use futures_util::{stream, StreamExt};
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
 use tracing::{debug, trace};
 
+/// How long to wait for the device to finish sending a message
+const SEND_STATUS_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Delay between `/api/sms/send-status` polls
+const SEND_STATUS_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Number of messages fetched per `/api/sms/sms-list` page by [`SmsApi::iter_all`]
+const SMS_PAGE_SIZE: u32 = 20;
+
+/// Number of [`SMS_PAGE_SIZE`] pages needed for `total` messages
+fn page_count(total: u32) -> u32 {
+    total.div_ceil(SMS_PAGE_SIZE)
+}
+
+/// Stream of SMS messages returned by [`SmsApi::iter_all`]
+///
+/// Pages are fetched lazily as the stream is polled. A failed page fetch is
+/// yielded as an `Err` item and the stream moves on to the next page.
+pub struct SmsStream<'a> {
+    inner: Pin<Box<dyn Stream<Item = Result<SmsMessage>> + Send + 'a>>,
+}
+
+impl Stream for SmsStream<'_> {
+    type Item = Result<SmsMessage>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+/// Paging state for [`SmsStream`]
+struct SmsPageState<'a> {
+    transport: &'a dyn Transport,
+    box_type: SmsBoxType,
+    page: u32,
+    /// Number of pages to fetch, known once the box count has been read
+    pages: Option<u32>,
+    buffer: VecDeque<SmsMessage>,
+    done: bool,
+}
+
 /// SMS API for SMS management
 pub struct SmsApi<'a> {
-    client: &'a Client,
+    transport: &'a dyn Transport,
 }
 
 impl<'a> SmsApi<'a> {
-    pub fn new(client: &'a Client) -> Self {
-        Self { client }
+    /// Create the API over any [`Transport`], usually a [`Client`](crate::Client)
+    pub fn new(transport: &'a dyn Transport) -> Self {
+        Self { transport }
     }
 
     pub async fn count(&self) -> Result<SmsCount> {
         debug!("Fetching SMS count");
 
-        let response = self.client.get("/api/sms/sms-count").await?;
-        let text = response.text().await?;
+        let text = self.transport.get("/api/sms/sms-count").await?;
 
         trace!("SMS count response: {}", text);
 
-        self.client.check_xml_for_errors(&text).await?;
-
         let count: SmsCount = serde_xml_rs::from_str(&text)
-            .map_err(|e| Error::generic(format!("Failed to parse SMS count: {}", e)))?;
+            .map_err(|e| Error::parse("/api/sms/sms-count", e, &text))?;
 
         debug!(
             "SMS count - Local unread: {}, SIM unread: {}, Total unread: {}",
@@ -49,16 +95,167 @@ impl<'a> SmsApi<'a> {
         let xml = serde_xml_rs::to_string(request)
             .map_err(|e| Error::generic(format!("Failed to serialize SMS list request: {}", e)))?;
 
-        self.client.post_xml_with_retry("/api/sms/sms-list", &xml, |text| {
-            debug!("SMS list response XML: {}", text);
-            let sms_list: SmsListResponse = serde_xml_rs::from_str(text)
-                .map_err(|e| Error::generic(format!("Failed to parse SMS list: {}", e)))?;
-            debug!(
-                "Retrieved {} SMS messages",
-                sms_list.messages.messages.len()
-            );
-            Ok(sms_list)
-        }).await
+        let text = self.transport.post_xml("/api/sms/sms-list", &xml).await?;
+
+        debug!("SMS list response XML: {}", text);
+
+        let sms_list: SmsListResponse = serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/sms/sms-list", e, &text))?;
+
+        debug!(
+            "Retrieved {} SMS messages",
+            sms_list.messages.messages.len()
+        );
+        Ok(sms_list)
+    }
+
+    /// Stream every message in `box_type`, fetching pages of 20 as needed.
+    ///
+    /// The number of pages comes from the box's total in
+    /// `/api/sms/sms-count`. A page that fails to load is yielded as an `Err`
+    /// item and paging continues with the next one; if the count itself
+    /// cannot be read, that error is the only item.
+    pub fn iter_all(&self, box_type: SmsBoxType) -> SmsStream<'a> {
+        self.stream_pages(box_type, None)
+    }
+
+    /// Page through `box_type`, using `total` as the message count if it is
+    /// already known
+    fn stream_pages(&self, box_type: SmsBoxType, total: Option<u32>) -> SmsStream<'a> {
+        let state = SmsPageState {
+            transport: self.transport,
+            box_type,
+            page: 1,
+            pages: total.map(page_count),
+            buffer: VecDeque::new(),
+            done: false,
+        };
+
+        let inner = stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(message) = state.buffer.pop_front() {
+                    return Some((Ok(message), state));
+                }
+
+                if state.done {
+                    return None;
+                }
+
+                let pages = match state.pages {
+                    Some(pages) => pages,
+                    None => match SmsApi::new(state.transport).count().await.and_then(|count| {
+                        count.box_count(state.box_type).map_err(|e| {
+                            Error::generic(format!("Invalid SMS count for box {}: {}", state.box_type, e))
+                        })
+                    }) {
+                        Ok(total) => {
+                            debug!("SMS box {} holds {} messages", state.box_type, total);
+                            *state.pages.insert(page_count(total))
+                        }
+                        Err(e) => {
+                            state.done = true;
+                            return Some((Err(e), state));
+                        }
+                    },
+                };
+
+                if state.page > pages {
+                    return None;
+                }
+
+                let request = SmsListRequest::builder()
+                    .page(state.page)
+                    .count(SMS_PAGE_SIZE)
+                    .box_type(state.box_type)
+                    .build();
+                let page = state.page;
+                state.page += 1;
+
+                match SmsApi::new(state.transport).list(&request).await {
+                    Ok(response) => {
+                        let messages = response.messages.messages;
+                        debug!("SMS page {} returned {} messages", page, messages.len());
+                        state.buffer.extend(messages);
+                    }
+                    Err(e) => {
+                        debug!("SMS page {} failed: {}", page, e);
+                        return Some((Err(e), state));
+                    }
+                }
+            }
+        });
+
+        SmsStream {
+            inner: Box::pin(inner),
+        }
+    }
+
+    /// Fetch every message in `box_type`, returning the first error encountered.
+    pub async fn collect_all(&self, box_type: SmsBoxType) -> Result<Vec<SmsMessage>> {
+        self.collect_pages(box_type, None).await
+    }
+
+    async fn collect_pages(&self, box_type: SmsBoxType, total: Option<u32>) -> Result<Vec<SmsMessage>> {
+        let mut stream = self.stream_pages(box_type, total);
+        let mut messages = Vec::new();
+
+        while let Some(message) = stream.next().await {
+            messages.push(message?);
+        }
+
+        Ok(messages)
+    }
+
+    /// Free room in the local inbox once its storage is full, keeping the
+    /// `keep_newest` most recent messages. See [`ensure_space_in`](Self::ensure_space_in).
+    pub async fn ensure_space(&self, keep_newest: usize) -> Result<usize> {
+        self.ensure_space_in(SmsBoxType::LocalInbox, keep_newest).await
+    }
+
+    /// If the storage holding `box_type` is full, delete the oldest messages
+    /// in `box_type` beyond the `keep_newest` most recent ones.
+    ///
+    /// Returns how many messages were deleted; nothing is deleted while there
+    /// is room or when the firmware does not report its capacity. Messages
+    /// with an unreadable date are treated as the oldest. Fails only if no
+    /// message could be deleted.
+    pub async fn ensure_space_in(&self, box_type: SmsBoxType, keep_newest: usize) -> Result<usize> {
+        let count = self.count().await?;
+        if !count.is_full(box_type) {
+            debug!("SMS storage for box {} has room, nothing to delete", box_type);
+            return Ok(0);
+        }
+
+        let total = count.box_count(box_type).ok();
+        let mut messages = self.collect_pages(box_type, total).await?;
+        if messages.len() <= keep_newest {
+            debug!("SMS storage full but box {} holds only {} messages", box_type, messages.len());
+            return Ok(0);
+        }
+
+        messages.sort_by_key(|message| {
+            (message.datetime(), message.index.trim().parse::<u64>().unwrap_or(0))
+        });
+        let excess = messages.len() - keep_newest;
+        let ids: Vec<&str> = messages[..excess].iter().map(|message| message.id()).collect();
+        debug!("SMS storage full, deleting {} oldest messages from box {}", ids.len(), box_type);
+
+        let mut deleted = 0;
+        let mut first_error = None;
+        for (id, result) in self.delete_many(&ids).await? {
+            match result {
+                Ok(()) => deleted += 1,
+                Err(e) => {
+                    debug!("Failed to delete SMS {}: {}", id, e);
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        match first_error {
+            Some(e) if deleted == 0 => Err(e),
+            _ => Ok(deleted),
+        }
     }
 
     pub async fn delete(&self, message_id: &str) -> Result<()> {
@@ -69,30 +266,210 @@ impl<'a> SmsApi<'a> {
             Error::generic(format!("Failed to serialize SMS delete request: {}", e))
         })?;
 
-        let response = self.client.post_xml("/api/sms/delete-sms", &xml).await?;
-        let text = response.text().await?;
+        self.post_delete(&xml).await?;
+
+        debug!("SMS message deleted successfully");
+        Ok(())
+    }
+
+    /// Delete several messages in a single request.
+    /// Firmware that rejects multiple `<Index>` elements with a format error
+    /// is handled by deleting the messages one at a time.
+    /// Returns the outcome for each id, or the error of the batch request
+    /// when it fails for any other reason.
+    pub async fn delete_many(&self, message_ids: &[&str]) -> Result<Vec<(String, Result<()>)>> {
+        debug!("Deleting {} SMS messages", message_ids.len());
+
+        if message_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let request = SmsDeleteManyRequest::new(message_ids);
+        let xml = serde_xml_rs::to_string(&request).map_err(|e| {
+            Error::generic(format!("Failed to serialize SMS delete request: {}", e))
+        })?;
+
+        match self.post_delete(&xml).await {
+            Ok(()) => {
+                debug!("Batch SMS delete succeeded");
+                Ok(message_ids.iter().map(|id| (id.to_string(), Ok(()))).collect())
+            }
+            Err(Error::Api { code: error_codes::FORMAT_ERROR, .. }) => {
+                debug!("Batch SMS delete rejected, deleting individually");
+
+                let mut results = Vec::with_capacity(message_ids.len());
+                for id in message_ids {
+                    results.push((id.to_string(), self.delete(id).await));
+                }
+                Ok(results)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn post_delete(&self, xml: &str) -> Result<()> {
+        let text = self.transport.post_xml("/api/sms/delete-sms", xml).await?;
 
         trace!("SMS delete response: {}", text);
 
-        self.client.check_xml_for_errors(&text).await?;
+        parse_generic_ok("/api/sms/delete-sms", &text, "SMS deletion failed")?;
 
-        let result: Response = serde_xml_rs::from_str(&text)
-            .map_err(|e| Error::generic(format!("Failed to parse SMS delete response: {}", e)))?;
+        Ok(())
+    }
 
-        if !result.is_success() {
-            return Err(Error::api(
-                result.error_code().unwrap_or(-1),
-                result
-                    .error_message()
-                    .unwrap_or("SMS deletion failed")
-                    .to_string(),
-            ));
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Moves messages between SIM and local storage, e.g. when the SIM fills up.
+    /// Every [`SmsBoxType`] is an inbox, outbox or draft box, so any target is
+    /// accepted; an empty id list is rejected with [`Error::config`].
+    ///
+    /// Firmware names the endpoint differently: `/api/sms/move-sms` is tried
+    /// first, falling back to `/api/sms/save-sms` if it is missing.
+    pub async fn move_sms(&self, ids: &[&str], target: SmsBoxType) -> Result<()> {
+        debug!("Moving {} SMS messages to box {}", ids.len(), target);
+
+        if ids.is_empty() {
+            return Err(Error::config("No SMS messages to move"));
         }
 
-        debug!("SMS message deleted successfully");
+        let request = SmsMoveRequest::new(ids, target);
+        let xml = serde_xml_rs::to_string(&request)
+            .map_err(|e| Error::generic(format!("Failed to serialize SMS move request: {}", e)))?;
+
+        match self.post_move("/api/sms/move-sms", &xml).await {
+            Err(Error::Api { code, .. }) if code == 404 || code == error_codes::NO_SUPPORT => {
+                debug!("move-sms not available, falling back to save-sms");
+                self.post_move("/api/sms/save-sms", &xml).await
+            }
+            result => result,
+        }
+    }
+
+    async fn post_move(&self, path: &str, xml: &str) -> Result<()> {
+        let text = self.transport.post_xml(path, xml).await?;
+
+        trace!("SMS move response: {}", text);
+
+        parse_generic_ok(path, &text, "SMS move failed")?;
+
+        debug!("SMS messages moved successfully");
+        Ok(())
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Splits the content with [`split_message`] and sends the parts in order,
+    /// waiting for the device to report each part's final send status. If a
+    /// part fails, the remaining parts are not sent.
+    pub async fn send(&self, phones: &[&str], content: &str) -> Result<Vec<SmsSendStatus>> {
+        debug!("Sending SMS to {} recipient(s)", phones.len());
+
+        if phones.is_empty() {
+            return Err(Error::config("At least one recipient is required"));
+        }
+
+        let parts = split_message(content);
+        let mut statuses = Vec::with_capacity(parts.len());
+        for (i, part) in parts.iter().enumerate() {
+            debug!("Sending SMS part {}/{}", i + 1, parts.len());
+            statuses.push(self.send_part(phones, part).await?);
+        }
+        Ok(statuses)
+    }
+
+    /// Send one message and wait for its final send status
+    async fn send_part(&self, phones: &[&str], content: &str) -> Result<SmsSendStatus> {
+        let request = SmsSendRequest::new(phones, content);
+        let xml = serde_xml_rs::to_string(&request).map_err(|e| {
+            Error::generic(format!("Failed to serialize SMS send request: {}", e))
+        })?;
+
+        let text = self.transport.post_xml("/api/sms/send-sms", &xml).await?;
+
+        trace!("SMS send response: {}", text);
+
+        parse_generic_ok("/api/sms/send-sms", &text, "SMS send failed")?;
+
+        self.wait_for_send_status().await
+    }
+
+    /// Poll `/api/sms/send-status` until the device finishes sending
+    async fn wait_for_send_status(&self) -> Result<SmsSendStatus> {
+        let deadline = Instant::now() + SEND_STATUS_TIMEOUT;
+
+        loop {
+            let status = self.send_status().await?;
+
+            if status.is_failed() {
+                return Err(Error::generic(format!(
+                    "SMS send failed for: {}",
+                    status.fail_phone.as_deref().unwrap_or_default()
+                )));
+            }
+
+            if !status.is_pending() {
+                debug!("SMS sent successfully");
+                return Ok(status);
+            }
+
+            if Instant::now() >= deadline {
+                return Err(Error::generic("Timed out waiting for SMS send status"));
+            }
+
+            sleep(SEND_STATUS_POLL_INTERVAL).await;
+        }
+    }
+
+    pub async fn send_status(&self) -> Result<SmsSendStatus> {
+        debug!("Fetching SMS send status");
+
+        let text = self.transport.get_authenticated("/api/sms/send-status").await?;
+
+        trace!("SMS send status response: {}", text);
+
+        serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/sms/send-status", e, &text))
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns the SMS settings, including the SMS center number.
+    pub async fn config(&self) -> Result<SmsConfig> {
+        debug!("Fetching SMS configuration");
+
+        let text = self.transport.get_authenticated("/api/sms/config").await?;
+
+        trace!("SMS config response: {}", text);
+
+        serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/sms/config", e, &text))
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Writes SMS settings; build the request from [`config`](Self::config)
+    /// to keep fields that should not change.
+    pub async fn set_config(&self, request: &SmsConfigRequest) -> Result<()> {
+        debug!("Updating SMS configuration");
+
+        let xml = serde_xml_rs::to_string(request)
+            .map_err(|e| Error::generic(format!("Failed to serialize SMS config request: {}", e)))?;
+
+        let text = self.transport.post_xml("/api/sms/config", &xml).await?;
+
+        trace!("SMS config update response: {}", text);
+
+        parse_generic_ok("/api/sms/config", &text, "SMS config update failed")?;
+
+        debug!("SMS configuration updated successfully");
         Ok(())
     }
 
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Sets the SMS center (SMSC) number, keeping all other SMS settings.
+    /// Needed when sending fails because the SMSC is blank on a fresh SIM.
+    pub async fn set_center_number(&self, number: &str) -> Result<()> {
+        let config = self.config().await?;
+        let request = SmsConfigRequest::from(&config).with_center_number(number);
+        self.set_config(&request).await
+    }
+
     pub async fn mark_read(&self, message_id: &str) -> Result<()> {
         debug!("Marking SMS message as read: {}", message_id);
 
@@ -101,25 +478,11 @@ impl<'a> SmsApi<'a> {
             Error::generic(format!("Failed to serialize SMS set read request: {}", e))
         })?;
 
-        let response = self.client.post_xml("/api/sms/set-read", &xml).await?;
-        let text = response.text().await?;
+        let text = self.transport.post_xml("/api/sms/set-read", &xml).await?;
 
         trace!("SMS set read response: {}", text);
 
-        self.client.check_xml_for_errors(&text).await?;
-
-        let result: Response = serde_xml_rs::from_str(&text)
-            .map_err(|e| Error::generic(format!("Failed to parse SMS set read response: {}", e)))?;
-
-        if !result.is_success() {
-            return Err(Error::api(
-                result.error_code().unwrap_or(-1),
-                result
-                    .error_message()
-                    .unwrap_or("SMS mark read failed")
-                    .to_string(),
-            ));
-        }
+        parse_generic_ok("/api/sms/set-read", &text, "SMS mark read failed")?;
 
         debug!("SMS message marked as read successfully");
         Ok(())
@@ -129,7 +492,10 @@ impl<'a> SmsApi<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::token_mock;
     use crate::config::Config;
+    use crate::models::SmsSortType;
+    use crate::Client;
 
     #[test]
     fn test_sms_api_creation() {
@@ -139,7 +505,433 @@ mod tests {
 
         assert_eq!(
             std::mem::size_of_val(&sms_api),
-            std::mem::size_of::<&Client>()
+            std::mem::size_of::<&dyn Transport>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_count_with_mock_transport() {
+        let transport = crate::testing::MockTransport::new().with_response(
+            "/api/sms/sms-count",
+            "<response><LocalUnread>2</LocalUnread><LocalInbox>5</LocalInbox><LocalOutbox>1</LocalOutbox>\
+<LocalDraft>0</LocalDraft><SimUnread>1</SimUnread><SimInbox>3</SimInbox><SimOutbox>0</SimOutbox>\
+<SimDraft>0</SimDraft><NewMsg>0</NewMsg></response>",
+        );
+
+        let count = SmsApi::new(&transport).count().await.unwrap();
+
+        assert_eq!(count.total_unread(), Ok(3));
+        assert_eq!(transport.requests().len(), 1);
+        assert_eq!(transport.requests()[0].path, "/api/sms/sms-count");
+    }
+
+    #[tokio::test]
+    async fn test_count_error_with_mock_transport() {
+        let transport = crate::testing::MockTransport::new().with_response(
+            "/api/sms/sms-count",
+            "<error><code>100003</code><message></message></error>",
+        );
+
+        let result = SmsApi::new(&transport).count().await;
+        assert!(matches!(result, Err(Error::LoginRequired)));
+    }
+
+    fn sms_list_page(first_index: u32, count: u32) -> String {
+        let messages: String = (first_index..first_index + count)
+            .map(|index| {
+                format!(
+                    "<Message><Smstat>1</Smstat><Index>{}</Index><Phone>3350</Phone>\
+                     <Content>Message {}</Content><Date>2025-06-09 17:08:58</Date><Sca></Sca>\
+                     <SaveType>0</SaveType><Priority>0</Priority><SmsType>1</SmsType></Message>",
+                    index, index
+                )
+            })
+            .collect();
+
+        format!(
+            "<response><Count>{}</Count><Messages>{}</Messages></response>",
+            count, messages
+        )
+    }
+
+    #[tokio::test]
+    async fn test_iter_all_fetches_pages_from_box_count() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+
+        server
+            .mock("GET", "/api/sms/sms-count")
+            .with_body(sms_count_xml(23, 100))
+            .create_async()
+            .await;
+
+        let first_page = server
+            .mock("POST", "/api/sms/sms-list")
+            .match_body(mockito::Matcher::Regex("<PageIndex>1</PageIndex>".to_string()))
+            .with_body(sms_list_page(40000, 20))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let second_page = server
+            .mock("POST", "/api/sms/sms-list")
+            .match_body(mockito::Matcher::Regex("<PageIndex>2</PageIndex>".to_string()))
+            .with_body(sms_list_page(40020, 3))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::new(Config::for_url(server.url()).unwrap()).unwrap();
+        let messages = client.sms().collect_all(SmsBoxType::LocalInbox).await.unwrap();
+
+        assert_eq!(messages.len(), 23);
+        assert_eq!(messages[0].index, "40000");
+        assert_eq!(messages[22].index, "40022");
+
+        first_page.assert_async().await;
+        second_page.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_move_sms_falls_back_to_save_sms() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+
+        let modern = server
+            .mock("POST", "/api/sms/move-sms")
+            .with_status(404)
+            .create_async()
+            .await;
+        let legacy = server
+            .mock("POST", "/api/sms/save-sms")
+            .match_body(mockito::Matcher::Regex("<BoxType>1</BoxType>".to_string()))
+            .with_body("<response>OK</response>")
+            .create_async()
+            .await;
+
+        let config = Config::builder().base_url(server.url()).max_retries(1).build().unwrap();
+        let client = Client::new(config).unwrap();
+        client
+            .sms()
+            .move_sms(&["40001"], SmsBoxType::LocalInbox)
+            .await
+            .unwrap();
+
+        modern.assert_async().await;
+        legacy.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_move_sms_rejects_empty_ids() {
+        let client = Client::new(Config::default()).unwrap();
+        let result = client.sms().move_sms(&[], SmsBoxType::LocalInbox).await;
+        assert!(matches!(result, Err(Error::Config { .. })));
+    }
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_list_gzip_encoded_response() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+
+        let mock = server
+            .mock("POST", "/api/sms/sms-list")
+            .match_header("accept-encoding", mockito::Matcher::Regex("gzip".to_string()))
+            .with_header("content-encoding", "gzip")
+            .with_body(gzip(sms_list_page(40000, 20).as_bytes()))
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        let request =
+            SmsListRequest::new(1, 20, SmsBoxType::LocalInbox, SmsSortType::ByTime, false, false);
+        let response = client.sms().list(&request).await.unwrap();
+
+        assert_eq!(response.messages.messages.len(), 20);
+        assert_eq!(response.messages.messages[19].content, "Message 40019");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_list_posts_builder_request() {
+        let transport = crate::testing::MockTransport::new()
+            .with_response("/api/sms/sms-list", &sms_list_page(40000, 5));
+
+        let request = SmsListRequest::builder()
+            .page(3)
+            .count(5)
+            .box_type(SmsBoxType::LocalOutbox)
+            .unread_preferred(true)
+            .build();
+        let response = SmsApi::new(&transport).list(&request).await.unwrap();
+        assert_eq!(response.messages.messages.len(), 5);
+
+        let body = transport.requests()[0].body.clone().unwrap();
+        assert!(body.contains("<PageIndex>3</PageIndex>"));
+        assert!(body.contains("<ReadCount>5</ReadCount>"));
+        assert!(body.contains("<BoxType>2</BoxType>"));
+        assert!(body.contains("<SortType>0</SortType>"));
+        assert!(body.contains("<Ascending>0</Ascending>"));
+        assert!(body.contains("<UnreadPreferred>1</UnreadPreferred>"));
+    }
+
+    #[tokio::test]
+    async fn test_iter_all_yields_error_item() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+
+        server
+            .mock("GET", "/api/sms/sms-count")
+            .with_body(sms_count_xml(1, 100))
+            .create_async()
+            .await;
+
+        server
+            .mock("POST", "/api/sms/sms-list")
+            .with_body("<response><Count>1</Count><Messages><Message></Messages></response>")
+            .create_async()
+            .await;
+
+        let client = Client::new(Config::for_url(server.url()).unwrap()).unwrap();
+        let mut stream = client.sms().iter_all(SmsBoxType::LocalInbox);
+
+        assert!(matches!(stream.next().await, Some(Err(_))));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_iter_all_continues_after_failed_page() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+
+        server
+            .mock("GET", "/api/sms/sms-count")
+            .with_body(sms_count_xml(45, 100))
+            .create_async()
+            .await;
+
+        server
+            .mock("POST", "/api/sms/sms-list")
+            .match_body(mockito::Matcher::Regex("<PageIndex>1</PageIndex>".to_string()))
+            .with_body(sms_list_page(40000, 20))
+            .create_async()
+            .await;
+
+        let broken_page = server
+            .mock("POST", "/api/sms/sms-list")
+            .match_body(mockito::Matcher::Regex("<PageIndex>2</PageIndex>".to_string()))
+            .with_body("<response><Count>20</Count><Messages><Message></Messages></response>")
+            .expect_at_least(1)
+            .create_async()
+            .await;
+
+        let last_page = server
+            .mock("POST", "/api/sms/sms-list")
+            .match_body(mockito::Matcher::Regex("<PageIndex>3</PageIndex>".to_string()))
+            .with_body(sms_list_page(40040, 5))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::new(Config::for_url(server.url()).unwrap()).unwrap();
+        let items: Vec<Result<SmsMessage>> = client.sms().iter_all(SmsBoxType::LocalInbox).collect().await;
+
+        assert_eq!(items.len(), 26);
+        assert!(items[..20].iter().all(|item| item.is_ok()));
+        assert!(items[20].is_err());
+        let last: Vec<&str> = items[21..]
+            .iter()
+            .map(|item| item.as_ref().unwrap().index.as_str())
+            .collect();
+        assert_eq!(last, ["40040", "40041", "40042", "40043", "40044"]);
+
+        broken_page.assert_async().await;
+        last_page.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_iter_all_fails_without_count() {
+        let transport = crate::testing::MockTransport::new().with_response(
+            "/api/sms/sms-count",
+            "<error><code>100003</code><message></message></error>",
+        );
+
+        let items: Vec<Result<SmsMessage>> = SmsApi::new(&transport).iter_all(SmsBoxType::LocalInbox).collect().await;
+
+        assert_eq!(items.len(), 1);
+        assert!(matches!(items[0], Err(Error::LoginRequired)));
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_delete_many_falls_back_on_format_error() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+
+        let batch = server
+            .mock("POST", "/api/sms/delete-sms")
+            .match_body(mockito::Matcher::Regex(
+                "<Index>40001</Index><Index>40002</Index>".to_string(),
+            ))
+            .with_body("<error><code>100005</code><message></message></error>")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let first = server
+            .mock("POST", "/api/sms/delete-sms")
+            .match_body(mockito::Matcher::Regex("<request><Index>40001</Index></request>".to_string()))
+            .with_body("<response>OK</response>")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let second = server
+            .mock("POST", "/api/sms/delete-sms")
+            .match_body(mockito::Matcher::Regex("<request><Index>40002</Index></request>".to_string()))
+            .with_body("<error><code>100001</code><message></message></error>")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::new(Config::for_url(server.url()).unwrap()).unwrap();
+        let results = client.sms().delete_many(&["40001", "40002"]).await.unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "40001");
+        assert!(results[0].1.is_ok());
+        assert_eq!(results[1].0, "40002");
+        assert!(matches!(results[1].1, Err(Error::Api { code: 100001, .. })));
+
+        batch.assert_async().await;
+        first.assert_async().await;
+        second.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_delete_many_keeps_batch_error_type() {
+        let transport = crate::testing::MockTransport::new().with_response(
+            "/api/sms/delete-sms",
+            "<error><code>100003</code><message></message></error>",
         );
+
+        let result = SmsApi::new(&transport).delete_many(&["40001", "40002"]).await;
+
+        assert!(matches!(result, Err(Error::LoginRequired)));
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    fn sms_count_xml(local_inbox: u32, local_max: u32) -> String {
+        format!(
+            "<response><LocalUnread>0</LocalUnread><LocalInbox>{}</LocalInbox><LocalOutbox>0</LocalOutbox>\
+<LocalDraft>0</LocalDraft><SimUnread>0</SimUnread><SimInbox>0</SimInbox><SimOutbox>0</SimOutbox>\
+<SimDraft>0</SimDraft><NewMsg>0</NewMsg><LocalMax>{}</LocalMax><SimMax>30</SimMax></response>",
+            local_inbox, local_max
+        )
+    }
+
+    #[tokio::test]
+    async fn test_ensure_space_deletes_oldest() {
+        let dates = [
+            (40001, "2025-06-09 10:00:00"),
+            (40002, "2025-06-01 08:00:00"),
+            (40003, "2025-06-10 12:00:00"),
+            (40004, "2025-05-30 23:59:59"),
+            (40005, "2025-06-05 09:30:00"),
+        ];
+        let messages: String = dates
+            .iter()
+            .map(|(index, date)| {
+                format!(
+                    "<Message><Smstat>1</Smstat><Index>{}</Index><Phone>3350</Phone>\
+                     <Content>Hi</Content><Date>{}</Date><Sca></Sca>\
+                     <SaveType>0</SaveType><Priority>0</Priority><SmsType>1</SmsType></Message>",
+                    index, date
+                )
+            })
+            .collect();
+        let transport = crate::testing::MockTransport::new()
+            .with_response("/api/sms/sms-count", &sms_count_xml(5, 5))
+            .with_response(
+                "/api/sms/sms-list",
+                &format!("<response><Count>5</Count><Messages>{}</Messages></response>", messages),
+            )
+            .with_response("/api/sms/delete-sms", "<response>OK</response>");
+
+        let deleted = SmsApi::new(&transport).ensure_space(2).await.unwrap();
+        assert_eq!(deleted, 3);
+
+        let requests = transport.requests();
+        let delete = requests
+            .iter()
+            .find(|request| request.path == "/api/sms/delete-sms")
+            .unwrap();
+        let body = delete.body.as_deref().unwrap();
+        assert!(body.contains("<Index>40004</Index><Index>40002</Index><Index>40005</Index>"));
+        assert!(!body.contains("40001"));
+        assert!(!body.contains("40003"));
+    }
+
+    #[tokio::test]
+    async fn test_ensure_space_leaves_inbox_with_room() {
+        let transport = crate::testing::MockTransport::new()
+            .with_response("/api/sms/sms-count", &sms_count_xml(4, 5));
+
+        let deleted = SmsApi::new(&transport).ensure_space(2).await.unwrap();
+        assert_eq!(deleted, 0);
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    const SEND_STATUS_DONE: &str = "<response><Phone></Phone><SucPhone>111</SucPhone>\
+<FailPhone></FailPhone><TotalCount>1</TotalCount><CurIndex>1</CurIndex></response>";
+
+    #[tokio::test]
+    async fn test_send_splits_long_message() {
+        let transport = crate::testing::MockTransport::new()
+            .with_response("/api/sms/send-sms", "<response>OK</response>")
+            .with_response("/api/sms/send-status", SEND_STATUS_DONE);
+
+        let content = "a".repeat(200);
+        let statuses = SmsApi::new(&transport).send(&["111"], &content).await.unwrap();
+        assert_eq!(statuses.len(), 2);
+
+        let sent: Vec<String> = transport
+            .requests()
+            .into_iter()
+            .filter(|r| r.path == "/api/sms/send-sms")
+            .map(|r| r.body.unwrap())
+            .collect();
+        assert_eq!(sent.len(), 2);
+        assert!(sent[0].contains(&format!("<Content>{}</Content>", "a".repeat(153))));
+        assert!(sent[0].contains("<Length>153</Length>"));
+        assert!(sent[1].contains("<Length>47</Length>"));
     }
+
+    #[tokio::test]
+    async fn test_send_emoji_uses_ucs2() {
+        let transport = crate::testing::MockTransport::new()
+            .with_response("/api/sms/send-sms", "<response>OK</response>")
+            .with_response("/api/sms/send-status", SEND_STATUS_DONE);
+
+        let statuses = SmsApi::new(&transport).send(&["111"], "Hi 😀").await.unwrap();
+        assert_eq!(statuses.len(), 1);
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.path, "/api/sms/send-sms");
+        assert!(request.body.as_deref().unwrap().contains("<Reserved>5</Reserved>"));
+    }
+
 }
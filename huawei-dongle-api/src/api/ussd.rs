@@ -0,0 +1,116 @@
+//! USSD API endpoints
+
+use crate::{
+    error::{Error, Result},
+    models::{common::parse_generic_ok, ussd::*},
+    transport::Transport,
+};
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+use tracing::{debug, trace};
+
+/// How long to wait for the network to answer a USSD request
+const USSD_REPLY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Delay between `/api/ussd/status` polls
+const USSD_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// USSD API for sending USSD codes (balance checks, operator menus)
+pub struct UssdApi<'a> {
+    transport: &'a dyn Transport,
+}
+
+impl<'a> UssdApi<'a> {
+    /// Create the API over any [`Transport`], usually a [`Client`](crate::Client)
+    pub fn new(transport: &'a dyn Transport) -> Self {
+        Self { transport }
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Sends the code and waits for the network reply.
+    pub async fn send(&self, code: &str) -> Result<UssdResponse> {
+        debug!("Sending USSD code: {}", code);
+
+        let request = UssdSendRequest::new(code);
+        let xml = serde_xml_rs::to_string(&request)
+            .map_err(|e| Error::generic(format!("Failed to serialize USSD request: {}", e)))?;
+
+        self.post_request("/api/ussd/send", &xml, "USSD send failed").await?;
+
+        let deadline = Instant::now() + USSD_REPLY_TIMEOUT;
+        loop {
+            let status = self.status().await?;
+            if !status.is_waiting() {
+                break;
+            }
+
+            if Instant::now() >= deadline {
+                return Err(Error::generic("Timed out waiting for USSD reply"));
+            }
+
+            sleep(USSD_POLL_INTERVAL).await;
+        }
+
+        self.get().await
+    }
+
+    /// Returns whether a USSD request is still waiting for the network.
+    pub async fn status(&self) -> Result<UssdStatus> {
+        let text = self.transport.get_authenticated("/api/ussd/status").await?;
+
+        trace!("USSD status response: {}", text);
+
+        serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/ussd/status", e, &text))
+    }
+
+    /// Returns the reply to the last USSD request.
+    pub async fn get(&self) -> Result<UssdResponse> {
+        let text = self.transport.get_authenticated("/api/ussd/get").await?;
+
+        trace!("USSD reply: {}", text);
+
+        serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/ussd/get", e, &text))
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Ends an interactive USSD session.
+    pub async fn release(&self) -> Result<()> {
+        debug!("Releasing USSD session");
+
+        let xml = serde_xml_rs::to_string(&UssdReleaseRequest {}).map_err(|e| {
+            Error::generic(format!("Failed to serialize USSD release request: {}", e))
+        })?;
+
+        self.post_request("/api/ussd/release", &xml, "USSD release failed").await
+    }
+
+    async fn post_request(&self, path: &str, xml: &str, failure: &str) -> Result<()> {
+        let text = self.transport.post_xml(path, xml).await?;
+
+        trace!("USSD response: {}", text);
+
+        parse_generic_ok(path, &text, failure)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn test_ussd_api_creation() {
+        let config = Config::default();
+        let client = crate::Client::new(config).unwrap();
+        let ussd_api = client.ussd();
+
+        assert_eq!(
+            std::mem::size_of_val(&ussd_api),
+            std::mem::size_of::<&dyn Transport>()
+        );
+    }
+}
@@ -0,0 +1,213 @@
+//! WLAN (WiFi) API endpoints
+
+use crate::{
+    error::{Error, Result},
+    models::{common::parse_generic_ok, wlan::*},
+    transport::Transport,
+};
+use tracing::{debug, trace};
+
+/// WLAN API for WiFi access point configuration
+pub struct WlanApi<'a> {
+    transport: &'a dyn Transport,
+}
+
+impl<'a> WlanApi<'a> {
+    /// Create the API over any [`Transport`], usually a [`Client`](crate::Client)
+    pub fn new(transport: &'a dyn Transport) -> Self {
+        Self { transport }
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns SSID, channel, mode and broadcast settings of the access point.
+    pub async fn basic_settings(&self) -> Result<WlanBasicSettings> {
+        debug!("Fetching WLAN basic settings");
+
+        let text = self.transport.get_authenticated("/api/wlan/basic-settings").await?;
+
+        trace!("WLAN basic settings response: {}", text);
+
+        serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/wlan/basic-settings", e, &text))
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns the clients currently associated with the access point.
+    pub async fn host_list(&self) -> Result<WlanHostList> {
+        debug!("Fetching WLAN host list");
+
+        let text = self.transport.get_authenticated("/api/wlan/host-list").await?;
+
+        trace!("WLAN host list response: {}", text);
+
+        let list: WlanHostList = serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/wlan/host-list", e, &text))?;
+
+        debug!("Retrieved {} WLAN hosts", list.hosts().len());
+        Ok(list)
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// **Warning**: Changing the SSID or mode disconnects all WiFi clients.
+    pub async fn set_basic_settings(&self, request: &WlanBasicSettingsRequest) -> Result<()> {
+        debug!("Setting WLAN SSID to: {}", request.wifi_ssid);
+
+        let xml = serde_xml_rs::to_string(request).map_err(|e| {
+            Error::generic(format!("Failed to serialize WLAN basic settings request: {}", e))
+        })?;
+
+        let text = self.transport.post_xml("/api/wlan/basic-settings", &xml).await?;
+
+        trace!("WLAN basic settings response: {}", text);
+
+        parse_generic_ok("/api/wlan/basic-settings", &text, "WLAN basic settings change failed")?;
+
+        debug!("WLAN basic settings changed successfully");
+        Ok(())
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns the guest network settings. Devices without a guest network
+    /// fail with `Error::Api` code `100002`.
+    pub async fn guest_network(&self) -> Result<GuestNetwork> {
+        debug!("Fetching guest network settings");
+
+        let text = self.transport.get_authenticated("/api/wlan/guest-network").await?;
+
+        trace!("Guest network response: {}", text);
+
+        serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/wlan/guest-network", e, &text))
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// Enables or disables the guest network, optionally with a time limit.
+    pub async fn set_guest_network(&self, request: &GuestNetworkRequest) -> Result<()> {
+        debug!(
+            "Setting guest network {}",
+            if request.enabled { "on" } else { "off" }
+        );
+
+        let xml = serde_xml_rs::to_string(request).map_err(|e| {
+            Error::generic(format!("Failed to serialize guest network request: {}", e))
+        })?;
+
+        let text = self.transport.post_xml("/api/wlan/guest-network", &xml).await?;
+
+        trace!("Guest network response: {}", text);
+
+        parse_generic_ok("/api/wlan/guest-network", &text, "Guest network change failed")?;
+
+        debug!("Guest network changed successfully");
+        Ok(())
+    }
+
+    /// This endpoint requires authentication.
+    /// Returns the MAC address filter mode and its list of addresses.
+    pub async fn mac_filter(&self) -> Result<MacFilter> {
+        debug!("Fetching MAC filter");
+
+        let text = self.transport.get_authenticated("/api/wlan/mac-filter").await?;
+
+        trace!("MAC filter response: {}", text);
+
+        serde_xml_rs::from_str(&text)
+            .map_err(|e| Error::parse("/api/wlan/mac-filter", e, &text))
+    }
+
+    /// This endpoint requires authentication and a valid CSRF token.
+    /// The device replaces the whole list with the one in `request`.
+    /// Malformed MAC addresses are rejected with [`Error::config`] before
+    /// anything is sent.
+    pub async fn set_mac_filter(&self, request: &MacFilterRequest) -> Result<()> {
+        debug!(
+            "Setting MAC filter to {} with {} entries",
+            request.mode,
+            request.mac_addresses.len()
+        );
+
+        validate_mac_filter(request)?;
+
+        let xml = quick_xml::se::to_string(request).map_err(|e| {
+            Error::generic(format!("Failed to serialize MAC filter request: {}", e))
+        })?;
+
+        let text = self.transport.post_xml("/api/wlan/mac-filter", &xml).await?;
+
+        trace!("MAC filter response: {}", text);
+
+        parse_generic_ok("/api/wlan/mac-filter", &text, "MAC filter change failed")?;
+
+        debug!("MAC filter changed successfully");
+        Ok(())
+    }
+}
+
+/// Ensure every MAC filter entry has the `AA:BB:CC:DD:EE:FF` form
+fn validate_mac_filter(request: &MacFilterRequest) -> Result<()> {
+    for mac in &request.mac_addresses {
+        if !is_valid_mac_address(mac) {
+            return Err(Error::config(format!(
+                "Invalid MAC address '{}', expected AA:BB:CC:DD:EE:FF",
+                mac
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::token_mock;
+    use crate::config::Config;
+    use crate::Client;
+    use crate::models::enums::MacFilterMode;
+
+    #[test]
+    fn test_wlan_api_creation() {
+        let config = Config::default();
+        let client = crate::Client::new(config).unwrap();
+        let wlan_api = client.wlan();
+
+        assert_eq!(
+            std::mem::size_of_val(&wlan_api),
+            std::mem::size_of::<&dyn Transport>()
+        );
+    }
+
+    #[test]
+    fn test_validate_mac_filter() {
+        let request = MacFilterRequest::new(
+            MacFilterMode::Allow,
+            &["AA:BB:CC:DD:EE:01", "aa:bb:cc:dd:ee:02"],
+        );
+        assert!(validate_mac_filter(&request).is_ok());
+        assert!(validate_mac_filter(&MacFilterRequest::disabled()).is_ok());
+
+        let request = MacFilterRequest::new(MacFilterMode::Deny, &["AA:BB:CC:DD:EE:01", "AA-BB"]);
+        let err = validate_mac_filter(&request).unwrap_err();
+        assert!(matches!(err, Error::Config { .. }));
+        assert!(err.to_string().contains("AA-BB"));
+    }
+
+    #[tokio::test]
+    async fn test_guest_network_not_supported_passes_through() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+        server
+            .mock("POST", "/api/wlan/guest-network")
+            .with_body("<error><code>100002</code><message></message></error>")
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        let result = client
+            .wlan()
+            .set_guest_network(&GuestNetworkRequest::disable())
+            .await;
+        assert!(matches!(result, Err(Error::Api { code: 100002, .. })));
+    }
+}
@@ -1,9 +1,13 @@
 //! Authentication utilities and password encoding
 
+use crate::error::{Error, Result};
 use crate::models::auth::{LoginState, PasswordEncoding};
 use base64::{engine::general_purpose, Engine as _};
 use sha2::{Digest, Sha256};
 
+/// SHA-256 block size in bytes, used by HMAC
+const SHA256_BLOCK_SIZE: usize = 64;
+
 /// Password encoder for different Huawei authentication types
 pub struct PasswordEncoder;
 
@@ -35,6 +39,126 @@ impl PasswordEncoder {
     }
 }
 
+/// SCRAM-SHA256 challenge login used by firmware that rejects the simple login.
+///
+/// The exchange is `/api/user/challenge_login` (client nonce in, salt, iteration
+/// count and server nonce out) followed by `/api/user/authentication_login`
+/// (client proof in, server signature out).
+#[derive(Debug, Clone)]
+pub struct ScramLogin {
+    client_nonce: String,
+}
+
+/// Client proof and expected server signature for one SCRAM exchange
+#[derive(Debug, Clone)]
+pub struct ScramProof {
+    /// Hex-encoded client proof sent to `/api/user/authentication_login`
+    pub client_proof: String,
+    /// Hex-encoded server signature the device must answer with
+    pub server_signature: String,
+}
+
+impl ScramLogin {
+    /// Start a SCRAM exchange with a random 32-byte client nonce
+    pub fn new() -> Result<Self> {
+        let mut nonce = [0u8; 32];
+        getrandom::getrandom(&mut nonce)
+            .map_err(|e| Error::generic(format!("Failed to generate SCRAM nonce: {}", e)))?;
+
+        Ok(Self::with_nonce(&hex::encode(nonce)))
+    }
+
+    /// Start a SCRAM exchange with a fixed client nonce
+    pub fn with_nonce(client_nonce: &str) -> Self {
+        Self {
+            client_nonce: client_nonce.to_string(),
+        }
+    }
+
+    /// Client nonce sent as `firstnonce`
+    pub fn client_nonce(&self) -> &str {
+        &self.client_nonce
+    }
+
+    /// Compute the client proof and expected server signature from the challenge
+    pub fn proof(&self, password: &str, salt: &[u8], iterations: u32, server_nonce: &str) -> ScramProof {
+        let auth_message = format!("{},{},{}", self.client_nonce, server_nonce, server_nonce);
+        let salted_password = pbkdf2_sha256(password.as_bytes(), salt, iterations);
+
+        let client_key = hmac_sha256(b"Client Key", &salted_password);
+        let stored_key: [u8; 32] = Sha256::digest(client_key).into();
+        let client_signature = hmac_sha256(auth_message.as_bytes(), &stored_key);
+
+        let client_proof: Vec<u8> = client_key
+            .iter()
+            .zip(client_signature.iter())
+            .map(|(key, signature)| key ^ signature)
+            .collect();
+
+        let server_key = hmac_sha256(b"Server Key", &salted_password);
+        let server_signature = hmac_sha256(auth_message.as_bytes(), &server_key);
+
+        ScramProof {
+            client_proof: hex::encode(client_proof),
+            server_signature: hex::encode(server_signature),
+        }
+    }
+}
+
+impl ScramProof {
+    /// Check the server signature returned by the device
+    pub fn verify_server_signature(&self, server_signature: &str) -> bool {
+        let expected = self.server_signature.as_bytes();
+        let actual = server_signature.trim().to_ascii_lowercase();
+        let actual = actual.as_bytes();
+
+        expected.len() == actual.len()
+            && expected
+                .iter()
+                .zip(actual.iter())
+                .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+}
+
+/// HMAC-SHA256 (RFC 2104)
+fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    let mut block_key = [0u8; SHA256_BLOCK_SIZE];
+    if key.len() > SHA256_BLOCK_SIZE {
+        block_key[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block_key[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(block_key.map(|b| b ^ 0x36));
+    inner.update(data);
+    let inner_hash = inner.finalize();
+
+    let mut outer = Sha256::new();
+    outer.update(block_key.map(|b| b ^ 0x5c));
+    outer.update(inner_hash);
+    outer.finalize().into()
+}
+
+/// PBKDF2-HMAC-SHA256 (RFC 8018) producing a single 32-byte block
+fn pbkdf2_sha256(password: &[u8], salt: &[u8], iterations: u32) -> [u8; 32] {
+    let mut first_input = salt.to_vec();
+    first_input.extend_from_slice(&1u32.to_be_bytes());
+
+    let mut u = hmac_sha256(password, &first_input);
+    let mut result = u;
+
+    for _ in 1..iterations {
+        u = hmac_sha256(password, &u);
+        for (r, b) in result.iter_mut().zip(u.iter()) {
+            *r ^= b;
+        }
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,4 +222,64 @@ mod tests {
         let expected = general_purpose::STANDARD.encode("newpassword".as_bytes());
         assert_eq!(encoded, expected);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_hmac_sha256_known_vectors() {
+        // RFC 4231 test cases 2 and 6
+        assert_eq!(
+            hex::encode(hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        assert_eq!(
+            hex::encode(hmac_sha256(
+                &[0xaa; 131],
+                b"Test Using Larger Than Block-Size Key - Hash Key First"
+            )),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+
+    #[test]
+    fn test_pbkdf2_sha256_known_vectors() {
+        assert_eq!(
+            hex::encode(pbkdf2_sha256(b"password", b"salt", 1)),
+            "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b"
+        );
+        assert_eq!(
+            hex::encode(pbkdf2_sha256(b"password", b"salt", 4096)),
+            "c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a"
+        );
+    }
+
+    #[test]
+    fn test_scram_proof() {
+        let client_nonce = "a".repeat(64);
+        let server_nonce = format!("{}SERVERPART0123456789", client_nonce);
+        let salt = hex::decode("00112233445566778899aabbccddeeff00112233445566778899aabbccddeeff").unwrap();
+
+        let scram = ScramLogin::with_nonce(&client_nonce);
+        let proof = scram.proof("admin", &salt, 100, &server_nonce);
+
+        assert_eq!(
+            proof.client_proof,
+            "d9669fb3276d6d0bb0a3ace02a6cdd7c232975b8405d9efa708908230893b286"
+        );
+        assert!(proof.verify_server_signature(
+            "02d89eabf60e4a430cc1b5846abccdb379fb780c50e4df38f7c81f674909d953"
+        ));
+        assert!(!proof.verify_server_signature(
+            "02d89eabf60e4a430cc1b5846abccdb379fb780c50e4df38f7c81f674909d954"
+        ));
+        assert!(!proof.verify_server_signature(""));
+    }
+
+    #[test]
+    fn test_scram_nonce_generation() {
+        let first = ScramLogin::new().unwrap();
+        let second = ScramLogin::new().unwrap();
+
+        assert_eq!(first.client_nonce().len(), 64);
+        assert!(first.client_nonce().chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(first.client_nonce(), second.client_nonce());
+    }
+}
@@ -0,0 +1,335 @@
+//! Circuit breaker for unreachable devices.
+//!
+//! When a device is powered off or unplugged every request runs into connect
+//! errors or timeouts and burns the full retry budget. A [`CircuitBreaker`]
+//! counts consecutive connect/timeout failures and, once a threshold is
+//! reached within a time window, fast-fails further requests until a cooldown
+//! has passed. After the cooldown a single trial request is let through
+//! (half-open): success closes the circuit again, failure reopens it. A trial
+//! that is cancelled before it completes also reopens it, so the next request
+//! after it becomes a new trial.
+//!
+//! # Example
+//!
+//! ```
+//! use huawei_dongle_api::circuit::CircuitBreakerConfig;
+//! use huawei_dongle_api::Config;
+//! use std::time::Duration;
+//!
+//! let config = Config::builder()
+//!     .circuit_breaker(CircuitBreakerConfig {
+//!         failure_threshold: 3,
+//!         window: Duration::from_secs(30),
+//!         cooldown: Duration::from_secs(10),
+//!     })
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use crate::error::{Error, Result};
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+/// Message of the [`Error::Generic`] returned while the circuit is open
+pub(crate) const OPEN_MESSAGE: &str = "circuit open";
+
+/// Circuit breaker settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive connect/timeout failures that trip the circuit open
+    pub failure_threshold: u32,
+    /// Failures further apart than this start a new count
+    pub window: Duration,
+    /// How long the circuit stays open before a trial request is allowed
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            window: Duration::from_secs(60),
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+/// State of a [`CircuitBreaker`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests flow normally
+    Closed,
+    /// Requests fail immediately until the cooldown has passed
+    Open,
+    /// A single trial request is in flight
+    HalfOpen,
+}
+
+impl fmt::Display for CircuitState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitState::Closed => write!(f, "closed"),
+            CircuitState::Open => write!(f, "open"),
+            CircuitState::HalfOpen => write!(f, "half-open"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    state: CircuitState,
+    failures: u32,
+    first_failure: Option<Instant>,
+    opened_at: Option<Instant>,
+}
+
+/// Tracks connect/timeout failures and fast-fails requests while open
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    inner: Mutex<Inner>,
+}
+
+impl CircuitBreaker {
+    /// Create a closed circuit breaker
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            inner: Mutex::new(Inner {
+                state: CircuitState::Closed,
+                failures: 0,
+                first_failure: None,
+                opened_at: None,
+            }),
+        }
+    }
+
+    /// Current state. An open circuit whose cooldown has passed is reported
+    /// as half-open, since the next request will be let through as a trial.
+    pub fn state(&self) -> CircuitState {
+        let inner = self.inner.lock().unwrap();
+        match inner.state {
+            CircuitState::Open if self.cooldown_elapsed(&inner) => CircuitState::HalfOpen,
+            state => state,
+        }
+    }
+
+    /// Check whether a request may be sent, moving an open circuit to
+    /// half-open once the cooldown has passed.
+    pub fn allow(&self) -> Result<()> {
+        self.admit().map(|_| ())
+    }
+
+    /// Like [`allow`](Self::allow), but returns a [`CircuitPermit`] that
+    /// reopens the circuit if a trial request is dropped before its outcome
+    /// is recorded.
+    pub fn acquire(&self) -> Result<CircuitPermit<'_>> {
+        let trial = self.admit()?;
+        Ok(CircuitPermit {
+            breaker: self,
+            trial,
+            finished: false,
+        })
+    }
+
+    /// Admit a request, returning whether it is the half-open trial
+    fn admit(&self) -> Result<bool> {
+        let mut inner = self.inner.lock().unwrap();
+        match inner.state {
+            CircuitState::Closed => Ok(false),
+            CircuitState::Open if self.cooldown_elapsed(&inner) => {
+                debug!("Circuit half-open, allowing trial request");
+                inner.state = CircuitState::HalfOpen;
+                Ok(true)
+            }
+            // Only one trial request while half-open
+            CircuitState::Open | CircuitState::HalfOpen => Err(Error::generic(OPEN_MESSAGE)),
+        }
+    }
+
+    /// Record a request that reached the device
+    pub fn record_success(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.state != CircuitState::Closed {
+            debug!("Circuit closed");
+        }
+        inner.state = CircuitState::Closed;
+        inner.failures = 0;
+        inner.first_failure = None;
+        inner.opened_at = None;
+    }
+
+    /// Record a connect or timeout failure
+    pub fn record_failure(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        let now = Instant::now();
+
+        if inner.state == CircuitState::HalfOpen {
+            debug!("Trial request failed, circuit open again");
+            inner.state = CircuitState::Open;
+            inner.opened_at = Some(now);
+            return;
+        }
+
+        match inner.first_failure {
+            Some(first) if now.duration_since(first) <= self.config.window => inner.failures += 1,
+            _ => {
+                inner.failures = 1;
+                inner.first_failure = Some(now);
+            }
+        }
+
+        if inner.state == CircuitState::Closed && inner.failures >= self.config.failure_threshold {
+            debug!("Circuit open after {} consecutive failures", inner.failures);
+            inner.state = CircuitState::Open;
+            inner.opened_at = Some(now);
+        }
+    }
+
+    /// Put a half-open circuit whose trial never finished back to open. The
+    /// cooldown has already passed, so the next request is a new trial.
+    fn abandon_trial(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        if inner.state == CircuitState::HalfOpen {
+            debug!("Trial request cancelled, circuit open again");
+            inner.state = CircuitState::Open;
+        }
+    }
+
+    fn cooldown_elapsed(&self, inner: &Inner) -> bool {
+        inner
+            .opened_at
+            .is_some_and(|opened| opened.elapsed() >= self.config.cooldown)
+    }
+}
+
+/// Permission to send one request, returned by [`CircuitBreaker::acquire`]
+///
+/// Report the outcome with [`success`](Self::success) or
+/// [`failure`](Self::failure). Dropping the permit without either, e.g.
+/// because the request future was cancelled, abandons a half-open trial.
+#[must_use = "dropping the permit abandons a half-open trial"]
+#[derive(Debug)]
+pub struct CircuitPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    trial: bool,
+    finished: bool,
+}
+
+impl CircuitPermit<'_> {
+    /// Record that the request reached the device
+    pub fn success(mut self) {
+        self.finished = true;
+        self.breaker.record_success();
+    }
+
+    /// Record a connect or timeout failure
+    pub fn failure(mut self) {
+        self.finished = true;
+        self.breaker.record_failure();
+    }
+}
+
+impl Drop for CircuitPermit<'_> {
+    fn drop(&mut self) {
+        if self.trial && !self.finished {
+            self.breaker.abandon_trial();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker(cooldown: Duration) -> CircuitBreaker {
+        CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 2,
+            window: Duration::from_secs(60),
+            cooldown,
+        })
+    }
+
+    #[test]
+    fn test_trips_after_threshold() {
+        let breaker = breaker(Duration::from_secs(60));
+
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(breaker.allow().is_ok());
+
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(breaker.allow().is_err());
+    }
+
+    #[test]
+    fn test_success_resets_failure_count() {
+        let breaker = breaker(Duration::from_secs(60));
+
+        breaker.record_failure();
+        breaker.record_success();
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_failures_outside_window_start_new_count() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 2,
+            window: Duration::from_millis(10),
+            cooldown: Duration::from_secs(60),
+        });
+
+        breaker.record_failure();
+        std::thread::sleep(Duration::from_millis(20));
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_half_open_allows_single_trial() {
+        let breaker = breaker(Duration::from_millis(10));
+        breaker.record_failure();
+        breaker.record_failure();
+
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.allow().is_ok());
+        assert!(breaker.allow().is_err());
+
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        std::thread::sleep(Duration::from_millis(20));
+        assert!(breaker.allow().is_ok());
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_dropped_trial_reopens_circuit() {
+        let breaker = breaker(Duration::from_millis(10));
+        breaker.record_failure();
+        breaker.record_failure();
+        std::thread::sleep(Duration::from_millis(20));
+
+        let permit = breaker.acquire().unwrap();
+        assert!(breaker.allow().is_err());
+        drop(permit);
+
+        // The abandoned trial no longer blocks the circuit
+        let permit = breaker.acquire().unwrap();
+        permit.success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_dropped_closed_permit_keeps_state() {
+        let breaker = breaker(Duration::from_secs(60));
+        drop(breaker.acquire().unwrap());
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+}
@@ -24,16 +24,65 @@
 
 use crate::{
     api,
+    circuit::{CircuitBreaker, CircuitState},
     config::Config,
-    error::{Error, Result},
-    models::common::check_for_api_error,
-    retry::RetryStrategy,
-    session::SessionManager,
+    encoding::read_text,
+    error::{error_codes, Error, Result},
+    metrics::{Metrics, MetricsSnapshot},
+    observer,
+    models::{capabilities::Capabilities, common::check_for_api_error, snapshot::DeviceSnapshot},
+    retry::{RetryBudget, RetryStrategy},
+    session::{SerializableSession, SessionManager},
 };
-use reqwest::{Client as HttpClient, ClientBuilder, Response};
-use tracing::{debug, trace};
+use reqwest::{cookie::Jar, Client as HttpClient, ClientBuilder, Response};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::future::{self, Future};
+use std::path::Path;
+use std::sync::{Arc, Mutex, RwLock, Weak};
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, debug_span, field, info, trace, Instrument, Span};
 use url::Url;
 
+/// Endpoint probed by [`Client::ping`]; it needs no session or token
+const PING_PATH: &str = "/api/webserver/SesTokInfo";
+
+/// POST endpoints still sent in dry-run mode; they change the session, not
+/// the device configuration
+const DRY_RUN_SENT_PATHS: &[&str] = &[
+    "/api/user/login",
+    "/api/user/challenge_login",
+    "/api/user/authentication_login",
+    "/api/user/logout",
+];
+
+/// POST endpoints whose bodies carry PINs, passwords, WPA keys, SMS text and
+/// recipients or a full configuration backup; the dry-run log leaves these
+/// bodies out
+const DRY_RUN_REDACTED_PATHS: &[&str] = &[
+    "/api/pin/operate",
+    "/api/sms/send-sms",
+    "/api/user/password",
+    "/api/wlan/basic-settings",
+    "/api/wlan/security-settings",
+    "/api/wlan/guest-network",
+    "/api/dialup/profiles",
+    "/api/device/restore",
+];
+
+/// Answer to POSTs skipped in dry-run mode
+const DRY_RUN_RESPONSE: &str = "<response>OK</response>";
+
+/// Timeout for [`Client::ping`], independent of `Config.timeout`
+pub const PING_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Session age at which [`Client::spawn_keepalive`] refreshes the CSRF token.
+/// Devices drop idle sessions after about five minutes.
+pub const KEEPALIVE_REFRESH_AGE_MINUTES: u64 = 4;
+
 /// Main client for interacting with Huawei LTE dongles.
 /// 
 /// The client handles:
@@ -64,9 +113,81 @@ use url::Url;
 #[derive(Debug)]
 pub struct Client {
     http_client: HttpClient,
+    /// Same settings as `http_client` but without the cookie store, for
+    /// requests that must not touch the session
+    probe_client: HttpClient,
     config: Config,
-    session: SessionManager,
+    session: Arc<SessionManager>,
     retry_strategy: RetryStrategy,
+    credentials: Mutex<Option<Credentials>>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    read_cache: Arc<ReadCache>,
+    capabilities: Arc<Mutex<Option<Capabilities>>>,
+    metrics: Arc<Metrics>,
+}
+
+/// Response bodies of rarely-changing unauthenticated reads, keyed by path
+#[derive(Debug, Default)]
+struct ReadCache {
+    entries: RwLock<HashMap<String, (Instant, String)>>,
+}
+
+impl ReadCache {
+    fn get(&self, path: &str, ttl: Duration) -> Option<String> {
+        let entries = self.entries.read().unwrap();
+        entries
+            .get(path)
+            .filter(|(fetched, _)| fetched.elapsed() < ttl)
+            .map(|(_, text)| text.clone())
+    }
+
+    fn insert(&self, path: &str, text: String) {
+        let mut entries = self.entries.write().unwrap();
+        entries.insert(path.to_string(), (Instant::now(), text));
+    }
+}
+
+/// Stored login credentials used to recover from an expired session
+#[derive(Clone)]
+struct Credentials {
+    username: String,
+    password: String,
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Handle to the task started by [`Client::spawn_keepalive`]. The task is
+/// aborted when the handle is dropped.
+#[derive(Debug)]
+#[must_use = "the keepalive task stops when the handle is dropped"]
+pub struct KeepaliveHandle {
+    task: JoinHandle<()>,
+}
+
+impl KeepaliveHandle {
+    /// Stop the keepalive task
+    pub fn abort(&self) {
+        self.task.abort();
+    }
+
+    /// Whether the task has ended, either aborted or because the client was
+    /// dropped
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+}
+
+impl Drop for KeepaliveHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
 }
 
 impl Client {
@@ -90,26 +211,63 @@ impl Client {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn new(config: Config) -> Result<Self> {
-        let http_client = ClientBuilder::new()
-            .cookie_store(true)
-            .timeout(config.timeout)
-            .user_agent(&config.user_agent)
-            .build()?;
+        let cookie_jar = config.cookie_store.then(|| Arc::new(Jar::default()));
+        let probe_client = http_client_builder(&config)?.build()?;
+
+        let mut builder = http_client_builder(&config)?;
+        if let Some(jar) = &cookie_jar {
+            builder = builder.cookie_provider(jar.clone());
+        }
 
-        let session = SessionManager::new(http_client.clone(), config.base_url.clone());
+        let http_client = builder.build()?;
 
+        let session = match cookie_jar {
+            Some(jar) => SessionManager::with_cookie_jar(
+                http_client.clone(),
+                config.base_url.clone(),
+                jar,
+            ),
+            None => SessionManager::new(http_client.clone(), config.base_url.clone()),
+        }
+        .with_probe_client(probe_client.clone());
+
+        let metrics = Arc::new(Metrics::default());
+        let retry_metrics = metrics.clone();
+        let user_on_retry = config.on_retry.clone();
         let retry_strategy = RetryStrategy {
             max_attempts: config.max_retries,
             initial_delay: config.retry_delay,
             max_delay: config.max_retry_delay,
+            budget: config
+                .retry_budget_per_minute
+                .map(|retries| Arc::new(RetryBudget::per_minute(retries))),
+            retry_on: config.retry_on.clone(),
+            jitter_strategy: config.jitter_strategy,
+            retryable_api_codes: config.retryable_api_codes.clone(),
+            on_retry: Some(Arc::new(move |attempt, error, delay| {
+                retry_metrics.record_retry();
+                if let Some(on_retry) = &user_on_retry {
+                    on_retry(attempt, error, delay);
+                }
+            })),
             ..Default::default()
         };
 
+        let circuit_breaker = config
+            .circuit_breaker
+            .map(|config| Arc::new(CircuitBreaker::new(config)));
+
         Ok(Self {
             http_client,
+            probe_client,
             config,
-            session,
+            session: Arc::new(session),
             retry_strategy,
+            credentials: Mutex::new(None),
+            circuit_breaker,
+            read_cache: Arc::default(),
+            capabilities: Arc::default(),
+            metrics,
         })
     }
 
@@ -124,107 +282,561 @@ impl Client {
         Self::new(config)
     }
 
-    pub fn device(&self) -> api::device::DeviceApi {
+    /// Store credentials so that authenticated calls can log in again
+    /// when the device reports that the session has expired (`100003`).
+    pub fn set_credentials(&self, username: &str, password: &str) {
+        *self.credentials.lock().unwrap() = Some(Credentials {
+            username: username.to_string(),
+            password: password.to_string(),
+        });
+    }
+
+    /// Replace the stored password after a password change, if credentials are stored
+    pub(crate) fn update_stored_password(&self, password: &str) {
+        if let Some(credentials) = self.credentials.lock().unwrap().as_mut() {
+            credentials.password = password.to_string();
+        }
+    }
+
+    /// Save the current session (CSRF token and session cookie) as JSON,
+    /// so a later process can reuse it with [`load_session`](Self::load_session)
+    /// instead of logging in again.
+    ///
+    /// The file holds live credentials, so on Unix it is created (or reset)
+    /// with mode `0600`.
+    pub async fn save_session<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        use tokio::io::AsyncWriteExt;
+
+        let state = self.session.export_state().await;
+        let json = serde_json::to_string_pretty(&state)
+            .map_err(|e| Error::generic(format!("Failed to serialize session: {}", e)))?;
+
+        let write_error = |e: std::io::Error| Error::generic(format!("Failed to write session file: {}", e));
+
+        let mut options = tokio::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+
+        let mut file = options.open(path.as_ref()).await.map_err(write_error)?;
+
+        // `mode` only applies to newly created files
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(std::fs::Permissions::from_mode(0o600))
+                .await
+                .map_err(write_error)?;
+        }
+
+        file.write_all(json.as_bytes()).await.map_err(write_error)?;
+        file.flush().await.map_err(write_error)?;
+
+        debug!("Session saved to {}", path.as_ref().display());
+        Ok(())
+    }
+
+    /// Load a session saved with [`save_session`](Self::save_session).
+    ///
+    /// The session is validated with a `state_login` call and discarded if the
+    /// device no longer reports it as logged in. Returns whether it was restored.
+    pub async fn load_session<P: AsRef<Path>>(&self, path: P) -> Result<bool> {
+        let json = tokio::fs::read_to_string(path.as_ref())
+            .await
+            .map_err(|e| Error::generic(format!("Failed to read session file: {}", e)))?;
+        let state: SerializableSession = serde_json::from_str(&json)
+            .map_err(|e| Error::generic(format!("Failed to parse session file: {}", e)))?;
+
+        self.session.import_state(state).await;
+
+        if self.auth().state_login().await?.is_logged_in() {
+            debug!("Session restored from {}", path.as_ref().display());
+            Ok(true)
+        } else {
+            debug!("Saved session is no longer logged in, discarding");
+            self.session.clear_session().await;
+            Ok(false)
+        }
+    }
+
+    /// Forget stored credentials, disabling automatic re-login
+    pub fn clear_credentials(&self) {
+        *self.credentials.lock().unwrap() = None;
+    }
+
+    pub fn device(&self) -> api::device::DeviceApi<'_> {
         api::device::DeviceApi::new(self)
     }
 
-    pub fn monitoring(&self) -> api::monitoring::MonitoringApi {
+    pub fn monitoring(&self) -> api::monitoring::MonitoringApi<'_> {
         api::monitoring::MonitoringApi::new(self)
     }
 
-    pub fn network(&self) -> api::network::NetworkApi {
+    pub fn network(&self) -> api::network::NetworkApi<'_> {
         api::network::NetworkApi::new(self)
     }
 
-    pub fn sms(&self) -> api::sms::SmsApi {
+    pub fn sms(&self) -> api::sms::SmsApi<'_> {
         api::sms::SmsApi::new(self)
     }
 
-    pub fn dhcp(&self) -> api::dhcp::DhcpApi {
+    pub fn dhcp(&self) -> api::dhcp::DhcpApi<'_> {
         api::dhcp::DhcpApi::new(self)
     }
 
-    pub fn auth(&self) -> api::auth::AuthApi {
+    pub fn dialup(&self) -> api::dialup::DialupApi<'_> {
+        api::dialup::DialupApi::new(self)
+    }
+
+    pub fn pin(&self) -> api::pin::PinApi<'_> {
+        api::pin::PinApi::new(self)
+    }
+
+    pub fn ussd(&self) -> api::ussd::UssdApi<'_> {
+        api::ussd::UssdApi::new(self)
+    }
+
+    pub fn security(&self) -> api::security::SecurityApi<'_> {
+        api::security::SecurityApi::new(self)
+    }
+
+    pub fn wlan(&self) -> api::wlan::WlanApi<'_> {
+        api::wlan::WlanApi::new(self)
+    }
+
+    pub fn auth(&self) -> api::auth::AuthApi<'_> {
         api::auth::AuthApi::new(self)
     }
 
+    /// Current request, retry and token refresh counters and the latency
+    /// histogram, shared with clients derived from this one
+    pub fn metrics_snapshot(&self) -> MetricsSnapshot {
+        self.metrics.snapshot()
+    }
+
+    /// State of the circuit breaker, or `None` if it is not enabled
+    pub fn circuit_state(&self) -> Option<CircuitState> {
+        self.circuit_breaker.as_ref().map(|breaker| breaker.state())
+    }
+
+    /// A client sharing this client's connection pool, session, credentials
+    /// and circuit breaker, but making a single attempt per request.
+    ///
+    /// Useful for interactive tools that refresh often and would rather
+    /// show an error than wait for retry backoff.
+    pub fn without_retries(&self) -> Client {
+        let mut client = self.shared();
+        client.config.max_retries = 1;
+        client.retry_strategy.max_attempts = 1;
+        client
+    }
+
+    /// A client sharing this client's connection pool, session, circuit
+    /// breaker and read cache, with a copy of its stored credentials
+    pub(crate) fn shared(&self) -> Client {
+        Client {
+            http_client: self.http_client.clone(),
+            probe_client: self.probe_client.clone(),
+            config: self.config.clone(),
+            session: self.session.clone(),
+            retry_strategy: self.retry_strategy.clone(),
+            credentials: Mutex::new(self.credentials.lock().unwrap().clone()),
+            circuit_breaker: self.circuit_breaker.clone(),
+            read_cache: self.read_cache.clone(),
+            capabilities: self.capabilities.clone(),
+            metrics: self.metrics.clone(),
+        }
+    }
+
+    /// Run one request attempt through the circuit breaker, if one is
+    /// enabled. Only connect errors and timeouts count as failures.
+    async fn guarded<T, Fut>(&self, attempt: Fut) -> Result<T>
+    where
+        Fut: std::future::Future<Output = Result<T>>,
+    {
+        let Some(breaker) = &self.circuit_breaker else {
+            return attempt.await;
+        };
+
+        // Dropping this future mid-request drops the permit, which frees a
+        // half-open circuit for the next trial
+        let permit = breaker.acquire()?;
+        let result = attempt.await;
+        match &result {
+            Err(Error::Http(e)) if e.is_connect() || e.is_timeout() => permit.failure(),
+            _ => permit.success(),
+        }
+        result
+    }
+
+    /// Fetch device information, current operator, connection status and
+    /// traffic statistics concurrently.
+    ///
+    /// Status and traffic statistics require authentication; if the client
+    /// is not logged in they are `None` instead of failing the snapshot.
+    pub async fn snapshot(&self) -> Result<DeviceSnapshot> {
+        let device = self.device();
+        let network = self.network();
+        let monitoring = self.monitoring();
+
+        let (device, plmn, status, traffic) = tokio::try_join!(
+            device.information(),
+            network.current_plmn(),
+            async { unless_unauthorized(monitoring.status().await) },
+            async { unless_unauthorized(monitoring.traffic_statistics().await) },
+        )?;
+
+        Ok(DeviceSnapshot {
+            device,
+            plmn,
+            status,
+            traffic,
+        })
+    }
+
+    /// Probe which optional endpoint groups the firmware provides.
+    ///
+    /// Each endpoint is fetched once, without retries; the result is cached
+    /// for the lifetime of the client. Errors other than "not found" or
+    /// "not supported" (e.g. the device being unreachable) are returned and
+    /// nothing is cached.
+    pub async fn capabilities(&self) -> Result<Capabilities> {
+        if let Some(capabilities) = *self.capabilities.lock().unwrap() {
+            return Ok(capabilities);
+        }
+
+        let probe = self.without_retries();
+        let (device, monitoring, traffic, wlan, sms, dialup) = tokio::try_join!(
+            probe.endpoint_exists("/api/device/information"),
+            probe.endpoint_exists("/api/monitoring/status"),
+            probe.endpoint_exists("/api/monitoring/traffic-statistics"),
+            probe.endpoint_exists("/api/wlan/basic-settings"),
+            probe.endpoint_exists("/api/sms/config"),
+            probe.endpoint_exists("/api/dialup/mobile-dataswitch"),
+        )?;
+
+        let capabilities = Capabilities {
+            has_device_information: device,
+            has_monitoring: monitoring,
+            has_traffic_stats: traffic,
+            has_wlan: wlan,
+            has_sms: sms,
+            has_dialup: dialup,
+        };
+        debug!("Discovered capabilities: {:?}", capabilities);
+        *self.capabilities.lock().unwrap() = Some(capabilities);
+        Ok(capabilities)
+    }
+
+    /// Whether `path` exists on this firmware, see [`capabilities`](Self::capabilities)
+    async fn endpoint_exists(&self, path: &str) -> Result<bool> {
+        match self.get_raw(path).await {
+            Ok(_) | Err(Error::LoginRequired | Error::Authentication { .. }) => Ok(true),
+            Err(Error::Api { code: 404 | error_codes::NO_SUPPORT, .. }) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Keep the session alive in the background.
+    ///
+    /// Every `interval` the task fetches `/api/user/state-login` and, once the
+    /// session is older than [`KEEPALIVE_REFRESH_AGE_MINUTES`], refreshes the
+    /// CSRF token. The task holds only a weak reference and ends when the
+    /// client is dropped; dropping the returned [`KeepaliveHandle`] stops it
+    /// earlier.
+    pub fn spawn_keepalive(self: &Arc<Self>, interval: Duration) -> KeepaliveHandle {
+        let client: Weak<Self> = Arc::downgrade(self);
+
+        let task = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            // The first tick completes immediately
+            ticker.tick().await;
+
+            loop {
+                ticker.tick().await;
+                let Some(client) = client.upgrade() else {
+                    debug!("Client dropped, stopping keepalive");
+                    return;
+                };
+
+                if let Err(e) = client.auth().state_login().await {
+                    debug!("Keepalive state-login failed: {}", e);
+                }
+
+                if client
+                    .session
+                    .is_session_expired(KEEPALIVE_REFRESH_AGE_MINUTES)
+                    .await
+                {
+                    match client.session.refresh_csrf_token().await {
+                        Ok(_) => debug!("Keepalive refreshed CSRF token"),
+                        Err(e) => debug!("Keepalive token refresh failed: {}", e),
+                    }
+                }
+            }
+        });
+
+        KeepaliveHandle { task }
+    }
+
+    /// Check that the device answers HTTP and return the round-trip time.
+    ///
+    /// Sends a single unauthenticated GET with [`PING_TIMEOUT`], bypassing
+    /// retries, the circuit breaker and session handling, so it is safe to
+    /// use as a health check before or between other requests. The request
+    /// does not use the cookie store, so cookies set by the device are not
+    /// kept.
+    pub async fn ping(&self) -> Result<Duration> {
+        let url = self.build_url(PING_PATH)?;
+        trace!("PING {}", url);
+
+        let started = std::time::Instant::now();
+        let response = self
+            .probe_client
+            .get(url)
+            .timeout(PING_TIMEOUT)
+            .send()
+            .await?;
+        let elapsed = started.elapsed();
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(Error::api(
+                status.as_u16() as i32,
+                format!("Device did not answer ping: HTTP {}", status),
+            ));
+        }
+
+        debug!("Device answered ping in {:?}", elapsed);
+        Ok(elapsed)
+    }
+
     pub(crate) fn session(&self) -> &SessionManager {
         &self.session
     }
 
-
     pub(crate) async fn get(&self, path: &str) -> Result<Response> {
+        traced(
+            &self.metrics,
+            request_span("GET", path),
+            self.get_internal(path, None, |response| future::ready(Ok(response))),
+        )
+        .await
+    }
+
+    /// Unauthenticated GET returning the error-checked body, served from the
+    /// read cache while fresh when `Config.read_cache_ttl` is set. Only use
+    /// this for endpoints whose data rarely changes.
+    pub(crate) async fn get_cached_text(&self, path: &str) -> Result<String> {
+        if let Some(ttl) = self.config.read_cache_ttl {
+            if let Some(text) = self.read_cache.get(path, ttl) {
+                trace!("GET {} served from read cache", path);
+                return Ok(text);
+            }
+        }
+
+        let text = self.get_uncached_text(path).await?;
+
+        if self.config.read_cache_ttl.is_some() {
+            self.read_cache.insert(path, text.clone());
+        }
+        Ok(text)
+    }
+
+    /// Unauthenticated GET returning the error-checked body, always sent to
+    /// the device
+    pub(crate) async fn get_uncached_text(&self, path: &str) -> Result<String> {
+        traced(
+            &self.metrics,
+            request_span("GET", path),
+            self.get_internal(path, None, |response| self.read_checked(response)),
+        )
+        .await?
+    }
+
+    /// Drop all cached reads, e.g. after changing the device configuration
+    pub fn clear_read_cache(&self) {
+        self.read_cache.entries.write().unwrap().clear();
+    }
+
+    /// GET with a timeout overriding `Config.timeout` for this request only,
+    /// e.g. a longer budget for slow operations or a short one for status polls.
+    /// The body is not checked for device errors.
+    pub async fn get_with_timeout(&self, path: &str, timeout: Duration) -> Result<Response> {
+        traced(
+            &self.metrics,
+            request_span("GET", path),
+            self.get_internal(path, Some(timeout), |response| future::ready(Ok(response))),
+        )
+        .await
+    }
+
+    /// GET `path`, passing each response to `finish` inside the retry loop
+    async fn get_internal<T, F, Fut>(&self, path: &str, timeout: Option<Duration>, finish: F) -> Result<T>
+    where
+        F: Fn(Response) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
         let url = self.build_url(path)?;
         trace!("GET {}", url);
 
         self.retry_strategy
-            .execute(|| async {
-                let response = self.http_client.get(url.clone()).send().await?;
-                self.check_response_status(&response).await?;
+            .execute(|| self.guarded(async {
+                let mut request = self.http_client.get(url.clone());
+                if let Some(timeout) = timeout {
+                    request = request.timeout(timeout);
+                }
+                let response = self.send("GET", &url, request).await?;
                 self.session.update_token_from_headers(response.headers()).await;
-                Ok(response)
-            })
+                finish(response).await
+            }))
             .await
     }
 
-    pub(crate) async fn get_authenticated(&self, path: &str) -> Result<Response> {
+    /// Authenticated GET with an optional timeout overriding `Config.timeout`
+    pub(crate) async fn get_authenticated(
+        &self,
+        path: &str,
+        timeout: Option<Duration>,
+    ) -> Result<Response> {
+        self.get_authenticated_then(path, timeout, |response| future::ready(Ok(response)))
+            .await
+    }
+
+    /// Authenticated GET, passing each response to `finish` inside the retry loop
+    async fn get_authenticated_then<T, F, Fut>(
+        &self,
+        path: &str,
+        timeout: Option<Duration>,
+        finish: F,
+    ) -> Result<T>
+    where
+        F: Fn(Response) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
         let url = self.build_url(path)?;
         trace!("GET {} (authenticated)", url);
 
-        let result = self.get_authenticated_internal(&url).await;
+        let result = self.get_authenticated_internal(&url, timeout, &finish).await;
         match &result {
             Err(Error::CsrfTokenInvalid) | Err(Error::SessionTokenInvalid) => {
                 debug!("CSRF/Session error detected, refreshing token and retrying");
-                self.session.refresh_csrf_token().await?;
-                self.get_authenticated_internal(&url).await
+                self.refresh_token().await?;
+                self.get_authenticated_internal(&url, timeout, &finish).await
             }
             _ => result
         }
     }
 
     /// Internal GET implementation
-    async fn get_authenticated_internal(&self, url: &Url) -> Result<Response> {
+    async fn get_authenticated_internal<T, F, Fut>(
+        &self,
+        url: &Url,
+        timeout: Option<Duration>,
+        finish: F,
+    ) -> Result<T>
+    where
+        F: Fn(Response) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
         self.retry_strategy
-            .execute(|| async {
+            .execute(|| self.guarded(async {
                 let csrf_token = self.session.get_csrf_token().await?;
 
-                let response = self
+                let mut request = self
                     .http_client
                     .get(url.clone())
                     .header("X-Requested-With", "XMLHttpRequest")
-                    .header("__RequestVerificationToken", &csrf_token)
-                    .send()
-                    .await?;
-
-                self.check_response_status(&response).await?;
+                    .header("__RequestVerificationToken", &csrf_token);
+                if let Some(timeout) = timeout {
+                    request = request.timeout(timeout);
+                }
+                let response = self.send("GET", url, request).await?;
                 self.session.update_token_from_headers(response.headers()).await;
-                Ok(response)
-            })
+                finish(response).await
+            }))
             .await
     }
 
     pub(crate) async fn post_xml(&self, path: &str, xml_body: &str) -> Result<Response> {
+        traced(
+            &self.metrics,
+            authenticated_span("POST", path),
+            self.post_xml_timeout(path, xml_body, None),
+        )
+        .await
+    }
+
+    /// POST with a timeout overriding `Config.timeout` for this request only.
+    /// The request carries a CSRF token; the body is not checked for device errors.
+    pub async fn post_xml_with_timeout(
+        &self,
+        path: &str,
+        xml_body: &str,
+        timeout: Duration,
+    ) -> Result<Response> {
+        traced(
+            &self.metrics,
+            authenticated_span("POST", path),
+            self.post_xml_timeout(path, xml_body, Some(timeout)),
+        )
+        .await
+    }
+
+    async fn post_xml_timeout(&self, path: &str, xml_body: &str, timeout: Option<Duration>) -> Result<Response> {
+        self.post_xml_then(path, xml_body, timeout, |response| future::ready(Ok(response)))
+            .await
+    }
+
+    /// POST `xml_body`, passing each response to `finish` inside the retry loop
+    async fn post_xml_then<T, F, Fut>(
+        &self,
+        path: &str,
+        xml_body: &str,
+        timeout: Option<Duration>,
+        finish: F,
+    ) -> Result<T>
+    where
+        F: Fn(Response) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
         let url = self.build_url(path)?;
         trace!("POST {} with XML body", url);
 
-        let result = self.post_xml_internal(&url, xml_body).await;
+        if self.config.dry_run && !DRY_RUN_SENT_PATHS.contains(&url.path()) {
+            info!("Dry run, not sending POST {}: {}", url, dry_run_body(url.path(), xml_body));
+            return finish(Response::from(http::Response::new(DRY_RUN_RESPONSE))).await;
+        }
+
+        let result = self.post_xml_internal(&url, xml_body, timeout, &finish).await;
         match &result {
             Err(Error::CsrfTokenInvalid) | Err(Error::SessionTokenInvalid) => {
                 debug!("CSRF/Session error detected, refreshing token and retrying");
-                self.session.refresh_csrf_token().await?;
-                self.post_xml_internal(&url, xml_body).await
+                self.refresh_token().await?;
+                self.post_xml_internal(&url, xml_body, timeout, &finish).await
             }
             _ => result
         }
     }
 
     /// Internal POST implementation
-    async fn post_xml_internal(&self, url: &Url, xml_body: &str) -> Result<Response> {
+    async fn post_xml_internal<T, F, Fut>(
+        &self,
+        url: &Url,
+        xml_body: &str,
+        timeout: Option<Duration>,
+        finish: F,
+    ) -> Result<T>
+    where
+        F: Fn(Response) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
         self.retry_strategy
-            .execute(|| async {
+            .execute(|| self.guarded(async {
                 let csrf_token = self.session.get_csrf_token().await?;
 
-                let response = self
+                let mut request = self
                     .http_client
                     .post(url.clone())
                     .header(
@@ -233,18 +845,17 @@ impl Client {
                     )
                     .header("X-Requested-With", "XMLHttpRequest")
                     .header("__RequestVerificationToken", &csrf_token)
-                    .body(xml_body.to_string())
-                    .send()
-                    .await?;
-
-                self.check_response_status(&response).await?;
+                    .body(xml_body.to_string());
+                if let Some(timeout) = timeout {
+                    request = request.timeout(timeout);
+                }
+                let response = self.send("POST", url, request).await?;
                 self.session.update_token_from_headers(response.headers()).await;
-                Ok(response)
-            })
+                finish(response).await
+            }))
             .await
     }
 
-
     fn build_url(&self, path: &str) -> Result<Url> {
         let path = if path.starts_with('/') {
             path.to_string()
@@ -255,6 +866,40 @@ impl Client {
         Ok(self.config.base_url.join(&path)?)
     }
 
+    /// Send one HTTP request and check its status, notifying the configured
+    /// observer before and after
+    async fn send(
+        &self,
+        method: &'static str,
+        url: &Url,
+        request: reqwest::RequestBuilder,
+    ) -> Result<Response> {
+        let Some(observer) = &self.config.observer else {
+            let response = request.send().await?;
+            self.check_response_status(&response).await?;
+            return Ok(response);
+        };
+
+        let path = url.path();
+        observer::notify(|| observer.on_request(method, path));
+
+        let started = Instant::now();
+        let (status, result) = match request.send().await {
+            Ok(response) => {
+                let status = response.status().as_u16();
+                let checked = self.check_response_status(&response).await;
+                (Some(status), checked.map(|()| response))
+            }
+            Err(e) => (None, Err(Error::from(e))),
+        };
+        let latency = started.elapsed();
+        observer::notify(|| {
+            observer.on_response(path, status, latency, result.as_ref().map(|_| ()))
+        });
+
+        result
+    }
+
     /// Check response status and handle common error cases
     async fn check_response_status(&self, response: &Response) -> Result<()> {
         let status = response.status();
@@ -310,23 +955,75 @@ impl Client {
         Ok(())
     }
 
+    /// Read and error-check a response body inside the retry loop, so device
+    /// errors such as [`Error::SystemBusy`] are retried like HTTP failures.
+    /// CSRF and session token errors come back as `Ok(Err(_))`: the caller
+    /// handles them by refreshing the token rather than retrying with the
+    /// stale one.
+    async fn read_checked(&self, response: Response) -> Result<Result<String>> {
+        let text = read_text(response).await?;
+        match self.check_xml_for_errors(&text).await {
+            Ok(()) => Ok(Ok(text)),
+            Err(e @ (Error::CsrfTokenInvalid | Error::SessionTokenInvalid)) => Ok(Err(e)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Refresh the CSRF token and mark the current request span as having done so
+    async fn refresh_token(&self) -> Result<String> {
+        Span::current().record("token_refreshed", true);
+        self.metrics.record_token_refresh();
+        self.session.refresh_csrf_token().await
+    }
+
+    /// Log in again with stored credentials after the session expired.
+    /// Returns `false` if no credentials are stored.
+    async fn relogin(&self) -> Result<bool> {
+        let credentials = self.credentials.lock().unwrap().clone();
+
+        match credentials {
+            Some(credentials) => {
+                debug!("Session expired, logging in again as {}", credentials.username);
+                self.session.invalidate_session().await;
+                self.auth()
+                    .login(&credentials.username, &credentials.password)
+                    .await?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
     /// Execute a POST request with automatic CSRF token refresh on failure
+    /// and re-login with stored credentials if the session expired
     pub(crate) async fn post_xml_with_retry<F, T>(&self, path: &str, xml_body: &str, parse_fn: F) -> Result<T>
     where
         F: Fn(&str) -> Result<T>,
     {
-        let response = self.post_xml(path, xml_body).await?;
-        let text = response.text().await?;
-        
-        match self.check_xml_for_errors(&text).await {
-            Ok(()) => parse_fn(&text),
+        let request = async {
+            match self.post_xml_with_token_retry(path, xml_body, &parse_fn).await {
+                Err(Error::LoginRequired) if self.relogin().await? => {
+                    self.post_xml_with_token_retry(path, xml_body, &parse_fn).await
+                }
+                result => result,
+            }
+        };
+        traced(&self.metrics, authenticated_span("POST", path), request).await
+    }
+
+    async fn post_xml_with_token_retry<F, T>(&self, path: &str, xml_body: &str, parse_fn: F) -> Result<T>
+    where
+        F: Fn(&str) -> Result<T>,
+    {
+        let checked = |response| self.read_checked(response);
+
+        match self.post_xml_then(path, xml_body, None, checked).await? {
+            Ok(text) => parse_fn(&text),
             Err(Error::CsrfTokenInvalid) | Err(Error::SessionTokenInvalid) => {
                 debug!("CSRF/Session error in response, refreshing token and retrying");
-                self.session.refresh_csrf_token().await?;
-                
-                let response = self.post_xml(path, xml_body).await?;
-                let text = response.text().await?;
-                self.check_xml_for_errors(&text).await?;
+                self.refresh_token().await?;
+
+                let text = self.post_xml_then(path, xml_body, None, checked).await??;
                 parse_fn(&text)
             }
             Err(e) => Err(e),
@@ -334,28 +1031,86 @@ impl Client {
     }
 
     /// Execute a GET request with automatic CSRF token refresh on failure
+    /// and re-login with stored credentials if the session expired
     pub(crate) async fn get_authenticated_with_retry<F, T>(&self, path: &str, parse_fn: F) -> Result<T>
     where
         F: Fn(&str) -> Result<T>,
     {
-        let response = self.get_authenticated(path).await?;
-        let text = response.text().await?;
-        
-        match self.check_xml_for_errors(&text).await {
-            Ok(()) => parse_fn(&text),
+        self.get_authenticated_with_retry_timeout(path, None, parse_fn).await
+    }
+
+    /// Same as [`get_authenticated_with_retry`](Self::get_authenticated_with_retry),
+    /// with an optional timeout for slow endpoints
+    pub(crate) async fn get_authenticated_with_retry_timeout<F, T>(
+        &self,
+        path: &str,
+        timeout: Option<Duration>,
+        parse_fn: F,
+    ) -> Result<T>
+    where
+        F: Fn(&str) -> Result<T>,
+    {
+        let request = async {
+            match self.get_authenticated_with_token_retry(path, timeout, &parse_fn).await {
+                Err(Error::LoginRequired) if self.relogin().await? => {
+                    self.get_authenticated_with_token_retry(path, timeout, &parse_fn).await
+                }
+                result => result,
+            }
+        };
+        traced(&self.metrics, authenticated_span("GET", path), request).await
+    }
+
+    async fn get_authenticated_with_token_retry<F, T>(
+        &self,
+        path: &str,
+        timeout: Option<Duration>,
+        parse_fn: F,
+    ) -> Result<T>
+    where
+        F: Fn(&str) -> Result<T>,
+    {
+        let checked = |response| self.read_checked(response);
+
+        match self.get_authenticated_then(path, timeout, checked).await? {
+            Ok(text) => parse_fn(&text),
             Err(Error::CsrfTokenInvalid) | Err(Error::SessionTokenInvalid) => {
                 debug!("CSRF/Session error in response, refreshing token and retrying");
-                self.session.refresh_csrf_token().await?;
-                
-                let response = self.get_authenticated(path).await?;
-                let text = response.text().await?;
-                self.check_xml_for_errors(&text).await?;
+                self.refresh_token().await?;
+
+                let text = self.get_authenticated_then(path, timeout, checked).await??;
                 parse_fn(&text)
             }
             Err(e) => Err(e),
         }
     }
 
+    /// GET an endpoint that has no typed wrapper and return the raw XML body.
+    ///
+    /// The request goes through the same CSRF refresh, re-login and error
+    /// checking as the typed APIs; device error responses are returned as
+    /// [`Error::api`]. Callers parse the XML themselves.
+    ///
+    /// ```no_run
+    /// # use huawei_dongle_api::Client;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::for_url("http://192.168.8.1")?;
+    /// let xml = client.get_raw("/api/device/basic_information").await?;
+    /// println!("{}", xml);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_raw(&self, path: &str) -> Result<String> {
+        self.get_authenticated_with_retry(path, |text| Ok(text.to_string())).await
+    }
+
+    /// POST an XML body to an endpoint that has no typed wrapper and return the raw XML response.
+    ///
+    /// See [`get_raw`](Self::get_raw) for error handling. Callers build and parse the XML themselves.
+    pub async fn post_raw(&self, path: &str, xml: &str) -> Result<String> {
+        self.post_xml_with_retry(path, xml, |text| Ok(text.to_string())).await
+    }
+
     pub fn base_url(&self) -> &Url {
         &self.config.base_url
     }
@@ -365,9 +1120,82 @@ impl Client {
     }
 }
 
+/// Request body as logged in dry-run mode, redacted for sensitive endpoints
+fn dry_run_body<'a>(path: &str, body: &'a str) -> Cow<'a, str> {
+    if DRY_RUN_REDACTED_PATHS.contains(&path) {
+        Cow::Owned(format!("<{} bytes redacted>", body.len()))
+    } else {
+        Cow::Borrowed(body)
+    }
+}
+
+/// Span for one API call. Only the method and path are recorded, never
+/// request bodies, credentials or tokens.
+fn request_span(method: &'static str, endpoint: &str) -> Span {
+    debug_span!("api_request", method, endpoint, latency_ms = field::Empty)
+}
+
+/// Span for an API call sent with a CSRF token
+fn authenticated_span(method: &'static str, endpoint: &str) -> Span {
+    debug_span!(
+        "api_request",
+        method,
+        endpoint,
+        token_refreshed = false,
+        latency_ms = field::Empty
+    )
+}
+
+/// Run `request` inside `span` and record its latency on the span and in `metrics`
+async fn traced<T, Fut>(metrics: &Metrics, span: Span, request: Fut) -> Result<T>
+where
+    Fut: Future<Output = Result<T>>,
+{
+    let started = Instant::now();
+    let result = request.instrument(span.clone()).await;
+    let latency = started.elapsed();
+    span.record("latency_ms", latency.as_millis() as u64);
+    metrics.record_request(latency, result.is_err());
+    result
+}
+
+/// Builder with the connection settings from `config`, without a cookie store
+fn http_client_builder(config: &Config) -> Result<ClientBuilder> {
+    let mut builder = ClientBuilder::new()
+        .default_headers(config.default_headers.clone())
+        .timeout(config.timeout)
+        .user_agent(&config.user_agent)
+        .pool_max_idle_per_host(config.pool_max_idle_per_host)
+        .pool_idle_timeout(config.pool_idle_timeout)
+        .tcp_keepalive(config.tcp_keepalive)
+        .danger_accept_invalid_certs(config.accept_invalid_certs);
+
+    if let Some(proxy) = &config.proxy {
+        let proxy = reqwest::Proxy::all(proxy.as_str())
+            .map_err(|e| Error::config(format!("Invalid proxy URL: {}", e)))?;
+        builder = builder.proxy(proxy);
+    }
+
+    if config.http1_only {
+        builder = builder.http1_only();
+    }
+
+    Ok(builder)
+}
+
+/// Turn a login error into `None`, keeping other errors
+fn unless_unauthorized<T>(result: Result<T>) -> Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(Error::LoginRequired | Error::Authentication { .. }) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::{device_information_xml, login_state_xml, token_mock};
 
     #[test]
     fn test_client_creation() {
@@ -395,4 +1223,1123 @@ mod tests {
         let url = client.build_url("api/device/information").unwrap();
         assert_eq!(url.as_str(), "http://192.168.8.1/api/device/information");
     }
+
+    const NO_RIGHTS: &str = "<error><code>100003</code><message></message></error>";
+
+    #[tokio::test]
+    async fn test_relogin_on_expired_session() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+
+        server
+            .mock("GET", "/api/user/state-login")
+            .with_body(login_state_xml("4"))
+            .create_async()
+            .await;
+
+        let expired = server
+            .mock("GET", "/api/dialup/mobile-dataswitch")
+            .with_body(NO_RIGHTS)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let login = server
+            .mock("POST", "/api/user/login")
+            .with_body("<response>OK</response>")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        client.set_credentials("admin", "admin");
+
+        let data = server
+            .mock("GET", "/api/dialup/mobile-dataswitch")
+            .with_body("<response><dataswitch>1</dataswitch></response>")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let switch = client.dialup().mobile_dataswitch().await.unwrap();
+        assert!(switch.is_enabled());
+
+        expired.assert_async().await;
+        login.assert_async().await;
+        data.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_no_relogin_without_credentials() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+
+        server
+            .mock("GET", "/api/dialup/mobile-dataswitch")
+            .with_body(NO_RIGHTS)
+            .create_async()
+            .await;
+
+        let login = server
+            .mock("POST", "/api/user/login")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        let result = client.dialup().mobile_dataswitch().await;
+
+        assert!(matches!(result, Err(Error::LoginRequired)));
+        login.assert_async().await;
+    }
+
+    /// Mock `path` answering `failures` device errors `code`, then `ok_body`;
+    /// returns the hit counter
+    async fn mock_failing_then_ok(
+        server: &mut mockito::ServerGuard,
+        path: &str,
+        code: i32,
+        failures: usize,
+        ok_body: &'static str,
+    ) -> Arc<std::sync::atomic::AtomicUsize> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let hits = Arc::new(AtomicUsize::new(0));
+        let hits_seen = hits.clone();
+        server
+            .mock("GET", path)
+            .with_body_from_request(move |_| {
+                if hits_seen.fetch_add(1, Ordering::SeqCst) < failures {
+                    format!("<error><code>{}</code><message></message></error>", code).into_bytes()
+                } else {
+                    ok_body.as_bytes().to_vec()
+                }
+            })
+            .create_async()
+            .await;
+        hits
+    }
+
+    #[tokio::test]
+    async fn test_system_busy_body_retried() {
+        use std::sync::atomic::Ordering;
+
+        let mut server = mockito::Server::new_async().await;
+        token_mock(&mut server).await;
+        let hits = mock_failing_then_ok(
+            &mut server,
+            "/api/dialup/mobile-dataswitch",
+            error_codes::SYSTEM_BUSY,
+            2,
+            "<response><dataswitch>1</dataswitch></response>",
+        )
+        .await;
+
+        let config = Config::builder()
+            .base_url(server.url())
+            .retry_delay(Duration::from_millis(1))
+            .build()
+            .unwrap();
+        let mut client = Client::new(config).unwrap();
+        client.retry_strategy.system_busy_delay = Duration::from_millis(1);
+
+        let switch = client.dialup().mobile_dataswitch().await.unwrap();
+        assert!(switch.is_enabled());
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retryable_api_codes_allowlist() {
+        use std::sync::atomic::Ordering;
+
+        for (allowlist, expected_hits) in [(vec![], 1), (vec![error_codes::SMS_PROCESSING], 3)] {
+            let mut server = mockito::Server::new_async().await;
+            token_mock(&mut server).await;
+            let hits = mock_failing_then_ok(
+                &mut server,
+                "/api/dialup/mobile-dataswitch",
+                error_codes::SMS_PROCESSING,
+                2,
+                "<response><dataswitch>1</dataswitch></response>",
+            )
+            .await;
+
+            let config = Config::builder()
+                .base_url(server.url())
+                .retry_delay(Duration::from_millis(1))
+                .retryable_api_codes(allowlist.clone())
+                .build()
+                .unwrap();
+            let client = Client::new(config).unwrap();
+
+            let result = client.dialup().mobile_dataswitch().await;
+            assert_eq!(result.is_ok(), !allowlist.is_empty());
+            assert_eq!(hits.load(Ordering::SeqCst), expected_hits);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_on_retry_hook_from_config() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/monitoring/status")
+            .with_status(503)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let retries = Arc::new(Mutex::new(Vec::new()));
+        let retries_seen = retries.clone();
+        let config = Config::builder()
+            .base_url(server.url())
+            .retry_delay(Duration::from_millis(1))
+            .on_retry(move |attempt, error, _| {
+                retries_seen.lock().unwrap().push((attempt, error.to_string()));
+            })
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+
+        assert!(client.get("/api/monitoring/status").await.is_err());
+
+        let retries = retries.lock().unwrap();
+        assert_eq!(retries.iter().map(|(attempt, _)| *attempt).collect::<Vec<_>>(), vec![1, 2]);
+        assert!(retries[0].1.contains("503"));
+        assert_eq!(client.metrics_snapshot().retries_total, 2);
+        assert!(format!("{:?}", client.config()).contains("on_retry: Some(\"<hook>\")"));
+    }
+
+    #[test]
+    fn test_jitter_strategy_from_config() {
+        let client = Client::for_url("http://192.168.8.1").unwrap();
+        assert_eq!(client.retry_strategy.jitter_strategy, None);
+
+        let config = Config::builder()
+            .jitter_strategy(crate::retry::Jitter::Full)
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+        assert_eq!(client.retry_strategy.jitter_strategy, Some(crate::retry::Jitter::Full));
+    }
+
+    #[test]
+    fn test_credentials_debug_redacts_password() {
+        let client = Client::for_url("http://192.168.8.1").unwrap();
+        client.set_credentials("admin", "secret");
+        assert!(!format!("{:?}", client).contains("secret"));
+    }
+
+    #[test]
+    fn test_client_with_https_and_invalid_certs() {
+        let config = Config::builder()
+            .base_url("https://192.168.8.1")
+            .accept_invalid_certs(true)
+            .build()
+            .unwrap();
+
+        assert!(config.accept_invalid_certs);
+
+        let client = Client::new(config).unwrap();
+        let url = client.build_url("/api/webserver/SesTokInfo").unwrap();
+        assert_eq!(url.as_str(), "https://192.168.8.1/api/webserver/SesTokInfo");
+    }
+
+    #[test]
+    fn test_client_with_proxy() {
+        let config = Config::builder()
+            .proxy("http://10.0.0.1:3128")
+            .build()
+            .unwrap();
+        assert!(Client::new(config).is_ok());
+
+        assert!(Config::builder().proxy("::invalid::").build().is_err());
+    }
+
+    #[test]
+    fn test_client_with_socks5_proxy() {
+        let config = Config::builder()
+            .proxy("socks5://127.0.0.1:1080")
+            .build()
+            .unwrap();
+        assert!(Client::new(config).is_ok());
+
+        let config = Config::builder()
+            .proxy("socks5h://127.0.0.1:1080")
+            .build()
+            .unwrap();
+        assert!(Client::new(config).is_ok());
+    }
+
+    #[test]
+    fn test_client_with_connection_options() {
+        let config = Config::builder()
+            .pool_max_idle_per_host(2)
+            .pool_idle_timeout(Duration::from_secs(30))
+            .tcp_keepalive(Duration::from_secs(60))
+            .http1_only(true)
+            .build()
+            .unwrap();
+        assert_eq!(config.pool_max_idle_per_host, 2);
+        assert_eq!(config.pool_idle_timeout, Some(Duration::from_secs(30)));
+        assert_eq!(config.tcp_keepalive, Some(Duration::from_secs(60)));
+        assert!(config.http1_only);
+
+        assert!(Client::new(config).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_default_headers_are_sent() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+
+        let mock = server
+            .mock("GET", "/api/device/basic_information")
+            .match_header("x-auth-token", "secret")
+            .with_body("<response></response>")
+            .create_async()
+            .await;
+
+        let config = Config::builder()
+            .base_url(server.url())
+            .default_header("X-Auth-Token", "secret")
+            .cookie_store(false)
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+
+        client.get_raw("/api/device/basic_information").await.unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_per_request_timeout() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+
+        for (method, path) in [("GET", "/api/net/plmn-list"), ("POST", "/api/net/register")] {
+            server
+                .mock(method, path)
+                .with_body_from_request(|_| {
+                    std::thread::sleep(std::time::Duration::from_millis(500));
+                    b"<response>OK</response>".to_vec()
+                })
+                .create_async()
+                .await;
+        }
+
+        let config = Config::builder()
+            .base_url(server.url())
+            .max_retries(1)
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+        let timeout = Duration::from_millis(50);
+
+        let result = client.get_with_timeout("/api/net/plmn-list", timeout).await;
+        assert!(matches!(result, Err(Error::Http(e)) if e.is_timeout()));
+
+        let result = client
+            .post_xml_with_timeout("/api/net/register", "<request/>", timeout)
+            .await;
+        assert!(matches!(result, Err(Error::Http(e)) if e.is_timeout()));
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_transitions() {
+        use crate::circuit::{CircuitBreakerConfig, CircuitState};
+
+        let mut server = mockito::Server::new_async().await;
+        let slow = server
+            .mock("GET", "/api/monitoring/status")
+            .with_body_from_request(|_| {
+                std::thread::sleep(std::time::Duration::from_millis(200));
+                b"<response>OK</response>".to_vec()
+            })
+            .create_async()
+            .await;
+
+        let config = Config::builder()
+            .base_url(server.url())
+            .timeout(Duration::from_millis(50))
+            .max_retries(1)
+            .circuit_breaker(CircuitBreakerConfig {
+                failure_threshold: 2,
+                window: Duration::from_secs(60),
+                cooldown: Duration::from_millis(300),
+            })
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+        assert_eq!(client.circuit_state(), Some(CircuitState::Closed));
+
+        for _ in 0..2 {
+            let result = client.get("/api/monitoring/status").await;
+            assert!(matches!(result, Err(Error::Http(e)) if e.is_timeout()));
+        }
+        assert_eq!(client.circuit_state(), Some(CircuitState::Open));
+
+        let result = client.get("/api/monitoring/status").await;
+        assert!(matches!(result, Err(Error::Generic { message }) if message == "circuit open"));
+
+        slow.remove_async().await;
+        server
+            .mock("GET", "/api/monitoring/status")
+            .with_body("<response>OK</response>")
+            .create_async()
+            .await;
+
+        tokio::time::sleep(Duration::from_millis(350)).await;
+        assert_eq!(client.circuit_state(), Some(CircuitState::HalfOpen));
+
+        client.get("/api/monitoring/status").await.unwrap();
+        assert_eq!(client.circuit_state(), Some(CircuitState::Closed));
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_cancelled_trial_reopens() {
+        use crate::circuit::{CircuitBreakerConfig, CircuitState};
+
+        let mut server = mockito::Server::new_async().await;
+        let slow = server
+            .mock("GET", "/api/monitoring/status")
+            .with_body_from_request(|_| {
+                std::thread::sleep(std::time::Duration::from_millis(200));
+                b"<response>OK</response>".to_vec()
+            })
+            .create_async()
+            .await;
+
+        let config = Config::builder()
+            .base_url(server.url())
+            .timeout(Duration::from_millis(50))
+            .max_retries(1)
+            .circuit_breaker(CircuitBreakerConfig {
+                failure_threshold: 1,
+                window: Duration::from_secs(60),
+                cooldown: Duration::from_millis(100),
+            })
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+
+        assert!(client.get("/api/monitoring/status").await.is_err());
+        assert_eq!(client.circuit_state(), Some(CircuitState::Open));
+        tokio::time::sleep(Duration::from_millis(150)).await;
+
+        // Cancel the trial request before it completes
+        let cancelled =
+            tokio::time::timeout(Duration::from_millis(10), client.get("/api/monitoring/status")).await;
+        assert!(cancelled.is_err());
+
+        slow.remove_async().await;
+        server
+            .mock("GET", "/api/monitoring/status")
+            .with_body("<response>OK</response>")
+            .create_async()
+            .await;
+
+        client.get("/api/monitoring/status").await.unwrap();
+        assert_eq!(client.circuit_state(), Some(CircuitState::Closed));
+    }
+
+    #[test]
+    fn test_circuit_state_disabled_by_default() {
+        let client = Client::with_default_config().unwrap();
+        assert_eq!(client.circuit_state(), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_raw_returns_body() {
+        let mut server = mockito::Server::new_async().await;
+
+        token_mock(&mut server).await;
+
+        let body = "<response><productfamily>LTE</productfamily></response>";
+        server
+            .mock("GET", "/api/device/basic_information")
+            .with_body(body)
+            .create_async()
+            .await;
+
+        server
+            .mock("POST", "/api/custom/endpoint")
+            .match_body("<request><Value>1</Value></request>")
+            .with_body("<error><code>100002</code><message></message></error>")
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+
+        let xml = client.get_raw("/api/device/basic_information").await.unwrap();
+        assert_eq!(xml, body);
+
+        let result = client
+            .post_raw("/api/custom/endpoint", "<request><Value>1</Value></request>")
+            .await;
+        assert!(matches!(result, Err(Error::Api { code: 100002, .. })));
+    }
+
+    async fn load_saved_session(state_login: &str) -> (Client, bool) {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/api/user/state-login")
+            .with_body(state_login)
+            .create_async()
+            .await;
+
+        let path = std::env::temp_dir().join(format!(
+            "huawei-dongle-session-{}-{}.json",
+            std::process::id(),
+            fastrand::u64(..)
+        ));
+
+        let original = Client::for_url(server.url()).unwrap();
+        original.session().mark_authenticated("admin").await;
+        original.save_session(&path).await.unwrap();
+
+        let client = Client::for_url(server.url()).unwrap();
+        let restored = client.load_session(&path).await.unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        (client, restored)
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_save_session_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = std::env::temp_dir().join(format!(
+            "huawei-dongle-session-{}-{}.json",
+            std::process::id(),
+            fastrand::u64(..)
+        ));
+        // A pre-existing, world-readable file must be tightened as well
+        std::fs::write(&path, "{}").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        let client = Client::for_url("http://127.0.0.1:1").unwrap();
+        client.session().mark_authenticated("admin").await;
+        client.save_session(&path).await.unwrap();
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[tokio::test]
+    async fn test_load_session_keeps_valid_session() {
+        let logged_in = login_state_xml("4").replace("<State>-1</State>", "<State>0</State>");
+        let (client, restored) = load_saved_session(&logged_in).await;
+
+        assert!(restored);
+        assert!(client.session().is_authenticated().await);
+    }
+
+    #[tokio::test]
+    async fn test_load_session_discards_expired_session() {
+        let (client, restored) = load_saved_session(&login_state_xml("4")).await;
+
+        assert!(!restored);
+        assert!(!client.session().is_authenticated().await);
+    }
+
+    #[tokio::test]
+    async fn test_ping() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/api/webserver/SesTokInfo")
+            .with_body("<response><SesInfo>SessionID=abc</SesInfo><TokInfo>tok</TokInfo></response>")
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        let rtt = client.ping().await.unwrap();
+        assert!(rtt <= PING_TIMEOUT);
+        mock.assert_async().await;
+        assert!(client.session().export_state().await.csrf_token.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_ping_does_not_store_cookies() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/webserver/SesTokInfo")
+            .with_header("set-cookie", "SessionID=from-ping; path=/")
+            .with_body("<response><SesInfo>SessionID=abc</SesInfo><TokInfo>tok</TokInfo></response>")
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        client.ping().await.unwrap();
+
+        assert_eq!(client.session().export_state().await.cookies, None);
+    }
+
+    #[tokio::test]
+    async fn test_ping_error_status() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/webserver/SesTokInfo")
+            .with_status(503)
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        assert!(matches!(client.ping().await, Err(Error::Api { code: 503, .. })));
+    }
+
+    const SNAPSHOT_PLMN_XML: &str = "<response><State>0</State><FullName>Orange</FullName>\
+<ShortName>Orange</ShortName><Numeric>26003</Numeric><Rat>7</Rat></response>";
+
+    async fn snapshot_server() -> mockito::ServerGuard {
+        let mut server = mockito::Server::new_async().await;
+        token_mock(&mut server).await;
+        server
+            .mock("GET", "/api/device/information")
+            .with_body(device_information_xml().build())
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/api/net/current-plmn")
+            .with_body(SNAPSHOT_PLMN_XML)
+            .create_async()
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_combines_endpoints() {
+        let mut server = snapshot_server().await;
+        let status = server
+            .mock("GET", "/api/monitoring/status")
+            .with_body(crate::testing::monitoring_status_xml("901"))
+            .create_async()
+            .await;
+        let traffic = server
+            .mock("GET", "/api/monitoring/traffic-statistics")
+            .with_body(
+                "<response><CurrentConnectTime>60</CurrentConnectTime><CurrentUpload>1</CurrentUpload>\
+<CurrentDownload>2</CurrentDownload><CurrentDownloadRate>0</CurrentDownloadRate>\
+<CurrentUploadRate>0</CurrentUploadRate><TotalUpload>10</TotalUpload>\
+<TotalDownload>20</TotalDownload><TotalConnectTime>600</TotalConnectTime></response>",
+            )
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        let snapshot = client.snapshot().await.unwrap();
+
+        assert_eq!(snapshot.device.device_name, "E3372");
+        assert_eq!(snapshot.plmn.numeric.as_deref(), Some("26003"));
+        assert!(snapshot.status.unwrap().is_connected());
+        assert_eq!(snapshot.traffic.unwrap().total_download_bytes(), 20);
+        status.assert_async().await;
+        traffic.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_degrades_without_login() {
+        let mut server = snapshot_server().await;
+        for path in ["/api/monitoring/status", "/api/monitoring/traffic-statistics"] {
+            server
+                .mock("GET", path)
+                .with_body(NO_RIGHTS)
+                .create_async()
+                .await;
+        }
+
+        let client = Client::for_url(server.url()).unwrap();
+        let snapshot = client.snapshot().await.unwrap();
+
+        assert_eq!(snapshot.device.device_name, "E3372");
+        assert!(snapshot.status.is_none());
+        assert!(snapshot.traffic.is_none());
+    }
+
+    /// Span id, name and fields
+    type CapturedSpan = (u64, String, Vec<(String, String)>);
+
+    /// Records the name and fields of every span, including fields recorded later
+    #[derive(Clone, Default)]
+    struct SpanCapture(Arc<Mutex<Vec<CapturedSpan>>>);
+
+    #[derive(Default)]
+    struct FieldCapture(Vec<(String, String)>);
+
+    impl tracing::field::Visit for FieldCapture {
+        fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+            self.0.push((field.name().to_string(), value.to_string()));
+        }
+
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn fmt::Debug) {
+            self.0.push((field.name().to_string(), format!("{:?}", value)));
+        }
+    }
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for SpanCapture {
+        fn on_new_span(
+            &self,
+            attrs: &tracing::span::Attributes<'_>,
+            id: &tracing::span::Id,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut fields = FieldCapture::default();
+            attrs.record(&mut fields);
+            self.0
+                .lock()
+                .unwrap()
+                .push((id.into_u64(), attrs.metadata().name().to_string(), fields.0));
+        }
+
+        fn on_record(
+            &self,
+            id: &tracing::span::Id,
+            values: &tracing::span::Record<'_>,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut fields = FieldCapture::default();
+            values.record(&mut fields);
+            let mut spans = self.0.lock().unwrap();
+            if let Some(span) = spans.iter_mut().find(|span| span.0 == id.into_u64()) {
+                span.2.extend(fields.0);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_api_call_emits_span_with_endpoint() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/device/information")
+            .with_body(device_information_xml().build())
+            .create_async()
+            .await;
+
+        let capture = SpanCapture::default();
+        let _guard =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+
+        let client = Client::for_url(server.url()).unwrap();
+        client.device().information().await.unwrap();
+
+        let spans = capture.0.lock().unwrap();
+        let (_, _, fields) = spans
+            .iter()
+            .find(|(_, name, _)| name == "api_request")
+            .expect("api_request span");
+        let field = |name: &str| fields.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str());
+
+        assert_eq!(field("endpoint"), Some("/api/device/information"));
+        assert_eq!(field("method"), Some("GET"));
+        assert!(field("latency_ms").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_without_retries_makes_single_attempt() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/api/device/information")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let config = Config::builder()
+            .base_url(server.url())
+            .max_retries(5)
+            .retry_delay(Duration::from_secs(10))
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+        let fast = client.without_retries();
+
+        let started = std::time::Instant::now();
+        let result = fast.device().information().await;
+        assert!(matches!(result, Err(Error::Api { code: 503, .. })));
+        assert!(started.elapsed() < Duration::from_secs(5));
+        mock.assert_async().await;
+
+        assert_eq!(client.config().max_retries, 5);
+        assert_eq!(fast.config().max_retries, 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_budget_stops_retries_across_calls() {
+        let mut server = mockito::Server::new_async().await;
+        // 5 first attempts plus the 2 retries in the budget
+        let mock = server
+            .mock("GET", "/api/device/information")
+            .with_status(503)
+            .expect(7)
+            .create_async()
+            .await;
+
+        let config = Config::builder()
+            .base_url(server.url())
+            .max_retries(3)
+            .retry_delay(Duration::from_millis(1))
+            .retry_budget_per_minute(2)
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+
+        for _ in 0..5 {
+            let result = client.device().information().await;
+            assert!(matches!(result, Err(Error::Api { code: 503, .. })));
+        }
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_keepalive_refreshes_token() {
+        let mut server = mockito::Server::new_async().await;
+        let state_login = server
+            .mock("GET", "/api/user/state-login")
+            .with_body(
+                "<response><password_type>4</password_type><extern_password_type>1</extern_password_type>\
+<history_login_flag>0</history_login_flag><State>-1</State></response>",
+            )
+            .expect_at_least(1)
+            .create_async()
+            .await;
+        let refresh = server
+            .mock("GET", "/api/webserver/SesTokInfo")
+            .with_body("<response><SesInfo>SessionID=abc</SesInfo><TokInfo>tok</TokInfo></response>")
+            .expect_at_least(1)
+            .create_async()
+            .await;
+
+        let client = Arc::new(Client::for_url(server.url()).unwrap());
+        let handle = client.spawn_keepalive(Duration::from_millis(20));
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        handle.abort();
+
+        state_login.assert_async().await;
+        refresh.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_keepalive_stops_when_client_dropped() {
+        let client = Arc::new(Client::for_url("http://127.0.0.1:1").unwrap());
+        let handle = client.spawn_keepalive(Duration::from_millis(10));
+        drop(client);
+
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while !handle.is_finished() {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("keepalive should stop");
+    }
+
+    #[tokio::test]
+    async fn test_keepalive_stops_when_handle_dropped() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let polls = Arc::new(AtomicUsize::new(0));
+        let polls_seen = polls.clone();
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/user/state-login")
+            .with_body_from_request(move |_| {
+                polls_seen.fetch_add(1, Ordering::SeqCst);
+                b"<response><State>0</State></response>".to_vec()
+            })
+            .create_async()
+            .await;
+
+        let client = Arc::new(Client::for_url(server.url()).unwrap());
+        let handle = client.spawn_keepalive(Duration::from_millis(10));
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        drop(handle);
+
+        // Let an in-flight request finish before sampling
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let after_drop = polls.load(Ordering::SeqCst);
+        assert!(after_drop > 0);
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(polls.load(Ordering::SeqCst), after_drop);
+    }
+
+    #[tokio::test]
+    async fn test_read_cache_ttl() {
+        let mut server = mockito::Server::new_async().await;
+        let info = server
+            .mock("GET", "/api/device/information")
+            .with_body(device_information_xml().build())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let config = Config::builder()
+            .base_url(server.url())
+            .read_cache_ttl(Duration::from_millis(100))
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+
+        client.device().information().await.unwrap();
+        client.device().information().await.unwrap();
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        client.device().information().await.unwrap();
+
+        info.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_read_cache_disabled_by_default() {
+        let mut server = mockito::Server::new_async().await;
+        let plmn = server
+            .mock("GET", "/api/net/current-plmn")
+            .with_body(SNAPSHOT_PLMN_XML)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = Client::for_url(server.url()).unwrap();
+        client.network().current_plmn().await.unwrap();
+        client.network().current_plmn().await.unwrap();
+
+        plmn.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_capabilities_probes_once() {
+        let mut server = mockito::Server::new_async().await;
+        token_mock(&mut server).await;
+        let mut probes = Vec::new();
+        for (path, status, body) in [
+            ("/api/device/information", 200, "<response><DeviceName>E3372</DeviceName></response>"),
+            ("/api/monitoring/status", 200, "<response><ConnectionStatus>901</ConnectionStatus></response>"),
+            ("/api/monitoring/traffic-statistics", 200, "<error><code>100002</code><message></message></error>"),
+            ("/api/wlan/basic-settings", 404, ""),
+            ("/api/sms/config", 200, "<error><code>100003</code><message></message></error>"),
+            ("/api/dialup/mobile-dataswitch", 404, ""),
+        ] {
+            probes.push(
+                server
+                    .mock("GET", path)
+                    .with_status(status)
+                    .with_body(body)
+                    .expect(1)
+                    .create_async()
+                    .await,
+            );
+        }
+
+        let client = Client::for_url(server.url()).unwrap();
+        let capabilities = client.capabilities().await.unwrap();
+        assert_eq!(
+            capabilities,
+            Capabilities {
+                has_device_information: true,
+                has_monitoring: true,
+                has_traffic_stats: false,
+                has_wlan: false,
+                has_sms: true,
+                has_dialup: false,
+            }
+        );
+
+        assert_eq!(client.capabilities().await.unwrap(), capabilities);
+        for probe in probes {
+            probe.assert_async().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_capabilities_not_cached_on_error() {
+        let client = Client::for_url("http://127.0.0.1:1").unwrap();
+        assert!(client.capabilities().await.is_err());
+        assert!(client.capabilities.lock().unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_metrics_count_requests_retries_and_refreshes() {
+        let mut server = mockito::Server::new_async().await;
+        token_mock(&mut server).await;
+        server
+            .mock("GET", "/api/device/information")
+            .with_body(device_information_xml().build())
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/api/monitoring/traffic-statistics")
+            .with_status(503)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/api/monitoring/status")
+            .with_body("<error><code>125002</code><message></message></error>")
+            .create_async()
+            .await;
+
+        let config = Config::builder()
+            .base_url(server.url())
+            .max_retries(2)
+            .retry_delay(Duration::from_millis(1))
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+        assert_eq!(client.metrics_snapshot().requests_total, 0);
+
+        client.device().information().await.unwrap();
+        client.device().information().await.unwrap();
+        assert!(client.monitoring().traffic_statistics().await.is_err());
+        assert!(client.monitoring().status().await.is_err());
+
+        let metrics = client.metrics_snapshot();
+        assert_eq!(metrics.requests_total, 4);
+        assert_eq!(metrics.request_errors_total, 2);
+        assert_eq!(metrics.retries_total, 1);
+        assert!(metrics.token_refreshes_total >= 1);
+        assert_eq!(metrics.latency_buckets.last().unwrap().count, 4);
+
+        assert_eq!(client.without_retries().metrics_snapshot(), metrics);
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingObserver {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl observer::ClientObserver for RecordingObserver {
+        fn on_request(&self, method: &str, path: &str) {
+            self.events.lock().unwrap().push(format!("request {} {}", method, path));
+        }
+
+        fn on_response(
+            &self,
+            path: &str,
+            status: Option<u16>,
+            _: Duration,
+            outcome: std::result::Result<(), &Error>,
+        ) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("response {} {:?} ok={}", path, status, outcome.is_ok()));
+        }
+    }
+
+    #[derive(Debug)]
+    struct PanickingObserver;
+
+    impl observer::ClientObserver for PanickingObserver {
+        fn on_request(&self, _: &str, _: &str) {
+            panic!("observer bug");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_observer_sees_request_and_response() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/monitoring/status")
+            .with_body("<response></response>")
+            .create_async()
+            .await;
+
+        let observer = Arc::new(RecordingObserver::default());
+        let config = Config::builder()
+            .base_url(server.url())
+            .observer(observer.clone())
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+        client.get("/api/monitoring/status").await.unwrap();
+
+        assert_eq!(
+            *observer.events.lock().unwrap(),
+            vec![
+                "request GET /api/monitoring/status".to_string(),
+                "response /api/monitoring/status Some(200) ok=true".to_string(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_observer_panic_does_not_fail_request() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/monitoring/status")
+            .with_body("<response></response>")
+            .create_async()
+            .await;
+
+        let config = Config::builder()
+            .base_url(server.url())
+            .observer(Arc::new(PanickingObserver))
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+        assert!(client.get("/api/monitoring/status").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_skips_posts() {
+        let mut server = mockito::Server::new_async().await;
+        let token = server
+            .mock("GET", "/api/webserver/token")
+            .with_body("<response><token>test-token</token></response>")
+            .expect(0)
+            .create_async()
+            .await;
+        let control = server
+            .mock("POST", "/api/device/control")
+            .expect(0)
+            .create_async()
+            .await;
+        let status = server
+            .mock("GET", "/api/monitoring/status")
+            .with_body("<response></response>")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let config = Config::builder()
+            .base_url(server.url())
+            .dry_run(true)
+            .build()
+            .unwrap();
+        let client = Client::new(config).unwrap();
+
+        client.device().reboot().await.unwrap();
+        client.get("/api/monitoring/status").await.unwrap();
+
+        token.assert_async().await;
+        control.assert_async().await;
+        status.assert_async().await;
+    }
+
+    #[test]
+    fn test_dry_run_body_redacts_sensitive_paths() {
+        let body = "<request><OperateType>0</OperateType><CurrentPin>1234</CurrentPin></request>";
+        let logged = dry_run_body("/api/pin/operate", body);
+        assert!(!logged.contains("1234"));
+        assert_eq!(logged, format!("<{} bytes redacted>", body.len()));
+
+        for path in [
+            "/api/user/password",
+            "/api/wlan/guest-network",
+            "/api/wlan/basic-settings",
+            "/api/sms/send-sms",
+        ] {
+            assert!(!dry_run_body(path, "<request>secret</request>").contains("secret"));
+        }
+
+        let body = "<request><Control>1</Control></request>";
+        assert_eq!(dry_run_body("/api/device/control", body), body);
+    }
 }
@@ -37,20 +37,28 @@
 //! let config = Config::for_url("http://192.168.62.1").unwrap();
 //! ```
 
+use crate::circuit::CircuitBreakerConfig;
 use crate::error::{Error, Result};
+use crate::observer::ClientObserver;
+use crate::retry::{Jitter, RetryHook, RetryOn};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
 use std::time::Duration;
 use url::Url;
 
 /// Configuration for the Huawei Dongle API client.
 /// 
 /// Controls connection parameters, retry behavior, and HTTP settings.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Config {
     /// Base URL of the device (e.g., "http://192.168.8.1")
     pub base_url: Url,
     /// Request timeout for HTTP operations
     pub timeout: Duration,
-    /// Maximum number of retry attempts for failed requests
+    /// Maximum number of attempts for failed requests, including the first.
+    /// `0` or `1` disables retries.
     pub max_retries: usize,
     /// Initial delay before first retry
     pub retry_delay: Duration,
@@ -58,6 +66,76 @@ pub struct Config {
     pub max_retry_delay: Duration,
     /// User agent string sent with requests
     pub user_agent: String,
+    /// Try the SCRAM challenge login before the simple login
+    pub prefer_scram: bool,
+    /// Skip TLS certificate verification, including the host name check
+    pub accept_invalid_certs: bool,
+    /// Proxy used for all requests (e.g. "socks5://10.0.0.1:1080")
+    pub proxy: Option<Url>,
+    /// Extra headers sent with every request
+    pub default_headers: HeaderMap,
+    /// Persist cookies between requests
+    pub cookie_store: bool,
+    /// Fast-fail requests after repeated connect/timeout failures
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+    /// How long rarely-changing reads (device information, current operator)
+    /// are cached; `None` disables the cache
+    pub read_cache_ttl: Option<Duration>,
+    /// Maximum retries per minute across all calls made through the client;
+    /// `None` leaves retries limited only by `max_retries` per call
+    pub retry_budget_per_minute: Option<u32>,
+    /// Which errors are retried; `None` uses the built-in classification
+    pub retry_on: Option<RetryOn>,
+    /// Jitter applied to retry delays; `None` uses the default ±25% jitter
+    pub jitter_strategy: Option<Jitter>,
+    /// Device API error codes retried in addition to the built-in ones
+    pub retryable_api_codes: HashSet<i32>,
+    /// Called before each retry, in addition to the client's own retry metrics
+    pub on_retry: Option<RetryHook>,
+    /// Maximum idle connections kept open to the device
+    pub pool_max_idle_per_host: usize,
+    /// How long idle connections are kept; `None` keeps them indefinitely
+    pub pool_idle_timeout: Option<Duration>,
+    /// TCP keepalive interval; `None` leaves keepalive off
+    pub tcp_keepalive: Option<Duration>,
+    /// Only speak HTTP/1.x, for webservers that mishandle HTTP/2 negotiation
+    pub http1_only: bool,
+    /// Called before and after every HTTP request
+    pub observer: Option<Arc<dyn ClientObserver>>,
+    /// Log POST requests instead of sending them and answer them with
+    /// `<response>OK</response>`. GETs and login/logout are still sent.
+    pub dry_run: bool,
+}
+
+impl fmt::Debug for Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Config")
+            .field("base_url", &self.base_url)
+            .field("timeout", &self.timeout)
+            .field("max_retries", &self.max_retries)
+            .field("retry_delay", &self.retry_delay)
+            .field("max_retry_delay", &self.max_retry_delay)
+            .field("user_agent", &self.user_agent)
+            .field("prefer_scram", &self.prefer_scram)
+            .field("accept_invalid_certs", &self.accept_invalid_certs)
+            .field("proxy", &self.proxy)
+            .field("default_headers", &self.default_headers)
+            .field("cookie_store", &self.cookie_store)
+            .field("circuit_breaker", &self.circuit_breaker)
+            .field("read_cache_ttl", &self.read_cache_ttl)
+            .field("retry_budget_per_minute", &self.retry_budget_per_minute)
+            .field("retry_on", &self.retry_on)
+            .field("jitter_strategy", &self.jitter_strategy)
+            .field("retryable_api_codes", &self.retryable_api_codes)
+            .field("on_retry", &self.on_retry.as_ref().map(|_| "<hook>"))
+            .field("pool_max_idle_per_host", &self.pool_max_idle_per_host)
+            .field("pool_idle_timeout", &self.pool_idle_timeout)
+            .field("tcp_keepalive", &self.tcp_keepalive)
+            .field("http1_only", &self.http1_only)
+            .field("observer", &self.observer)
+            .field("dry_run", &self.dry_run)
+            .finish()
+    }
 }
 
 impl Default for Config {
@@ -69,6 +147,24 @@ impl Default for Config {
             retry_delay: Duration::from_millis(500),
             max_retry_delay: Duration::from_secs(30),
             user_agent: format!("huawei-dongle-api/{}", env!("CARGO_PKG_VERSION")),
+            prefer_scram: false,
+            accept_invalid_certs: false,
+            proxy: None,
+            default_headers: HeaderMap::new(),
+            cookie_store: true,
+            circuit_breaker: None,
+            read_cache_ttl: None,
+            retry_budget_per_minute: None,
+            retry_on: None,
+            jitter_strategy: None,
+            retryable_api_codes: HashSet::new(),
+            on_retry: None,
+            pool_max_idle_per_host: usize::MAX,
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+            tcp_keepalive: None,
+            http1_only: false,
+            observer: None,
+            dry_run: false,
         }
     }
 }
@@ -89,7 +185,7 @@ impl Config {
 }
 
 /// Builder for Config
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub struct ConfigBuilder {
     base_url: Option<String>,
     timeout: Option<Duration>,
@@ -97,6 +193,55 @@ pub struct ConfigBuilder {
     retry_delay: Option<Duration>,
     max_retry_delay: Option<Duration>,
     user_agent: Option<String>,
+    prefer_scram: Option<bool>,
+    accept_invalid_certs: Option<bool>,
+    proxy: Option<String>,
+    default_headers: Vec<(String, String)>,
+    cookie_store: Option<bool>,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    read_cache_ttl: Option<Duration>,
+    retry_budget_per_minute: Option<u32>,
+    retry_on: Option<RetryOn>,
+    jitter_strategy: Option<Jitter>,
+    retryable_api_codes: HashSet<i32>,
+    on_retry: Option<RetryHook>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    http1_only: Option<bool>,
+    observer: Option<Arc<dyn ClientObserver>>,
+    dry_run: Option<bool>,
+}
+
+impl fmt::Debug for ConfigBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConfigBuilder")
+            .field("base_url", &self.base_url)
+            .field("timeout", &self.timeout)
+            .field("max_retries", &self.max_retries)
+            .field("retry_delay", &self.retry_delay)
+            .field("max_retry_delay", &self.max_retry_delay)
+            .field("user_agent", &self.user_agent)
+            .field("prefer_scram", &self.prefer_scram)
+            .field("accept_invalid_certs", &self.accept_invalid_certs)
+            .field("proxy", &self.proxy)
+            .field("default_headers", &self.default_headers)
+            .field("cookie_store", &self.cookie_store)
+            .field("circuit_breaker", &self.circuit_breaker)
+            .field("read_cache_ttl", &self.read_cache_ttl)
+            .field("retry_budget_per_minute", &self.retry_budget_per_minute)
+            .field("retry_on", &self.retry_on)
+            .field("jitter_strategy", &self.jitter_strategy)
+            .field("retryable_api_codes", &self.retryable_api_codes)
+            .field("on_retry", &self.on_retry.as_ref().map(|_| "<hook>"))
+            .field("pool_max_idle_per_host", &self.pool_max_idle_per_host)
+            .field("pool_idle_timeout", &self.pool_idle_timeout)
+            .field("tcp_keepalive", &self.tcp_keepalive)
+            .field("http1_only", &self.http1_only)
+            .field("observer", &self.observer)
+            .field("dry_run", &self.dry_run)
+            .finish()
+    }
 }
 
 impl ConfigBuilder {
@@ -130,6 +275,145 @@ impl ConfigBuilder {
         self
     }
 
+    pub fn prefer_scram(mut self, prefer_scram: bool) -> Self {
+        self.prefer_scram = Some(prefer_scram);
+        self
+    }
+
+    /// Accept invalid TLS certificates, such as the self-signed certificate
+    /// many devices use for their HTTPS admin UI.
+    ///
+    /// **Warning**: This disables certificate verification entirely, chain
+    /// and host name alike, and exposes the connection to man-in-the-middle
+    /// attacks. The rustls backend has no way to skip only the host name
+    /// check. The CLI exposes this as `--insecure`.
+    pub fn accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.accept_invalid_certs = Some(accept);
+        self
+    }
+
+    /// Route all requests through a proxy, e.g. when the device is only
+    /// reachable through a jump host.
+    ///
+    /// Supported schemes are `http://`, `https://`, `socks5://` and
+    /// `socks5h://`, where `socks5h://` also resolves the device host name
+    /// through the proxy. The proxy's own host name is resolved when the
+    /// client is built, so [`Client::new`](crate::Client::new) fails if it
+    /// cannot be looked up.
+    pub fn proxy<S: Into<String>>(mut self, url: S) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    /// Add a header sent with every request, e.g. an auth header required by
+    /// a reverse proxy in front of the device. The name and value are
+    /// validated by [`build`](Self::build).
+    pub fn default_header<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.default_headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Enable or disable cookie persistence (enabled by default).
+    ///
+    /// Without a cookie store the device session cookie is not kept, so
+    /// authenticated requests will generally fail.
+    pub fn cookie_store(mut self, enabled: bool) -> Self {
+        self.cookie_store = Some(enabled);
+        self
+    }
+
+    /// Enable a circuit breaker that fast-fails requests while the device
+    /// is unreachable (disabled by default)
+    pub fn circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(config);
+        self
+    }
+
+    /// Cache device information and the current operator for `ttl`
+    /// (disabled by default). Status and other volatile reads are never cached.
+    pub fn read_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.read_cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Cap retries across the whole client at `retries` per minute (unlimited
+    /// by default). Once spent, failing calls return their error immediately.
+    pub fn retry_budget_per_minute(mut self, retries: u32) -> Self {
+        self.retry_budget_per_minute = Some(retries);
+        self
+    }
+
+    /// Retry only the errors `retry_on` accepts, e.g.
+    /// [`RetryOn::transient_network_only`] to fail fast on HTTP errors
+    pub fn retry_on(mut self, retry_on: RetryOn) -> Self {
+        self.retry_on = Some(retry_on);
+        self
+    }
+
+    /// Randomize retry delays with `jitter` instead of the default ±25%,
+    /// e.g. [`Jitter::Full`] to spread out many clients polling one device
+    pub fn jitter_strategy(mut self, jitter: Jitter) -> Self {
+        self.jitter_strategy = Some(jitter);
+        self
+    }
+
+    /// Also retry device errors with these codes, e.g. `111020` (SMS
+    /// processing). Ignored when [`retry_on`](Self::retry_on) is set.
+    pub fn retryable_api_codes<I: IntoIterator<Item = i32>>(mut self, codes: I) -> Self {
+        self.retryable_api_codes.extend(codes);
+        self
+    }
+
+    /// Call `hook` before each retry with the failed attempt number, the
+    /// error and the delay before the next attempt, e.g. to count or log
+    /// retries
+    pub fn on_retry<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(usize, &Error, Duration) + Send + Sync + 'static,
+    {
+        self.on_retry = Some(Arc::new(hook));
+        self
+    }
+
+    /// Keep at most `max` idle connections to the device (unlimited by default)
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Close idle connections after `timeout` (90 seconds by default)
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Enable TCP keepalive with the given interval (off by default)
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Restrict connections to HTTP/1.x (off by default)
+    pub fn http1_only(mut self, enabled: bool) -> Self {
+        self.http1_only = Some(enabled);
+        self
+    }
+
+    /// Notify `observer` around every HTTP request, including retries
+    pub fn observer(mut self, observer: Arc<dyn ClientObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Preview changes without applying them (off by default).
+    ///
+    /// Every POST except login and logout is logged at info level with its
+    /// URL and body and answered with a synthetic `<response>OK</response>`.
+    pub fn dry_run(mut self, enabled: bool) -> Self {
+        self.dry_run = Some(enabled);
+        self
+    }
+
     pub fn build(self) -> Result<Config> {
         let default = Config::default();
 
@@ -139,6 +423,30 @@ impl ConfigBuilder {
             default.base_url
         };
 
+        let proxy = match self.proxy {
+            Some(proxy) => {
+                let url = Url::parse(&proxy)
+                    .map_err(|e| Error::config(format!("Invalid proxy URL: {}", e)))?;
+                if !matches!(url.scheme(), "http" | "https" | "socks5" | "socks5h") {
+                    return Err(Error::config(format!(
+                        "Unsupported proxy scheme: {}",
+                        url.scheme()
+                    )));
+                }
+                Some(url)
+            }
+            None => default.proxy,
+        };
+
+        let mut default_headers = default.default_headers;
+        for (name, value) in self.default_headers {
+            let header_name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| Error::config(format!("Invalid header name '{}': {}", name, e)))?;
+            let header_value = HeaderValue::from_str(&value)
+                .map_err(|e| Error::config(format!("Invalid value for header '{}': {}", name, e)))?;
+            default_headers.append(header_name, header_value);
+        }
+
         Ok(Config {
             base_url,
             timeout: self.timeout.unwrap_or(default.timeout),
@@ -146,6 +454,28 @@ impl ConfigBuilder {
             retry_delay: self.retry_delay.unwrap_or(default.retry_delay),
             max_retry_delay: self.max_retry_delay.unwrap_or(default.max_retry_delay),
             user_agent: self.user_agent.unwrap_or(default.user_agent),
+            prefer_scram: self.prefer_scram.unwrap_or(default.prefer_scram),
+            accept_invalid_certs: self.accept_invalid_certs.unwrap_or(default.accept_invalid_certs),
+            proxy,
+            default_headers,
+            cookie_store: self.cookie_store.unwrap_or(default.cookie_store),
+            circuit_breaker: self.circuit_breaker.or(default.circuit_breaker),
+            read_cache_ttl: self.read_cache_ttl.or(default.read_cache_ttl),
+            retry_budget_per_minute: self
+                .retry_budget_per_minute
+                .or(default.retry_budget_per_minute),
+            retry_on: self.retry_on.or(default.retry_on),
+            jitter_strategy: self.jitter_strategy.or(default.jitter_strategy),
+            retryable_api_codes: self.retryable_api_codes,
+            on_retry: self.on_retry.or(default.on_retry),
+            pool_max_idle_per_host: self
+                .pool_max_idle_per_host
+                .unwrap_or(default.pool_max_idle_per_host),
+            pool_idle_timeout: self.pool_idle_timeout.or(default.pool_idle_timeout),
+            tcp_keepalive: self.tcp_keepalive.or(default.tcp_keepalive),
+            http1_only: self.http1_only.unwrap_or(default.http1_only),
+            observer: self.observer.or(default.observer),
+            dry_run: self.dry_run.unwrap_or(default.dry_run),
         })
     }
 }
@@ -160,6 +490,66 @@ mod tests {
         assert_eq!(config.base_url.as_str(), "http://192.168.8.1/");
         assert_eq!(config.timeout, Duration::from_secs(30));
         assert_eq!(config.max_retries, 3);
+        assert!(!config.accept_invalid_certs);
+        assert!(config.proxy.is_none());
+        assert!(config.default_headers.is_empty());
+        assert!(config.cookie_store);
+        assert_eq!(config.pool_max_idle_per_host, usize::MAX);
+        assert_eq!(config.pool_idle_timeout, Some(Duration::from_secs(90)));
+        assert!(config.tcp_keepalive.is_none());
+        assert!(!config.http1_only);
+    }
+
+    #[test]
+    fn test_default_headers() {
+        let config = Config::builder()
+            .default_header("X-Auth-Token", "secret")
+            .default_header("X-Forwarded-User", "admin")
+            .cookie_store(false)
+            .build()
+            .unwrap();
+
+        assert_eq!(config.default_headers.len(), 2);
+        assert_eq!(config.default_headers["x-auth-token"], "secret");
+        assert_eq!(config.default_headers["x-forwarded-user"], "admin");
+        assert!(!config.cookie_store);
+    }
+
+    #[test]
+    fn test_invalid_default_header() {
+        assert!(matches!(
+            Config::builder().default_header("Bad Header", "value").build(),
+            Err(Error::Config { .. })
+        ));
+        assert!(matches!(
+            Config::builder().default_header("X-Ok", "line\nbreak").build(),
+            Err(Error::Config { .. })
+        ));
+    }
+
+    #[test]
+    fn test_proxy_schemes() {
+        for proxy in [
+            "http://10.0.0.1:3128",
+            "https://proxy.example",
+            "socks5://jump:1080",
+            "socks5h://jump:1080",
+        ] {
+            let config = Config::builder().proxy(proxy).build().unwrap();
+            assert_eq!(config.proxy.unwrap().scheme(), proxy.split(':').next().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_invalid_proxy() {
+        assert!(matches!(
+            Config::builder().proxy("not a url").build(),
+            Err(Error::Config { .. })
+        ));
+        assert!(matches!(
+            Config::builder().proxy("ftp://proxy.example").build(),
+            Err(Error::Config { .. })
+        ));
     }
 
     #[test]
@@ -174,6 +564,17 @@ mod tests {
         assert_eq!(config.base_url.as_str(), "http://192.168.62.1/");
         assert_eq!(config.timeout, Duration::from_secs(60));
         assert_eq!(config.max_retries, 5);
+        assert!(config.retry_on.is_none());
+        assert!(config.jitter_strategy.is_none());
+
+        let config = Config::builder()
+            .retry_on(RetryOn::transient_network_only())
+            .jitter_strategy(Jitter::Equal)
+            .build()
+            .unwrap();
+        assert_eq!(config.jitter_strategy, Some(Jitter::Equal));
+        let retry_on = config.retry_on.unwrap();
+        assert!(!retry_on.matches(&Error::session("Temporary failure")));
     }
 
     #[test]
@@ -0,0 +1,124 @@
+//! Response body decoding.
+//!
+//! Most firmware sends UTF-8, but some devices declare GBK or ISO-8859-1
+//! either in the `Content-Type` header or in the XML declaration. Bodies are
+//! decoded with the declared charset before they reach the XML parser; the
+//! header takes precedence over the declaration and UTF-8 is the fallback.
+//!
+//! Some E3372 firmware also prefixes bodies with a byte order mark or blank
+//! lines before `<?xml`, which serde_xml_rs rejects; [`trim_xml_prefix`]
+//! strips those.
+//!
+//! Compressed bodies (gzip, deflate, brotli) are inflated by reqwest before
+//! they get here.
+
+use crate::error::Result;
+use encoding_rs::{Encoding, UTF_8};
+use reqwest::{header::CONTENT_TYPE, Response};
+
+/// Read a response body and decode it using its declared charset
+pub async fn read_text(response: Response) -> Result<String> {
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let bytes = response.bytes().await?;
+    let text = decode_body(&bytes, content_type.as_deref());
+    Ok(trim_xml_prefix(&text).to_string())
+}
+
+/// Strip a leading byte order mark and whitespace before the first `<`
+pub fn trim_xml_prefix(text: &str) -> &str {
+    text.trim_start_matches('\u{feff}').trim_start()
+}
+
+/// Decode `bytes` using the charset from `content_type` or the XML
+/// declaration, falling back to UTF-8. Invalid sequences are replaced
+/// rather than failing the request.
+pub fn decode_body(bytes: &[u8], content_type: Option<&str>) -> String {
+    let encoding = content_type
+        .and_then(charset_from_content_type)
+        .or_else(|| charset_from_xml_declaration(bytes))
+        .and_then(|label| Encoding::for_label(label.trim().as_bytes()))
+        .unwrap_or(UTF_8);
+
+    let (text, _, _) = encoding.decode(bytes);
+    text.into_owned()
+}
+
+/// Extract the `charset` parameter from a `Content-Type` value
+fn charset_from_content_type(content_type: &str) -> Option<&str> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        name.trim()
+            .eq_ignore_ascii_case("charset")
+            .then(|| value.trim().trim_matches('"'))
+    })
+}
+
+/// Extract `encoding="..."` from an `<?xml ...?>` declaration. The
+/// declaration is ASCII in every encoding we care about, so it can be read
+/// before decoding.
+fn charset_from_xml_declaration(bytes: &[u8]) -> Option<&str> {
+    let start = bytes.windows(5).position(|w| w == b"<?xml")?;
+    let end = start + bytes[start..].windows(2).position(|w| w == b"?>")?;
+    let declaration = std::str::from_utf8(&bytes[start..end]).ok()?;
+
+    let value = declaration.split("encoding=").nth(1)?;
+    let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    value[1..].split(quote).next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use encoding_rs::{GBK, WINDOWS_1252};
+
+    #[test]
+    fn test_charset_from_content_type() {
+        assert_eq!(charset_from_content_type("text/xml; charset=GBK"), Some("GBK"));
+        assert_eq!(charset_from_content_type("text/xml;Charset=\"utf-8\""), Some("utf-8"));
+        assert_eq!(charset_from_content_type("text/xml"), None);
+    }
+
+    #[test]
+    fn test_decode_gbk_operator_name_from_declaration() {
+        let xml = r#"<?xml version="1.0" encoding="GBK"?><response><FullName>中国移动</FullName></response>"#;
+        let (bytes, _, _) = GBK.encode(xml);
+
+        let text = decode_body(&bytes, Some("text/xml"));
+        assert!(text.contains("<FullName>中国移动</FullName>"));
+    }
+
+    #[test]
+    fn test_decode_latin1_sms_from_content_type() {
+        let xml = "<response><Content>Grüße aus München</Content></response>";
+        let (bytes, _, _) = WINDOWS_1252.encode(xml);
+
+        let text = decode_body(&bytes, Some("text/xml; charset=ISO-8859-1"));
+        assert!(text.contains("Grüße aus München"));
+    }
+
+    #[test]
+    fn test_content_type_overrides_declaration() {
+        let xml = r#"<?xml version="1.0" encoding="GBK"?><response>é</response>"#;
+        let (bytes, _, _) = WINDOWS_1252.encode(xml);
+
+        assert!(decode_body(&bytes, Some("text/xml; charset=iso-8859-1")).contains("é"));
+    }
+
+    #[test]
+    fn test_trim_xml_prefix() {
+        assert_eq!(trim_xml_prefix("\u{feff}<response/>"), "<response/>");
+        assert_eq!(trim_xml_prefix("\r\n\n  <?xml version=\"1.0\"?>"), "<?xml version=\"1.0\"?>");
+        assert_eq!(trim_xml_prefix("\u{feff}\n<response/>"), "<response/>");
+        assert_eq!(trim_xml_prefix("<response/>"), "<response/>");
+    }
+
+    #[test]
+    fn test_defaults_to_utf8() {
+        let xml = "<response><Content>Zażółć</Content></response>";
+        assert_eq!(decode_body(xml.as_bytes(), None), xml);
+    }
+}
@@ -1,5 +1,6 @@
 //! Error types for the Huawei Dongle API
 
+use crate::retry::RetryStrategy;
 use thiserror::Error;
 
 /// Common Huawei API Error Codes
@@ -13,6 +14,7 @@ use thiserror::Error;
 /// - `108003` - Already logged in
 /// - `108006` - Username or password wrong
 /// - `108007` - Too many login attempts
+/// - `115002` - Password change required
 /// 
 /// ## System Errors (100xxx)
 /// - `100001` - Unknown system error
@@ -42,7 +44,10 @@ use thiserror::Error;
 /// - `107002` - Incorrect PUK
 /// - `107003` - PUK times exceeded (SIM locked)
 pub mod error_codes {
+    pub const NO_SUPPORT: i32 = 100002;
     pub const NO_RIGHTS: i32 = 100003;
+    pub const SYSTEM_BUSY: i32 = 100004;
+    pub const FORMAT_ERROR: i32 = 100005;
     pub const CSRF_TOKEN_ERROR: i32 = 125002;
     pub const SESSION_TOKEN_ERROR: i32 = 125003;
     pub const USERNAME_WRONG: i32 = 108001;
@@ -50,8 +55,20 @@ pub mod error_codes {
     pub const ALREADY_LOGIN: i32 = 108003;
     pub const USERNAME_PWD_WRONG: i32 = 108006;
     pub const USERNAME_PWD_OVERRUN: i32 = 108007;
+    pub const PASSWORD_CHANGE_REQUIRED: i32 = 115002;
+    pub const INCORRECT_PIN: i32 = 106001;
+    pub const INCORRECT_PUK: i32 = 107002;
+    pub const PUK_EXHAUSTED: i32 = 107003;
+    pub const PHONE_NUMBER_INVALID: i32 = 111001;
+    pub const SMS_CENTER_NUMBER_INVALID: i32 = 111019;
+    pub const SMS_PROCESSING: i32 = 111020;
+    pub const SMS_NOT_ENOUGH_SPACE: i32 = 111022;
+    pub const SIM_NOT_INSERTED: i32 = 113017;
 }
 
+/// Maximum number of body characters kept in [`Error::Parse`]
+pub const PARSE_SNIPPET_LEN: usize = 200;
+
 /// Result type alias for this crate
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -66,6 +83,18 @@ pub enum Error {
     #[error("XML parsing failed: {0}")]
     Xml(#[from] serde_xml_rs::Error),
 
+    /// Response body from an endpoint could not be parsed
+    ///
+    /// `body_snippet` may hold tokens, phone numbers or message text, so it is
+    /// left out of the `Display` output; log it deliberately if needed.
+    #[error("Failed to parse response from {endpoint}: {source}")]
+    Parse {
+        endpoint: String,
+        #[source]
+        source: serde_xml_rs::Error,
+        body_snippet: String,
+    },
+
     /// Quick XML parsing errors
     #[error("XML parsing failed: {0}")]
     QuickXml(#[from] quick_xml::Error),
@@ -102,14 +131,35 @@ pub enum Error {
     #[error("Already logged in")]
     AlreadyLoggedIn,
 
+    /// The device requires the default password to be changed before use
+    #[error("Password change required")]
+    PasswordChangeRequired,
+
     /// CSRF token error
     #[error("CSRF token invalid")]
     CsrfTokenInvalid,
 
+    /// Incorrect SIM PIN
+    #[error("Incorrect PIN")]
+    IncorrectPin,
+
+    /// Incorrect SIM PUK
+    #[error("Incorrect PUK")]
+    IncorrectPuk,
+
+    /// PUK attempts exhausted, SIM is permanently locked
+    #[error("PUK attempts exhausted (SIM locked)")]
+    PukExhausted,
+
     /// Session token error
     #[error("Session token invalid")]
     SessionTokenInvalid,
 
+    /// The device is busy, typically right after a mode change or reboot;
+    /// retried with at least [`RetryStrategy::system_busy_delay`] between attempts
+    #[error("System busy")]
+    SystemBusy,
+
     /// API errors with error code
     #[error("API error {code}: {message}")]
     Api { code: i32, message: String },
@@ -150,12 +200,31 @@ impl Error {
             Error::InvalidCredentials => false,
             Error::TooManyLoginAttempts => false,
             Error::AlreadyLoggedIn => false,
+            Error::PasswordChangeRequired => false,
+            Error::IncorrectPin => false,
+            Error::IncorrectPuk => false,
+            Error::PukExhausted => false,
             Error::CsrfTokenInvalid => true,
             Error::SessionTokenInvalid => true,
+            Error::SystemBusy => true,
             _ => false,
         }
     }
 
+    /// Check if this error is retryable under `strategy`, which may opt
+    /// additional device API codes into retries or replace the
+    /// classification entirely with [`RetryStrategy::retry_on`]
+    pub fn is_retryable_with(&self, strategy: &RetryStrategy) -> bool {
+        if let Some(retry_on) = &strategy.retry_on {
+            return retry_on.matches(self);
+        }
+
+        match self {
+            Error::Api { code, .. } if strategy.retryable_api_codes.contains(code) => true,
+            _ => self.is_retryable(),
+        }
+    }
+
     /// Create an authentication error
     pub fn authentication<S: Into<String>>(message: S) -> Self {
         Self::Authentication {
@@ -176,10 +245,25 @@ impl Error {
             ALREADY_LOGIN => Self::AlreadyLoggedIn,
             USERNAME_PWD_WRONG => Self::InvalidCredentials,
             USERNAME_PWD_OVERRUN => Self::TooManyLoginAttempts,
+            PASSWORD_CHANGE_REQUIRED => Self::PasswordChangeRequired,
+            INCORRECT_PIN => Self::IncorrectPin,
+            INCORRECT_PUK => Self::IncorrectPuk,
+            PUK_EXHAUSTED => Self::PukExhausted,
+            SYSTEM_BUSY => Self::SystemBusy,
             _ => Self::Api { code, message }
         }
     }
 
+    /// Create a parse error for `endpoint`, keeping the first
+    /// [`PARSE_SNIPPET_LEN`] characters of the body
+    pub fn parse<E: Into<String>>(endpoint: E, source: serde_xml_rs::Error, body: &str) -> Self {
+        Self::Parse {
+            endpoint: endpoint.into(),
+            source,
+            body_snippet: body.trim().chars().take(PARSE_SNIPPET_LEN).collect(),
+        }
+    }
+
     /// Create a session error
     pub fn session<S: Into<String>>(message: S) -> Self {
         Self::Session {
@@ -201,3 +285,48 @@ impl Error {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_system_busy_mapping() {
+        let error = Error::api(100004, "busy".to_string());
+        assert!(matches!(error, Error::SystemBusy));
+        assert!(error.is_retryable());
+    }
+
+    #[test]
+    fn test_pin_error_mapping() {
+        assert!(matches!(Error::api(106001, String::new()), Error::IncorrectPin));
+        assert!(matches!(Error::api(107002, String::new()), Error::IncorrectPuk));
+        assert!(matches!(Error::api(107003, String::new()), Error::PukExhausted));
+        assert!(!Error::IncorrectPin.is_retryable());
+    }
+
+    #[test]
+    fn test_password_change_required_mapping() {
+        let error = Error::api(115002, String::new());
+        assert!(matches!(error, Error::PasswordChangeRequired));
+        assert!(!error.is_retryable());
+    }
+
+    #[test]
+    fn test_parse_error_keeps_snippet() {
+        let body = format!("<response><unexpected>{}</unexpected></response>", "x".repeat(300));
+        let source = serde_xml_rs::from_str::<i32>("<oops").unwrap_err();
+        let error = Error::parse("/api/device/information", source, &body);
+
+        match &error {
+            Error::Parse { endpoint, body_snippet, .. } => {
+                assert_eq!(endpoint, "/api/device/information");
+                assert_eq!(body_snippet.chars().count(), PARSE_SNIPPET_LEN);
+                assert!(body_snippet.starts_with("<response><unexpected>"));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert!(error.to_string().contains("/api/device/information"));
+        assert!(!error.to_string().contains("<unexpected>"));
+    }
+}
@@ -51,8 +51,8 @@
 //! client.auth().login("admin", "password").await?;
 //! 
 //! // Now you can access protected endpoints
-//! use huawei_dongle_api::models::{SmsListRequest, SmsBoxType, SmsSortType};
-//! let request = SmsListRequest::new(1, 20, SmsBoxType::LocalInbox, SmsSortType::ByTime, false, true);
+//! use huawei_dongle_api::models::SmsListRequest;
+//! let request = SmsListRequest::builder().unread_preferred(true).build();
 //! let sms_list = client.sms().list(&request).await?;
 //! 
 //! // Logout when done
@@ -61,6 +61,10 @@
 //! # }
 //! ```
 //! 
+//! Firmware that rejects the simple login can use the SCRAM challenge flow by setting
+//! [`ConfigBuilder::prefer_scram`](config::ConfigBuilder::prefer_scram). The client falls back
+//! to the simple login if the device does not expose the challenge endpoint.
+//! 
 //! ## Configuration
 //! 
 //! The client can be configured with custom timeouts, retry policies, and base URLs:
@@ -87,7 +91,7 @@
 //! The library provides detailed error types and handles common issues automatically:
 //! 
 //! - CSRF token expiry - automatically refreshes and retries
-//! - Session timeout - re-authenticates if needed
+//! - Session timeout - re-authenticates with credentials stored via [`Client::set_credentials`]
 //! - Network errors - retries with exponential backoff
 //! - Device quirks - handles different response formats
 //! 
@@ -98,14 +102,25 @@
 //! - **SMS** - List, send, delete messages
 //! - **Network** - Mode selection, operator info, signal details
 //! - **DHCP** - IP configuration, DNS settings
-//! - **Authentication** - Login/logout, password encoding
+//! - **Dial-up** - Mobile data on/off
+//! - **PIN** - SIM PIN/PUK management
+//! - **USSD** - Balance checks and operator menus
+//! - **WLAN** - WiFi SSID and access point settings
+//! - **Authentication** - Login/logout, password encoding, SCRAM challenge login
 
 pub mod auth;
+pub mod circuit;
 pub mod client;
 pub mod config;
+pub mod encoding;
 pub mod error;
+pub mod metrics;
+pub mod observer;
 pub mod retry;
 pub mod session;
+#[cfg(any(test, feature = "test-support"))]
+pub mod testing;
+pub mod transport;
 
 pub mod api;
 pub mod models;
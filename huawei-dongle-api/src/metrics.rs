@@ -0,0 +1,143 @@
+//! Request counters for monitoring the client.
+//!
+//! Every [`Client`](crate::Client) keeps a few atomic counters and a coarse
+//! latency histogram, shared with clients derived from it. Read them with
+//! [`Client::metrics_snapshot`](crate::Client::metrics_snapshot) and export
+//! them in whatever format your monitoring system expects.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use huawei_dongle_api::Client;
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::for_url("http://192.168.8.1")?;
+//! client.device().information().await?;
+//!
+//! let metrics = client.metrics_snapshot();
+//! println!("huawei_requests_total {}", metrics.requests_total);
+//! for bucket in &metrics.latency_buckets {
+//!     let le = bucket.le_ms.map_or("+Inf".to_string(), |ms| (ms as f64 / 1000.0).to_string());
+//!     println!("huawei_request_seconds_bucket{{le=\"{}\"}} {}", le, bucket.count);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Upper bounds of the latency histogram buckets in milliseconds; a final
+/// unbounded bucket catches everything slower
+pub const LATENCY_BUCKETS_MS: [u64; 9] = [10, 50, 100, 250, 500, 1000, 2500, 5000, 10000];
+
+/// Live counters updated by the client
+#[derive(Debug, Default)]
+pub(crate) struct Metrics {
+    requests_total: AtomicU64,
+    request_errors_total: AtomicU64,
+    retries_total: AtomicU64,
+    token_refreshes_total: AtomicU64,
+    latency_sum_ms: AtomicU64,
+    latency_buckets: [AtomicU64; LATENCY_BUCKETS_MS.len() + 1],
+}
+
+impl Metrics {
+    /// Record one finished API call
+    pub(crate) fn record_request(&self, latency: Duration, failed: bool) {
+        let latency_ms = latency.as_millis() as u64;
+        let bucket = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|&bound| latency_ms <= bound)
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+
+        self.requests_total.fetch_add(1, Ordering::Relaxed);
+        if failed {
+            self.request_errors_total.fetch_add(1, Ordering::Relaxed);
+        }
+        self.latency_sum_ms.fetch_add(latency_ms, Ordering::Relaxed);
+        self.latency_buckets[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_retry(&self) {
+        self.retries_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_token_refresh(&self) {
+        self.token_refreshes_total.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> MetricsSnapshot {
+        let mut cumulative = 0;
+        let latency_buckets = self
+            .latency_buckets
+            .iter()
+            .enumerate()
+            .map(|(i, count)| {
+                cumulative += count.load(Ordering::Relaxed);
+                LatencyBucket {
+                    le_ms: LATENCY_BUCKETS_MS.get(i).copied(),
+                    count: cumulative,
+                }
+            })
+            .collect();
+
+        MetricsSnapshot {
+            requests_total: self.requests_total.load(Ordering::Relaxed),
+            request_errors_total: self.request_errors_total.load(Ordering::Relaxed),
+            retries_total: self.retries_total.load(Ordering::Relaxed),
+            token_refreshes_total: self.token_refreshes_total.load(Ordering::Relaxed),
+            latency_sum_ms: self.latency_sum_ms.load(Ordering::Relaxed),
+            latency_buckets,
+        }
+    }
+}
+
+/// Point-in-time copy of the client's counters
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MetricsSnapshot {
+    /// API calls made, counting each call once regardless of retries
+    pub requests_total: u64,
+    /// API calls that returned an error
+    pub request_errors_total: u64,
+    /// Retry attempts made after transient failures
+    pub retries_total: u64,
+    /// CSRF token refreshes after token errors
+    pub token_refreshes_total: u64,
+    /// Sum of all call latencies in milliseconds
+    pub latency_sum_ms: u64,
+    /// Cumulative latency histogram, one entry per bound in
+    /// [`LATENCY_BUCKETS_MS`] followed by the unbounded bucket
+    pub latency_buckets: Vec<LatencyBucket>,
+}
+
+/// Number of calls that finished within `le_ms` milliseconds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LatencyBucket {
+    /// Upper bound in milliseconds, `None` for the unbounded bucket
+    pub le_ms: Option<u64>,
+    pub count: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latency_buckets_are_cumulative() {
+        let metrics = Metrics::default();
+        metrics.record_request(Duration::from_millis(5), false);
+        metrics.record_request(Duration::from_millis(300), true);
+        metrics.record_request(Duration::from_secs(20), false);
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.requests_total, 3);
+        assert_eq!(snapshot.request_errors_total, 1);
+        assert_eq!(snapshot.latency_sum_ms, 20_305);
+
+        let counts: Vec<u64> = snapshot.latency_buckets.iter().map(|b| b.count).collect();
+        assert_eq!(counts, vec![1, 1, 1, 1, 2, 2, 2, 2, 2, 3]);
+        assert_eq!(snapshot.latency_buckets[0].le_ms, Some(10));
+        assert_eq!(snapshot.latency_buckets.last().unwrap().le_ms, None);
+    }
+}
@@ -87,6 +87,116 @@ pub struct LogoutRequest {
     pub logout: String,
 }
 
+/// Password change request for `/api/user/password`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct PasswordChangeRequest {
+    /// Encoded current password
+    #[serde(rename = "CurrentPassword")]
+    pub current_password: String,
+
+    /// Encoded new password
+    #[serde(rename = "NewPassword")]
+    pub new_password: String,
+}
+
+/// SCRAM challenge request for `/api/user/challenge_login`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct ChallengeLoginRequest {
+    #[serde(rename = "username")]
+    pub username: String,
+
+    /// Hex-encoded client nonce
+    #[serde(rename = "firstnonce")]
+    pub first_nonce: String,
+
+    /// Challenge mode (always 1)
+    #[serde(rename = "mode")]
+    pub mode: String,
+}
+
+/// SCRAM challenge response from `/api/user/challenge_login`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct ChallengeLoginResponse {
+    /// Hex-encoded PBKDF2 salt
+    #[serde(rename = "salt")]
+    pub salt: String,
+
+    #[serde(rename = "iterations")]
+    pub iterations: String,
+
+    /// Server nonce (client nonce with the server part appended)
+    #[serde(rename = "servernonce")]
+    pub server_nonce: String,
+
+    #[serde(rename = "modeselected", default)]
+    pub mode_selected: Option<String>,
+}
+
+/// SCRAM final request for `/api/user/authentication_login`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct AuthenticationLoginRequest {
+    /// Hex-encoded client proof
+    #[serde(rename = "clientproof")]
+    pub client_proof: String,
+
+    #[serde(rename = "finalnonce")]
+    pub final_nonce: String,
+}
+
+/// SCRAM final response from `/api/user/authentication_login`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct AuthenticationLoginResponse {
+    /// Hex-encoded server signature
+    #[serde(rename = "serversignature")]
+    pub server_signature: String,
+}
+
+impl ChallengeLoginRequest {
+    /// Create a new challenge request
+    pub fn new(username: &str, first_nonce: &str) -> Self {
+        Self {
+            username: username.to_string(),
+            first_nonce: first_nonce.to_string(),
+            mode: "1".to_string(),
+        }
+    }
+}
+
+/// Combined session cookie and CSRF token from `/api/webserver/SesTokInfo`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct SesTokInfo {
+    /// Session cookie, usually already in `SessionID=...` form
+    #[serde(rename = "SesInfo")]
+    pub ses_info: String,
+
+    /// CSRF token
+    #[serde(rename = "TokInfo")]
+    pub tok_info: String,
+}
+
+impl SesTokInfo {
+    /// Session cookie as a `SessionID=...` pair
+    pub fn session_cookie(&self) -> String {
+        let ses_info = self.ses_info.trim();
+        if ses_info.contains('=') {
+            ses_info.to_string()
+        } else {
+            format!("SessionID={}", ses_info)
+        }
+    }
+
+    /// CSRF token
+    pub fn token(&self) -> &str {
+        self.tok_info.trim()
+    }
+}
+
 impl LoginState {
     /// Check if user is currently logged in
     pub fn is_logged_in(&self) -> bool {
@@ -97,6 +207,12 @@ impl LoginState {
     pub fn is_locked(&self) -> bool {
         self.lock_status.is_locked()
     }
+
+    /// Check if the device wants the default password changed before use.
+    /// Firmware shows the change page unless it was told to skip the guide.
+    pub fn must_change_password(&self) -> bool {
+        self.guide_modify_pwd_page_flag.trim() == "1" && self.force_skip_guide.trim() != "1"
+    }
     
     /// Get password encoding type
     pub fn password_encoding(&self) -> PasswordEncoding {
@@ -194,6 +310,49 @@ mod tests {
         assert!(xml.contains("<password_type>4</password_type>"));
     }
 
+    #[test]
+    fn test_challenge_login_parsing() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<salt>00112233445566778899aabbccddeeff00112233445566778899aabbccddeeff</salt>
+<iterations>100</iterations>
+<servernonce>aaaaSERVERPART</servernonce>
+<modeselected>1</modeselected>
+</response>"#;
+
+        let challenge: ChallengeLoginResponse = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(challenge.iterations, "100");
+        assert_eq!(challenge.server_nonce, "aaaaSERVERPART");
+
+        let request = ChallengeLoginRequest::new("admin", "aaaa");
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+        assert!(xml.contains("<username>admin</username>"));
+        assert!(xml.contains("<firstnonce>aaaa</firstnonce>"));
+        assert!(xml.contains("<mode>1</mode>"));
+    }
+
+    #[test]
+    fn test_ses_tok_info_parsing() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<SesInfo>SessionID=nLxJ1sFQ2Rb0t9hbwIoSDU7iD9WxVJeF1dmXjyNRhdXq2Gbyu5Xq6uc6vAz2WbCG5hAXVrWKe9yREvQ1Jq1z0NEnP5l4i5tIjF5nL8a0zKpV6jYkFRzGDuEoZ7DXQ3Ut</SesInfo>
+<TokInfo>Ae6c2yEHSM/nKxMJ7mYNdgIYBoi9R+Xu</TokInfo>
+</response>"#;
+
+        let info: SesTokInfo = serde_xml_rs::from_str(xml).unwrap();
+        assert!(info.session_cookie().starts_with("SessionID=nLxJ1sFQ2Rb0"));
+        assert_eq!(info.token(), "Ae6c2yEHSM/nKxMJ7mYNdgIYBoi9R+Xu");
+    }
+
+    #[test]
+    fn test_ses_tok_info_bare_session_id() {
+        let info = SesTokInfo {
+            ses_info: "abc123".to_string(),
+            tok_info: "token".to_string(),
+        };
+        assert_eq!(info.session_cookie(), "SessionID=abc123");
+    }
+
     #[test]
     fn test_password_encoding_detection() {
         let mut state = LoginState {
@@ -218,4 +377,28 @@ mod tests {
         state.password_type = "4".to_string();
         assert_eq!(state.password_encoding(), PasswordEncoding::Sha256);
     }
+
+    #[test]
+    fn test_must_change_password() {
+        let xml = |guide: &str, skip: &str| {
+            format!(
+                "<response><password_type>4</password_type><extern_password_type>1</extern_password_type>\
+<history_login_flag>0</history_login_flag><State>-1</State>\
+<guidemodifypwdpageflag>{}</guidemodifypwdpageflag><rsapadingtype>1</rsapadingtype>\
+<accounts_number>1</accounts_number><wifipwdsamewithwebpwd>0</wifipwdsamewithwebpwd>\
+<remainwaittime>0</remainwaittime><lockstatus>0</lockstatus><forceskipguide>{}</forceskipguide>\
+<username></username><firstlogin>1</firstlogin><userlevel></userlevel></response>",
+                guide, skip
+            )
+        };
+
+        let state: LoginState = serde_xml_rs::from_str(&xml("1", "0")).unwrap();
+        assert!(state.must_change_password());
+
+        let state: LoginState = serde_xml_rs::from_str(&xml("1", "1")).unwrap();
+        assert!(!state.must_change_password());
+
+        let state: LoginState = serde_xml_rs::from_str(&xml("0", "0")).unwrap();
+        assert!(!state.must_change_password());
+    }
 }
\ No newline at end of file
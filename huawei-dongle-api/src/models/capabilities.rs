@@ -0,0 +1,25 @@
+//! Endpoint capability discovery results
+
+use serde::Serialize;
+
+/// Which optional endpoint groups the firmware provides, as found by
+/// [`Client::capabilities`](crate::Client::capabilities).
+///
+/// An endpoint counts as present unless it answered with HTTP 404 or the
+/// device's "not supported" error (`100002`). Endpoints that merely need a
+/// login are reported as present.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Capabilities {
+    /// `/api/device/information`
+    pub has_device_information: bool,
+    /// `/api/monitoring/status`
+    pub has_monitoring: bool,
+    /// `/api/monitoring/traffic-statistics`
+    pub has_traffic_stats: bool,
+    /// `/api/wlan/basic-settings`
+    pub has_wlan: bool,
+    /// `/api/sms/config`
+    pub has_sms: bool,
+    /// `/api/dialup/mobile-dataswitch`
+    pub has_dialup: bool,
+}
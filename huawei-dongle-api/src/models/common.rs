@@ -1,7 +1,10 @@
 //! Common models and types
 
 use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
 use super::enums::ApiErrorCode;
+use crate::encoding::trim_xml_prefix;
+use crate::error::Error;
 
 /// Standard API response wrapper
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -61,11 +64,21 @@ impl ApiError {
 }
 
 /// Generic success/error response
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Firmware versions disagree on how success is encoded, so all of the
+/// following shapes are accepted:
+///
+/// - `<response><OK/></response>`
+/// - `<response>OK</response>`
+/// - `<response><Result>OK</Result></response>` (or `success`)
+/// - a bare `OK` body without any XML wrapper
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename = "response")]
 pub struct Response {
     #[serde(rename = "OK", default)]
     pub ok: Option<String>,
+    #[serde(rename = "Result", default)]
+    pub result: Option<String>,
     #[serde(rename = "ErrorCode", default)]
     pub error_code: Option<String>,
     #[serde(rename = "ErrorMessage", default)]
@@ -73,9 +86,30 @@ pub struct Response {
 }
 
 impl Response {
+    /// Parse a generic response body, accepting a bare `OK` body as success
+    pub fn parse(text: &str) -> Result<Self, serde_xml_rs::Error> {
+        if is_ok_text(text) {
+            return Ok(Self {
+                ok: Some(text.trim().to_string()),
+                ..Default::default()
+            });
+        }
+        serde_xml_rs::from_str(text)
+    }
+
     /// Check if the response indicates success
     pub fn is_success(&self) -> bool {
-        self.ok.is_some() || self.error_code.as_deref() == Some("0") || self.error_code.is_none()
+        if let Some(code) = self.error_code.as_deref() {
+            if code.trim() != "0" && self.ok.is_none() {
+                return false;
+            }
+        }
+
+        if let Some(result) = self.result.as_deref() {
+            return is_ok_text(result);
+        }
+
+        true
     }
 
     /// Get the error code as an integer
@@ -89,8 +123,55 @@ impl Response {
     }
 }
 
+/// Check whether a value is one of the success markers used by the firmware
+fn is_ok_text(text: &str) -> bool {
+    let text = text.trim();
+    text.eq_ignore_ascii_case("OK") || text.eq_ignore_ascii_case("success")
+}
+
+/// Parse an IPv4 or IPv6 address, treating blank values as absent
+///
+/// Some firmware reports unset DNS servers as empty elements.
+pub fn parse_ip_addr(value: &str) -> Option<IpAddr> {
+    value.trim().parse().ok()
+}
+
+/// Serde helper for boolean flags the device encodes as `0`/`1`
+pub(crate) mod flag {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &bool, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(if *value { "1" } else { "0" })
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        match s.trim() {
+            "1" | "true" => Ok(true),
+            "0" | "false" | "" => Ok(false),
+            other => Err(serde::de::Error::custom(format!("Invalid flag value: {}", other))),
+        }
+    }
+}
+
+/// Parse a signal value such as `-95dBm`, `>=-44dBm` or `12.5dB`
+pub(crate) fn parse_signal_value(value: Option<&str>) -> Option<f32> {
+    let value = value?.trim();
+    let value = value.trim_start_matches(">=").trim_start_matches("<=");
+    let value = value
+        .strip_suffix("dBm")
+        .or_else(|| value.strip_suffix("dB"))
+        .unwrap_or(value);
+    value.trim().parse().ok()
+}
+
 /// Check if XML text contains an error response and parse it
 pub fn check_for_api_error(xml_text: &str) -> Option<ApiError> {
+    let xml_text = trim_xml_prefix(xml_text);
+    if is_ok_text(xml_text) {
+        return None;
+    }
+
     if xml_text.contains("<error>") && xml_text.contains("<code>") {
         if let Ok(error) = serde_xml_rs::from_str::<ApiError>(xml_text) {
             return Some(error);
@@ -99,6 +180,46 @@ pub fn check_for_api_error(xml_text: &str) -> Option<ApiError> {
     None
 }
 
+/// Check the body of a write operation for success.
+///
+/// Firmware answers set operations in several ways: `<response>OK</response>`,
+/// a bare `OK`, a `<response>` without the usual fields, or an empty body.
+/// All of these count as success. An `<error>` body or a `<response>`
+/// carrying a non-zero `ErrorCode` becomes [`Error::api`], using `failure`
+/// when the device gives no message. Anything else (an HTML page, truncated
+/// XML, a different root element) is an [`Error::parse`] against `path`.
+pub fn parse_generic_ok(path: &str, text: &str, failure: &str) -> Result<(), Error> {
+    if let Some(error) = check_for_api_error(text) {
+        return Err(Error::api(error.code.as_int(), error.error_message()));
+    }
+
+    let body = trim_xml_prefix(text);
+    let body = match body.strip_prefix("<?xml") {
+        Some(rest) => rest.split_once("?>").map_or("", |(_, rest)| rest).trim(),
+        None => body.trim(),
+    };
+    if body.is_empty() || is_ok_text(body) {
+        return Ok(());
+    }
+
+    if !body.starts_with("<response>") && !body.starts_with("<response ") {
+        return Err(Error::parse(
+            path,
+            <serde_xml_rs::Error as serde::de::Error>::custom("expected a <response> root element"),
+            text,
+        ));
+    }
+
+    let response = serde_xml_rs::from_str::<Response>(body).map_err(|e| Error::parse(path, e, text))?;
+    if !response.is_success() {
+        return Err(Error::api(
+            response.error_code().unwrap_or(-1),
+            response.error_message().unwrap_or(failure).to_string(),
+        ));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,6 +252,101 @@ mod tests {
         assert!(check_for_api_error(success_xml).is_none());
     }
 
+    #[test]
+    fn test_sms_and_pin_error_codes() {
+        let error = check_for_api_error("<error><code>111022</code><message/></error>").unwrap();
+        assert_eq!(error.code, ApiErrorCode::SmsNotEnoughSpace);
+        assert!(error.code.is_sms_error());
+        assert!(!error.code.is_pin_error());
+        assert_eq!(error.error_message(), "Not enough space for SMS");
+
+        let error = check_for_api_error("<error><code>107003</code><message/></error>").unwrap();
+        assert_eq!(error.code, ApiErrorCode::PukExhausted);
+        assert!(error.code.is_pin_error());
+        assert!(!error.code.is_sms_error());
+        assert_eq!(error.error_message(), "PUK attempts exhausted");
+    }
+
+    #[test]
+    fn test_unknown_error_code_is_reported() {
+        let error = check_for_api_error("<error><code>999999</code><message/></error>").unwrap();
+        assert_eq!(error.code, ApiErrorCode::Other(999999));
+        assert_eq!(error.code.as_int(), 999999);
+        assert_eq!(error.error_message(), "Unknown error 999999");
+    }
+
+    #[test]
+    fn test_error_code_round_trip() {
+        for code in [111001, 111019, 111020, 111022, 112001, 113017, 114001, 114002, 123456] {
+            let error = ApiError { code: ApiErrorCode::from_int(code), message: None };
+            let xml = serde_xml_rs::to_string(&error).unwrap();
+            let parsed: ApiError = serde_xml_rs::from_str(&xml).unwrap();
+            assert_eq!(parsed.code.as_int(), code);
+        }
+    }
+
+    #[test]
+    fn test_check_for_api_error_with_bom_and_blank_lines() {
+        let error_xml = "\u{feff}\r\n\n<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<error><code>125002</code><message/></error>";
+        let error = check_for_api_error(error_xml).unwrap();
+        assert_eq!(error.code, ApiErrorCode::CsrfTokenInvalid);
+    }
+
+    #[test]
+    fn test_check_for_api_error_bare_ok() {
+        assert!(check_for_api_error("OK").is_none());
+        assert!(check_for_api_error("OK\n").is_none());
+    }
+
+    #[test]
+    fn test_response_ok_element() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?><response><OK></OK></response>"#;
+        let response = Response::parse(xml).unwrap();
+        assert!(response.is_success());
+    }
+
+    #[test]
+    fn test_response_ok_text_content() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>OK</response>"#;
+        let response = Response::parse(xml).unwrap();
+        assert!(response.error_code.is_none());
+        assert!(response.is_success());
+    }
+
+    #[test]
+    fn test_response_result_element() {
+        let xml = r#"<response><Result>OK</Result></response>"#;
+        assert!(Response::parse(xml).unwrap().is_success());
+
+        let xml = r#"<response><Result>success</Result></response>"#;
+        assert!(Response::parse(xml).unwrap().is_success());
+
+        let xml = r#"<response><Result>failure</Result></response>"#;
+        assert!(!Response::parse(xml).unwrap().is_success());
+    }
+
+    #[test]
+    fn test_response_bare_ok_body() {
+        let response = Response::parse("OK").unwrap();
+        assert!(response.is_success());
+
+        let response = Response::parse("  OK\r\n").unwrap();
+        assert!(response.is_success());
+    }
+
+    #[test]
+    fn test_response_error_code() {
+        let xml = r#"<response><ErrorCode>100005</ErrorCode><ErrorMessage>Format error</ErrorMessage></response>"#;
+        let response = Response::parse(xml).unwrap();
+        assert!(!response.is_success());
+        assert_eq!(response.error_code(), Some(100005));
+        assert_eq!(response.error_message(), Some("Format error"));
+
+        let xml = r#"<response><ErrorCode>0</ErrorCode></response>"#;
+        assert!(Response::parse(xml).unwrap().is_success());
+    }
+
     #[test]
     fn test_error_code_classification() {
         let mut error = ApiError { 
@@ -145,4 +361,78 @@ mod tests {
         assert!(error.is_csrf_error());
         assert!(!error.is_auth_error());
     }
+
+    #[test]
+    fn test_parse_ip_addr() {
+        assert_eq!(
+            parse_ip_addr("192.168.8.1"),
+            Some(IpAddr::V4(std::net::Ipv4Addr::new(192, 168, 8, 1)))
+        );
+        assert_eq!(
+            parse_ip_addr(" 2001:4860:4860::8888 "),
+            Some("2001:4860:4860::8888".parse().unwrap())
+        );
+        assert_eq!(parse_ip_addr(""), None);
+        assert_eq!(parse_ip_addr("   "), None);
+        assert_eq!(parse_ip_addr("not-an-ip"), None);
+    }
+
+    #[test]
+    fn test_parse_generic_ok_empty_body() {
+        assert!(parse_generic_ok("/api/test", "", "Test failed").is_ok());
+        assert!(parse_generic_ok("/api/test", "  \r\n", "Test failed").is_ok());
+        assert!(parse_generic_ok("/api/test", "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n", "Test failed").is_ok());
+    }
+
+    #[test]
+    fn test_parse_generic_ok_response_ok() {
+        assert!(parse_generic_ok("/api/test", "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<response>OK</response>", "Test failed").is_ok());
+        assert!(parse_generic_ok("/api/test", "OK", "Test failed").is_ok());
+        assert!(parse_generic_ok("/api/test", "<response><Result>success</Result></response>", "Test failed").is_ok());
+        assert!(parse_generic_ok("/api/test", "<response><Unexpected>1</Unexpected></response>", "Test failed").is_ok());
+    }
+
+    #[test]
+    fn test_parse_generic_ok_error_body() {
+        let result = parse_generic_ok(
+            "/api/test",
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<error><code>100005</code><message></message></error>",
+            "Test failed",
+        );
+        assert!(matches!(result, Err(Error::Api { code: 100005, .. })));
+
+        let result = parse_generic_ok("/api/test", "<response><ErrorCode>100002</ErrorCode></response>", "Test failed");
+        assert!(matches!(result, Err(Error::Api { code: 100002, .. })));
+    }
+
+    #[test]
+    fn test_parse_generic_ok_failure_message() {
+        let result = parse_generic_ok(
+            "/api/test",
+            "<response><ErrorCode>100002</ErrorCode></response>",
+            "SMS send failed",
+        );
+        match result {
+            Err(Error::Api { message, .. }) => assert_eq!(message, "SMS send failed"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_generic_ok_rejects_unparseable_body() {
+        for body in [
+            "<html><body>502 Bad Gateway</body></html>",
+            "<response><ErrorCode>1",
+            "not xml at all",
+            "<?xml version=\"1.0\"?><result>OK</result>",
+        ] {
+            let result = parse_generic_ok("/api/test", body, "Test failed");
+            assert!(
+                matches!(&result, Err(Error::Parse { endpoint, .. }) if endpoint == "/api/test"),
+                "body {:?} gave {:?}",
+                body,
+                result
+            );
+        }
+    }
 }
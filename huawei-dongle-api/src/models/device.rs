@@ -1,7 +1,12 @@
 //! Device information models
 
+use chrono::{DateTime, NaiveDateTime};
 use serde::{Deserialize, Serialize};
-use super::enums::DeviceControlType;
+use std::collections::BTreeSet;
+use super::common::{flag, parse_signal_value};
+use super::enums::{AntennaType, DeviceControlType, NetworkType, RadioTechnology, UpdateState};
+use super::monitoring::ConvergedStatus;
+use super::pin::PinStatus;
 
 /// Device information response
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,6 +58,439 @@ pub struct DeviceInformation {
     pub work_mode: Option<String>,
 }
 
+/// Mobile country codes whose networks use 3-digit MNCs (North America,
+/// the Caribbean, India's newer ranges and parts of South America)
+const THREE_DIGIT_MNC_MCCS: &[&str] = &[
+    "302", "310", "311", "312", "313", "314", "315", "316", "334", "338", "342", "344", "346",
+    "348", "352", "354", "356", "358", "360", "365", "366", "376", "405", "708", "722", "732",
+];
+
+impl DeviceInformation {
+    /// Check that the IMEI is 15 digits with a valid Luhn check digit
+    pub fn imei_valid(&self) -> bool {
+        let imei = self.imei.trim();
+        if imei.len() != 15 || !imei.bytes().all(|b| b.is_ascii_digit()) {
+            return false;
+        }
+
+        let sum: u32 = imei
+            .bytes()
+            .rev()
+            .enumerate()
+            .map(|(i, b)| {
+                let digit = u32::from(b - b'0');
+                if i % 2 == 1 {
+                    let doubled = digit * 2;
+                    if doubled > 9 { doubled - 9 } else { doubled }
+                } else {
+                    digit
+                }
+            })
+            .sum();
+        sum.is_multiple_of(10)
+    }
+
+    /// Radio technologies the hardware supports, parsed from `supportmode`
+    pub fn supported_modes(&self) -> SupportedModes {
+        self.support_mode
+            .as_deref()
+            .map(SupportedModes::parse)
+            .unwrap_or_default()
+    }
+
+    /// Network type the device currently works in, parsed from `workmode`.
+    /// Accepts numeric network type codes (`19`) and names (`LTE`); `None`
+    /// if missing or not representable as a [`NetworkType`] (e.g. `GSM`).
+    pub fn work_mode_network(&self) -> Option<NetworkType> {
+        let work_mode = self.work_mode.as_deref()?.trim();
+        if let Some(network) = NetworkType::from_code(work_mode) {
+            return Some(network);
+        }
+
+        match RadioTechnology::from_name(work_mode)? {
+            RadioTechnology::Wcdma => Some(NetworkType::Hspa),
+            RadioTechnology::Lte => Some(NetworkType::Lte),
+            RadioTechnology::Nr => Some(NetworkType::FiveGSa),
+            _ => None,
+        }
+    }
+
+    /// Split the IMSI into its mobile country code and mobile network code,
+    /// or `None` if the IMSI is missing or malformed
+    pub fn imsi_mcc_mnc(&self) -> Option<(String, String)> {
+        let imsi = self.imsi.as_deref()?.trim();
+        if imsi.len() < 6 || !imsi.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let mcc = &imsi[..3];
+        let mnc_len = if THREE_DIGIT_MNC_MCCS.contains(&mcc) { 3 } else { 2 };
+        Some((mcc.to_string(), imsi[3..3 + mnc_len].to_string()))
+    }
+}
+
+/// Set of radio technologies from the `supportmode` field.
+///
+/// Firmware reports either pipe-delimited names (`LTE|WCDMA|GSM`) or
+/// concatenated two-digit network mode codes (`030201`); unknown entries are
+/// skipped.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SupportedModes {
+    technologies: BTreeSet<RadioTechnology>,
+}
+
+impl SupportedModes {
+    /// Parse a `supportmode` value
+    pub fn parse(value: &str) -> Self {
+        let value = value.trim();
+        let technologies = if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+            value
+                .as_bytes()
+                .chunks(2)
+                .filter_map(|code| std::str::from_utf8(code).ok())
+                .filter_map(RadioTechnology::from_mode_code)
+                .collect()
+        } else {
+            value
+                .split(['|', ',', ' '])
+                .filter_map(RadioTechnology::from_name)
+                .collect()
+        };
+        Self { technologies }
+    }
+
+    /// Check if `technology` is supported
+    pub fn contains(&self, technology: RadioTechnology) -> bool {
+        self.technologies.contains(&technology)
+    }
+
+    /// Supported technologies, oldest generation first
+    pub fn iter(&self) -> impl Iterator<Item = RadioTechnology> + '_ {
+        self.technologies.iter().copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.technologies.is_empty()
+    }
+}
+
+/// Detailed radio signal metrics from `/api/device/signal`.
+///
+/// Values are reported as strings with units appended (e.g. `-95dBm`, `-11dB`).
+/// Use the typed accessors to get numeric values. 5G fields are only present
+/// on NR-capable devices.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct SignalInfo {
+    #[serde(rename = "rsrp")]
+    pub rsrp: Option<String>,
+
+    #[serde(rename = "rsrq")]
+    pub rsrq: Option<String>,
+
+    #[serde(rename = "sinr")]
+    pub sinr: Option<String>,
+
+    #[serde(rename = "rssi")]
+    pub rssi: Option<String>,
+
+    #[serde(rename = "cell_id")]
+    pub cell_id: Option<String>,
+
+    #[serde(rename = "pci")]
+    pub pci: Option<String>,
+
+    #[serde(rename = "band")]
+    pub band: Option<String>,
+
+    #[serde(rename = "dlbandwidth")]
+    pub dlbandwidth: Option<String>,
+
+    #[serde(rename = "ulbandwidth")]
+    pub ulbandwidth: Option<String>,
+
+    #[serde(rename = "nrrsrp")]
+    pub nrrsrp: Option<String>,
+
+    #[serde(rename = "nrrsrq")]
+    pub nrrsrq: Option<String>,
+
+    #[serde(rename = "nrsinr")]
+    pub nrsinr: Option<String>,
+
+    #[serde(rename = "tac")]
+    pub tac: Option<String>,
+
+    #[serde(rename = "earfcn")]
+    pub earfcn: Option<String>,
+}
+
+impl SignalInfo {
+    /// LTE reference signal received power in dBm
+    pub fn rsrp_dbm(&self) -> Option<i32> {
+        parse_signal_value(self.rsrp.as_deref()).map(|v| v.round() as i32)
+    }
+
+    /// LTE reference signal received quality in dB
+    pub fn rsrq_db(&self) -> Option<f32> {
+        parse_signal_value(self.rsrq.as_deref())
+    }
+
+    /// LTE signal to interference plus noise ratio in dB
+    pub fn sinr_db(&self) -> Option<f32> {
+        parse_signal_value(self.sinr.as_deref())
+    }
+
+    /// Received signal strength indicator in dBm
+    pub fn rssi_dbm(&self) -> Option<i32> {
+        parse_signal_value(self.rssi.as_deref()).map(|v| v.round() as i32)
+    }
+
+    /// 5G NR reference signal received power in dBm
+    pub fn nr_rsrp_dbm(&self) -> Option<i32> {
+        parse_signal_value(self.nrrsrp.as_deref()).map(|v| v.round() as i32)
+    }
+
+    /// 5G NR reference signal received quality in dB
+    pub fn nr_rsrq_db(&self) -> Option<f32> {
+        parse_signal_value(self.nrrsrq.as_deref())
+    }
+
+    /// 5G NR signal to interference plus noise ratio in dB
+    pub fn nr_sinr_db(&self) -> Option<f32> {
+        parse_signal_value(self.nrsinr.as_deref())
+    }
+
+    /// Check if the device reports 5G NR signal metrics
+    pub fn has_nr(&self) -> bool {
+        self.nrrsrp.as_deref().is_some_and(|v| !v.trim().is_empty())
+    }
+}
+
+/// Neighbouring cell reported by `/api/net/cell-info`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeighborCell {
+    #[serde(rename = "CellID")]
+    pub cell_id: Option<String>,
+
+    #[serde(rename = "PCI")]
+    pub pci: Option<String>,
+
+    #[serde(rename = "EARFCN")]
+    pub earfcn: Option<String>,
+
+    #[serde(rename = "RSRP")]
+    pub rsrp: Option<String>,
+
+    #[serde(rename = "RSRQ")]
+    pub rsrq: Option<String>,
+}
+
+impl NeighborCell {
+    /// Reference signal received power in dBm
+    pub fn rsrp_dbm(&self) -> Option<i32> {
+        parse_signal_value(self.rsrp.as_deref()).map(|v| v.round() as i32)
+    }
+
+    /// Reference signal received quality in dB
+    pub fn rsrq_db(&self) -> Option<f32> {
+        parse_signal_value(self.rsrq.as_deref())
+    }
+}
+
+/// Repeated `<NeighborCell>` entries
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NeighborCells {
+    #[serde(rename = "$value", default)]
+    pub cells: Vec<NeighborCell>,
+}
+
+/// Neighbour cell list response from `/api/net/cell-info`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct NeighborCellList {
+    #[serde(rename = "NeighborCells", default)]
+    pub cells: NeighborCells,
+}
+
+impl NeighborCellList {
+    /// Get the neighbouring cells
+    pub fn cells(&self) -> &[NeighborCell] {
+        &self.cells.cells
+    }
+}
+
+/// Serving cell identity combined from `/api/device/signal`, the current PLMN
+/// and `/api/net/cell-info`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CellInfo {
+    /// E-UTRAN cell identity (28 bits)
+    pub cell_id: Option<u32>,
+    /// eNodeB ID, the upper 20 bits of the cell identity
+    pub enb_id: Option<u32>,
+    /// Local cell ID within the eNodeB, the lower 8 bits of the cell identity
+    pub local_cell_id: Option<u8>,
+    /// Operator code (MCC + MNC)
+    pub plmn: Option<String>,
+    /// Tracking area code
+    pub tac: Option<String>,
+    pub earfcn: Option<String>,
+    /// Empty if the device does not report neighbouring cells
+    pub neighbors: Vec<NeighborCell>,
+}
+
+impl CellInfo {
+    /// Combine signal information with the operator code and neighbour list
+    pub fn new(signal: &SignalInfo, plmn: Option<String>, neighbors: Vec<NeighborCell>) -> Self {
+        let cell_id = signal
+            .cell_id
+            .as_deref()
+            .and_then(|id| id.trim().parse::<u32>().ok());
+        let (enb_id, local_cell_id) = cell_id.map(Self::split_cell_id).unzip();
+
+        Self {
+            cell_id,
+            enb_id,
+            local_cell_id,
+            plmn,
+            tac: signal.tac.clone(),
+            earfcn: signal.earfcn.clone(),
+            neighbors,
+        }
+    }
+
+    /// Split a raw LTE cell identity into eNodeB ID and local cell ID
+    pub fn split_cell_id(cell_id: u32) -> (u32, u8) {
+        (cell_id >> 8, (cell_id & 0xFF) as u8)
+    }
+}
+
+/// Format used for `systemTime` by firmware that does not report epoch seconds
+const DEVICE_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Device clock settings from `/api/device/time`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct DeviceTime {
+    /// Either epoch seconds or `YYYY-MM-DD HH:MM:SS`, depending on firmware
+    #[serde(rename = "systemTime")]
+    pub system_time: Option<String>,
+
+    #[serde(rename = "timezone")]
+    pub timezone: Option<String>,
+
+    #[serde(rename = "autoTime")]
+    pub auto_time: Option<String>,
+
+    #[serde(rename = "ntpServer")]
+    pub ntp_server: Option<String>,
+}
+
+impl DeviceTime {
+    /// Parse `systemTime`, accepting both epoch seconds (UTC) and the
+    /// formatted string (device local time)
+    pub fn datetime(&self) -> Option<NaiveDateTime> {
+        let value = self.system_time.as_deref()?.trim();
+        if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+            return DateTime::from_timestamp(value.parse().ok()?, 0).map(|dt| dt.naive_utc());
+        }
+        NaiveDateTime::parse_from_str(value, DEVICE_TIME_FORMAT)
+            .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S"))
+            .ok()
+    }
+
+    /// Check if the clock is synchronised via NTP
+    pub fn is_auto_time(&self) -> bool {
+        self.auto_time.as_deref().map(str::trim) == Some("1")
+    }
+}
+
+/// Request for `/api/device/time`, either setting the clock manually or
+/// enabling NTP synchronisation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct DeviceTimeRequest {
+    #[serde(rename = "systemTime", skip_serializing_if = "Option::is_none")]
+    pub system_time: Option<String>,
+
+    #[serde(rename = "timezone", skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+
+    #[serde(rename = "autoTime")]
+    pub auto_time: String,
+
+    #[serde(rename = "ntpServer", skip_serializing_if = "Option::is_none")]
+    pub ntp_server: Option<String>,
+}
+
+impl DeviceTimeRequest {
+    /// Set the clock to `time` and disable NTP
+    pub fn manual(time: NaiveDateTime) -> Self {
+        Self {
+            system_time: Some(time.format(DEVICE_TIME_FORMAT).to_string()),
+            timezone: None,
+            auto_time: "0".to_string(),
+            ntp_server: None,
+        }
+    }
+
+    /// Synchronise the clock with `server`
+    pub fn ntp<S: Into<String>>(server: S) -> Self {
+        Self {
+            system_time: None,
+            timezone: None,
+            auto_time: "1".to_string(),
+            ntp_server: Some(server.into()),
+        }
+    }
+
+    /// Also set the time zone (e.g. `UTC+01:00`)
+    pub fn with_timezone<S: Into<String>>(mut self, timezone: S) -> Self {
+        self.timezone = Some(timezone.into());
+        self
+    }
+}
+
+/// Antenna configuration from `/api/device/antenna_settings`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct AntennaSettings {
+    #[serde(rename = "antennatype", default)]
+    pub antenna_type: Option<AntennaType>,
+
+    /// Raw antenna status as reported by the firmware
+    #[serde(rename = "antenna_status", default)]
+    pub antenna_status: Option<String>,
+}
+
+/// Request for `/api/device/antenna_set_type`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct AntennaTypeRequest {
+    #[serde(rename = "antennatype")]
+    pub antenna_type: AntennaType,
+}
+
+impl AntennaTypeRequest {
+    pub fn new(antenna_type: AntennaType) -> Self {
+        Self { antenna_type }
+    }
+}
+
+/// Airplane (fly) mode request for `/api/net/flymode`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct FlyModeRequest {
+    #[serde(rename = "flymode", with = "flag")]
+    pub enabled: bool,
+}
+
+impl FlyModeRequest {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+}
+
 /// Device control request for operations like reboot
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename = "request")]
@@ -62,23 +500,459 @@ pub struct DeviceControlRequest {
 }
 
 impl DeviceControlRequest {
+    /// Create a request for any control operation
+    pub fn new(control: DeviceControlType) -> Self {
+        Self { control }
+    }
+
     /// Create a reboot request
     pub fn reboot() -> Self {
-        Self { control: DeviceControlType::Reboot }
+        Self::new(DeviceControlType::Reboot)
     }
 
     /// Create a power off request  
     pub fn power_off() -> Self {
-        Self { control: DeviceControlType::PowerOff }
+        Self::new(DeviceControlType::PowerOff)
     }
 
     /// Create a factory reset request
     pub fn factory_reset() -> Self {
-        Self { control: DeviceControlType::FactoryReset }
+        Self::new(DeviceControlType::FactoryReset)
     }
 
     /// Create a backup configuration request
     pub fn backup_configuration() -> Self {
-        Self { control: DeviceControlType::BackupConfiguration }
+        Self::new(DeviceControlType::BackupConfiguration)
+    }
+}
+
+/// Firmware update status from `/api/online-update/status`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct UpdateStatus {
+    /// Raw status code, see [`state`](Self::state)
+    #[serde(rename = "Status", default)]
+    pub status: Option<String>,
+
+    #[serde(rename = "CurrentVersion", default)]
+    pub current_version: Option<String>,
+
+    /// Newest firmware version offered, if an update is available
+    #[serde(rename = "LatestVersion", default)]
+    pub latest_version: Option<String>,
+
+    /// Download or install progress in percent
+    #[serde(rename = "Progress", default)]
+    pub progress: Option<String>,
+}
+
+impl UpdateStatus {
+    /// Update state, or `None` if missing or not known to this library
+    pub fn state(&self) -> Option<UpdateState> {
+        self.status.as_deref().and_then(UpdateState::from_code)
+    }
+
+    /// Progress in percent (0-100), or `None` if missing or malformed
+    pub fn progress_percent(&self) -> Option<u8> {
+        self.progress
+            .as_deref()
+            .and_then(|progress| progress.trim().trim_end_matches('%').parse::<u8>().ok())
+            .map(|progress| progress.min(100))
+    }
+
+    pub fn is_update_available(&self) -> bool {
+        self.state() == Some(UpdateState::Available)
+    }
+}
+
+/// SIM card details combined from `/api/device/information`,
+/// `/api/pin/status` and `/api/monitoring/converged-status`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SimInfo {
+    /// SIM state code (255=no SIM, 257=ready, 260=PIN required, ...)
+    pub sim_state: Option<String>,
+    pub iccid: Option<String>,
+    pub imsi: Option<String>,
+    /// Subscriber phone number, rarely provisioned on data SIMs
+    pub msisdn: Option<String>,
+    /// Active slot on dual-SIM devices
+    pub sim_slot: Option<u8>,
+}
+
+impl SimInfo {
+    /// Combine whichever sources are available. The PIN status takes
+    /// precedence over the converged status for the SIM state.
+    pub fn from_sources(
+        info: Option<&DeviceInformation>,
+        pin: Option<&PinStatus>,
+        converged: Option<&ConvergedStatus>,
+    ) -> Self {
+        let sim_state = pin
+            .map(|pin| pin.sim_state.clone())
+            .or_else(|| converged.and_then(|status| status.sim_state.clone()));
+
+        Self {
+            sim_state: non_blank(sim_state),
+            iccid: non_blank(info.and_then(|info| info.iccid.clone())),
+            imsi: non_blank(info.and_then(|info| info.imsi.clone())),
+            msisdn: non_blank(info.and_then(|info| info.msisdn.clone())),
+            sim_slot: converged
+                .and_then(|status| status.sim_slot.as_deref())
+                .and_then(|slot| slot.trim().parse().ok()),
+        }
+    }
+
+    /// Check if a SIM card is inserted. Without a state this falls back to
+    /// whether the device reported SIM identifiers.
+    pub fn is_sim_present(&self) -> bool {
+        match self.sim_state.as_deref() {
+            Some(state) => state.trim() != "255",
+            None => self.iccid.is_some() || self.imsi.is_some(),
+        }
+    }
+}
+
+fn non_blank(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::device_information_xml;
+
+    #[test]
+    fn test_signal_info_lte() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<pci>254</pci>
+<sc></sc>
+<cell_id>27447297</cell_id>
+<rsrq>-11.0dB</rsrq>
+<rsrp>-95dBm</rsrp>
+<rssi>-67dBm</rssi>
+<sinr>13dB</sinr>
+<rscp></rscp>
+<ecio></ecio>
+<mode>7</mode>
+<ulbandwidth>20MHz</ulbandwidth>
+<dlbandwidth>20MHz</dlbandwidth>
+<band>3</band>
+</response>"#;
+
+        let signal: SignalInfo = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(signal.rsrp_dbm(), Some(-95));
+        assert_eq!(signal.rsrq_db(), Some(-11.0));
+        assert_eq!(signal.sinr_db(), Some(13.0));
+        assert_eq!(signal.rssi_dbm(), Some(-67));
+        assert_eq!(signal.cell_id.as_deref(), Some("27447297"));
+        assert_eq!(signal.band.as_deref(), Some("3"));
+        assert!(!signal.has_nr());
+        assert_eq!(signal.nr_rsrp_dbm(), None);
+    }
+
+    #[test]
+    fn test_signal_info_5g() {
+        let xml = r#"<response>
+<pci>101</pci>
+<cell_id>12345678</cell_id>
+<rsrq>-10dB</rsrq>
+<rsrp>&gt;=-44dBm</rsrp>
+<rssi>-51dBm</rssi>
+<sinr>20dB</sinr>
+<band>20</band>
+<ulbandwidth>10MHz</ulbandwidth>
+<dlbandwidth>10MHz</dlbandwidth>
+<nrrsrp>-88dBm</nrrsrp>
+<nrrsrq>-12dB</nrrsrq>
+<nrsinr>7.5dB</nrsinr>
+</response>"#;
+
+        let signal: SignalInfo = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(signal.rsrp_dbm(), Some(-44));
+        assert!(signal.has_nr());
+        assert_eq!(signal.nr_rsrp_dbm(), Some(-88));
+        assert_eq!(signal.nr_rsrq_db(), Some(-12.0));
+        assert_eq!(signal.nr_sinr_db(), Some(7.5));
+    }
+
+    #[test]
+    fn test_split_cell_id() {
+        assert_eq!(CellInfo::split_cell_id(27447297), (107216, 1));
+        assert_eq!(CellInfo::split_cell_id(0x0ABCDE02), (0xABCDE, 2));
+    }
+
+    #[test]
+    fn test_cell_info_with_neighbors() {
+        let signal: SignalInfo = serde_xml_rs::from_str(
+            r#"<response>
+<cell_id>27447297</cell_id>
+<tac>51401</tac>
+<earfcn>DL:1300 UL:19300</earfcn>
+<band>3</band>
+</response>"#,
+        )
+        .unwrap();
+
+        let list: NeighborCellList = serde_xml_rs::from_str(
+            r#"<response>
+<NeighborCells>
+<NeighborCell>
+<CellID>27447298</CellID>
+<PCI>255</PCI>
+<EARFCN>1300</EARFCN>
+<RSRP>-101dBm</RSRP>
+<RSRQ>-14dB</RSRQ>
+</NeighborCell>
+<NeighborCell>
+<PCI>87</PCI>
+<EARFCN>6300</EARFCN>
+<RSRP>-110</RSRP>
+</NeighborCell>
+</NeighborCells>
+</response>"#,
+        )
+        .unwrap();
+        assert_eq!(list.cells().len(), 2);
+
+        let info = CellInfo::new(&signal, Some("26001".to_string()), list.cells().to_vec());
+        assert_eq!(info.cell_id, Some(27447297));
+        assert_eq!(info.enb_id, Some(107216));
+        assert_eq!(info.local_cell_id, Some(1));
+        assert_eq!(info.plmn.as_deref(), Some("26001"));
+        assert_eq!(info.tac.as_deref(), Some("51401"));
+        assert_eq!(info.earfcn.as_deref(), Some("DL:1300 UL:19300"));
+        assert_eq!(info.neighbors[0].pci.as_deref(), Some("255"));
+        assert_eq!(info.neighbors[0].rsrp_dbm(), Some(-101));
+        assert_eq!(info.neighbors[1].rsrp_dbm(), Some(-110));
+        assert_eq!(info.neighbors[1].rsrq_db(), None);
+    }
+
+    #[test]
+    fn test_cell_info_without_neighbors() {
+        let list: NeighborCellList = serde_xml_rs::from_str("<response></response>").unwrap();
+        assert!(list.cells().is_empty());
+
+        let signal: SignalInfo = serde_xml_rs::from_str("<response><cell_id></cell_id></response>").unwrap();
+        let info = CellInfo::new(&signal, None, Vec::new());
+        assert_eq!(info.cell_id, None);
+        assert_eq!(info.enb_id, None);
+        assert!(info.neighbors.is_empty());
+    }
+
+    #[test]
+    fn test_device_time_epoch_seconds() {
+        let xml = r#"<response><systemTime>1718000000</systemTime><timezone>UTC+01:00</timezone>
+<autoTime>1</autoTime><ntpServer>pool.ntp.org</ntpServer></response>"#;
+        let time: DeviceTime = serde_xml_rs::from_str(xml).unwrap();
+
+        assert!(time.is_auto_time());
+        assert_eq!(time.ntp_server.as_deref(), Some("pool.ntp.org"));
+        assert_eq!(
+            time.datetime().unwrap().to_string(),
+            "2024-06-10 06:13:20"
+        );
+    }
+
+    #[test]
+    fn test_device_time_formatted_string() {
+        let xml = r#"<response><systemTime>2025-06-09 17:08:58</systemTime><timezone>UTC+02:00</timezone>
+<autoTime>0</autoTime><ntpServer></ntpServer></response>"#;
+        let time: DeviceTime = serde_xml_rs::from_str(xml).unwrap();
+
+        assert!(!time.is_auto_time());
+        assert_eq!(
+            time.datetime().unwrap().to_string(),
+            "2025-06-09 17:08:58"
+        );
+    }
+
+    #[test]
+    fn test_device_time_request_serialization() {
+        let time = NaiveDateTime::parse_from_str("2025-06-09 17:08:58", DEVICE_TIME_FORMAT).unwrap();
+        let xml = serde_xml_rs::to_string(&DeviceTimeRequest::manual(time)).unwrap();
+        assert!(xml.contains("<systemTime>2025-06-09 17:08:58</systemTime>"));
+        assert!(xml.contains("<autoTime>0</autoTime>"));
+        assert!(!xml.contains("ntpServer"));
+
+        let request = DeviceTimeRequest::ntp("pool.ntp.org").with_timezone("UTC+01:00");
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+        assert!(xml.contains("<autoTime>1</autoTime>"));
+        assert!(xml.contains("<ntpServer>pool.ntp.org</ntpServer>"));
+        assert!(xml.contains("<timezone>UTC+01:00</timezone>"));
+        assert!(!xml.contains("systemTime"));
+    }
+
+    #[test]
+    fn test_antenna_settings_and_request() {
+        let xml = "<response><antennatype>2</antennatype><antenna_status>1</antenna_status></response>";
+        let settings: AntennaSettings = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(settings.antenna_type, Some(AntennaType::External));
+        assert_eq!(settings.antenna_status.as_deref(), Some("1"));
+
+        let xml = serde_xml_rs::to_string(&AntennaTypeRequest::new(AntennaType::Internal)).unwrap();
+        assert!(xml.contains("<request><antennatype>1</antennatype></request>"));
+    }
+
+    #[test]
+    fn test_fly_mode_request_serialization() {
+        let xml = serde_xml_rs::to_string(&FlyModeRequest::new(true)).unwrap();
+        assert!(xml.contains("<request><flymode>1</flymode></request>"));
+
+        let xml = serde_xml_rs::to_string(&FlyModeRequest::new(false)).unwrap();
+        assert!(xml.contains("<request><flymode>0</flymode></request>"));
+    }
+
+    #[test]
+    fn test_sim_info_from_partial_sources() {
+        let info = device_information_xml()
+            .field("Imsi", "260011234567890")
+            .field("Iccid", "8948011234567890123")
+            .field("Msisdn", "")
+            .parse();
+        let pin: PinStatus = serde_xml_rs::from_str(
+            "<response><SimState>257</SimState><SimPinTimes>3</SimPinTimes>\
+<SimPukTimes>10</SimPukTimes></response>",
+        )
+        .unwrap();
+
+        let sim = SimInfo::from_sources(Some(&info), Some(&pin), None);
+
+        assert_eq!(sim.imsi.as_deref(), Some("260011234567890"));
+        assert_eq!(sim.iccid.as_deref(), Some("8948011234567890123"));
+        assert_eq!(sim.msisdn, None);
+        assert_eq!(sim.sim_slot, None);
+        assert!(sim.is_sim_present());
+
+        let converged = ConvergedStatus {
+            sim_state: Some("255".to_string()),
+            sim_slot: Some("1".to_string()),
+            ..Default::default()
+        };
+        let sim = SimInfo::from_sources(None, None, Some(&converged));
+        assert_eq!(sim.sim_slot, Some(1));
+        assert!(!sim.is_sim_present());
+    }
+
+    fn device_info(imei: &str, imsi: Option<&str>) -> DeviceInformation {
+        let xml = device_information_xml().field("Imei", imei);
+        match imsi {
+            Some(imsi) => xml.field("Imsi", imsi).parse(),
+            None => xml.parse(),
+        }
+    }
+
+    #[test]
+    fn test_imei_valid() {
+        assert!(device_info("490154203237518", None).imei_valid());
+        assert!(device_info("356938035643809", None).imei_valid());
+    }
+
+    #[test]
+    fn test_imei_invalid() {
+        assert!(!device_info("490154203237519", None).imei_valid());
+        assert!(!device_info("49015420323751", None).imei_valid());
+        assert!(!device_info("49015420323751A", None).imei_valid());
+        assert!(!device_info("", None).imei_valid());
+    }
+
+    #[test]
+    fn test_imsi_mcc_mnc() {
+        // Orange Polska
+        assert_eq!(
+            device_info("490154203237518", Some("260031234567890")).imsi_mcc_mnc(),
+            Some(("260".to_string(), "03".to_string()))
+        );
+        // Vodafone UK
+        assert_eq!(
+            device_info("490154203237518", Some("234151234567890")).imsi_mcc_mnc(),
+            Some(("234".to_string(), "15".to_string()))
+        );
+        // T-Mobile US
+        assert_eq!(
+            device_info("490154203237518", Some("310260123456789")).imsi_mcc_mnc(),
+            Some(("310".to_string(), "260".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_imsi_mcc_mnc_missing() {
+        assert_eq!(device_info("490154203237518", None).imsi_mcc_mnc(), None);
+        assert_eq!(device_info("490154203237518", Some("")).imsi_mcc_mnc(), None);
+        assert_eq!(device_info("490154203237518", Some("2600")).imsi_mcc_mnc(), None);
+    }
+
+    #[test]
+    fn test_supported_modes_names() {
+        let modes = SupportedModes::parse("LTE|WCDMA|GSM");
+        assert!(modes.contains(RadioTechnology::Lte));
+        assert!(modes.contains(RadioTechnology::Wcdma));
+        assert!(modes.contains(RadioTechnology::Gsm));
+        assert!(!modes.contains(RadioTechnology::Nr));
+        assert_eq!(
+            modes.iter().collect::<Vec<_>>(),
+            vec![RadioTechnology::Gsm, RadioTechnology::Wcdma, RadioTechnology::Lte]
+        );
+    }
+
+    #[test]
+    fn test_supported_modes_codes() {
+        let modes = SupportedModes::parse("080302");
+        assert_eq!(
+            modes.iter().collect::<Vec<_>>(),
+            vec![RadioTechnology::Wcdma, RadioTechnology::Lte, RadioTechnology::Nr]
+        );
+        assert!(SupportedModes::parse("").is_empty());
+        assert!(SupportedModes::parse("FOO|BAR").is_empty());
+    }
+
+    fn device_with_modes(support_mode: &str, work_mode: &str) -> DeviceInformation {
+        device_information_xml()
+            .field("supportmode", support_mode)
+            .field("workmode", work_mode)
+            .parse()
+    }
+
+    #[test]
+    fn test_work_mode_network() {
+        let info = device_with_modes("LTE|WCDMA|GSM", "19");
+        assert_eq!(info.work_mode_network(), Some(NetworkType::Lte));
+        assert!(info.supported_modes().contains(RadioTechnology::Lte));
+
+        assert_eq!(device_with_modes("", "7").work_mode_network(), Some(NetworkType::Hspa));
+        assert_eq!(device_with_modes("", "LTE").work_mode_network(), Some(NetworkType::Lte));
+        assert_eq!(device_with_modes("", "GSM").work_mode_network(), None);
+        assert_eq!(device_info("490154203237518", None).work_mode_network(), None);
+        assert!(device_info("490154203237518", None).supported_modes().is_empty());
+    }
+
+    #[test]
+    fn test_update_status_available() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<Status>1</Status>
+<CurrentVersion>21.333.64.00.1217</CurrentVersion>
+<LatestVersion>22.328.62.00.1217</LatestVersion>
+<Progress>0</Progress>
+</response>"#;
+
+        let status: UpdateStatus = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(status.state(), Some(UpdateState::Available));
+        assert!(status.is_update_available());
+        assert_eq!(status.current_version.as_deref(), Some("21.333.64.00.1217"));
+        assert_eq!(status.latest_version.as_deref(), Some("22.328.62.00.1217"));
+        assert_eq!(status.progress_percent(), Some(0));
+    }
+
+    #[test]
+    fn test_update_status_unknown_state() {
+        let status: UpdateStatus =
+            serde_xml_rs::from_str("<response><Status>9</Status><Progress>45%</Progress></response>")
+                .unwrap();
+        assert_eq!(status.state(), None);
+        assert_eq!(status.progress_percent(), Some(45));
+        assert!(!status.is_update_available());
     }
 }
@@ -1,7 +1,10 @@
 //! DHCP configuration models
 
 use serde::{Deserialize, Serialize};
-use super::{DhcpStatus, DnsStatus};
+use std::net::{IpAddr, Ipv4Addr};
+use std::time::Duration;
+use super::wlan::WlanHost;
+use super::{parse_ip_addr, DhcpStatus, DnsStatus};
 
 /// DHCP settings response
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,6 +44,57 @@ pub struct DhcpSettings {
     /// DHCP lease time in seconds
     #[serde(rename = "DhcpLeaseTime")]
     pub dhcp_lease_time: String,
+
+    /// IPv6 DNS status, on firmware with IPv6 support
+    #[serde(rename = "Ipv6DnsStatus", default)]
+    pub ipv6_dns_status: Option<DnsStatus>,
+
+    /// Primary IPv6 DNS server, on firmware with IPv6 support
+    #[serde(rename = "Ipv6PrimaryDns", default)]
+    pub ipv6_primary_dns: Option<String>,
+
+    /// Secondary IPv6 DNS server, on firmware with IPv6 support
+    #[serde(rename = "Ipv6SecondaryDns", default)]
+    pub ipv6_secondary_dns: Option<String>,
+
+    /// DHCPv6 server status, on firmware with IPv6 support
+    #[serde(rename = "Ipv6DhcpStatus", default)]
+    pub ipv6_dhcp_status: Option<DhcpStatus>,
+}
+
+/// DHCPv6 settings from `/api/dhcp/dhcpv6-settings`.
+///
+/// All fields are optional since firmware reports only the ones it supports.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct Dhcpv6Settings {
+    /// IPv6 DNS status, on firmware with IPv6 support
+    #[serde(rename = "Ipv6DnsStatus", default)]
+    pub ipv6_dns_status: Option<DnsStatus>,
+
+    /// Primary IPv6 DNS server, on firmware with IPv6 support
+    #[serde(rename = "Ipv6PrimaryDns", default)]
+    pub ipv6_primary_dns: Option<String>,
+
+    /// Secondary IPv6 DNS server, on firmware with IPv6 support
+    #[serde(rename = "Ipv6SecondaryDns", default)]
+    pub ipv6_secondary_dns: Option<String>,
+
+    /// DHCPv6 server status, on firmware with IPv6 support
+    #[serde(rename = "Ipv6DhcpStatus", default)]
+    pub ipv6_dhcp_status: Option<DhcpStatus>,
+}
+
+impl Dhcpv6Settings {
+    /// Primary IPv6 DNS server, or `None` if missing, blank or malformed
+    pub fn ipv6_primary_dns_addr(&self) -> Option<IpAddr> {
+        self.ipv6_primary_dns.as_deref().and_then(parse_ip_addr)
+    }
+
+    /// Secondary IPv6 DNS server, or `None` if missing, blank or malformed
+    pub fn ipv6_secondary_dns_addr(&self) -> Option<IpAddr> {
+        self.ipv6_secondary_dns.as_deref().and_then(parse_ip_addr)
+    }
 }
 
 /// DHCP settings request
@@ -111,9 +165,176 @@ impl DhcpSettingsRequest {
     }
 }
 
+impl DhcpSettings {
+    /// Gateway address, or `None` if blank or malformed
+    pub fn dhcp_ip_addr(&self) -> Option<IpAddr> {
+        parse_ip_addr(&self.dhcp_ip_address)
+    }
+
+    /// Primary DNS server, or `None` if blank or malformed
+    pub fn primary_dns_addr(&self) -> Option<IpAddr> {
+        parse_ip_addr(&self.primary_dns)
+    }
+
+    /// Secondary DNS server, or `None` if blank or malformed
+    pub fn secondary_dns_addr(&self) -> Option<IpAddr> {
+        parse_ip_addr(&self.secondary_dns)
+    }
+
+    /// Primary IPv6 DNS server, or `None` if missing, blank or malformed
+    pub fn ipv6_primary_dns_addr(&self) -> Option<IpAddr> {
+        self.ipv6_primary_dns.as_deref().and_then(parse_ip_addr)
+    }
+
+    /// Secondary IPv6 DNS server, or `None` if missing, blank or malformed
+    pub fn ipv6_secondary_dns_addr(&self) -> Option<IpAddr> {
+        self.ipv6_secondary_dns.as_deref().and_then(parse_ip_addr)
+    }
+
+    /// Check if an address falls within the DHCP pool
+    pub fn pool_contains(&self, ip: Ipv4Addr) -> bool {
+        let start = self.dhcp_start_ip_address.trim().parse::<Ipv4Addr>();
+        let end = self.dhcp_end_ip_address.trim().parse::<Ipv4Addr>();
+
+        match (start, end) {
+            (Ok(start), Ok(end)) => (start..=end).contains(&ip),
+            _ => false,
+        }
+    }
+
+    /// Check if an address is in the LAN subnet of the gateway
+    pub fn subnet_contains(&self, ip: Ipv4Addr) -> bool {
+        let gateway = self.dhcp_ip_address.trim().parse::<Ipv4Addr>();
+        let netmask = self.dhcp_lan_netmask.trim().parse::<Ipv4Addr>();
+
+        match (gateway, netmask) {
+            (Ok(gateway), Ok(netmask)) => {
+                let mask = u32::from(netmask);
+                u32::from(gateway) & mask == u32::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Static DHCP reservation from `/api/dhcp/static-addr-info`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename = "static_addr_info")]
+pub struct StaticHost {
+    #[serde(rename = "MacAddress")]
+    pub mac_address: String,
+
+    #[serde(rename = "IpAddress")]
+    pub ip_address: String,
+
+    #[serde(rename = "HostName", default)]
+    pub host_name: String,
+
+    /// Reservation state (1=enabled, 0=disabled)
+    #[serde(rename = "Enable")]
+    pub enable: String,
+}
+
+impl StaticHost {
+    /// Create a new enabled reservation
+    pub fn new(mac_address: &str, ip_address: &str, host_name: &str) -> Self {
+        Self {
+            mac_address: mac_address.to_string(),
+            ip_address: ip_address.to_string(),
+            host_name: host_name.to_string(),
+            enable: "1".to_string(),
+        }
+    }
+
+    /// Check if the reservation is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.enable.trim() == "1"
+    }
+}
+
+/// Static reservation list response from `/api/dhcp/static-addr-info`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct StaticHostList {
+    #[serde(rename = "static_addr_info", default)]
+    pub hosts: Vec<StaticHost>,
+}
+
+/// Static reservation list request for `/api/dhcp/static-addr-info`
+///
+/// The device replaces the whole table, so the request must carry every
+/// reservation that should remain. serde-xml-rs cannot serialize repeated
+/// struct elements, so this request is serialized with `quick_xml::se`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct StaticHostListRequest {
+    #[serde(rename = "static_addr_info", default)]
+    pub hosts: Vec<StaticHost>,
+}
+
+impl StaticHostListRequest {
+    /// Create a request replacing the table with `hosts`
+    pub fn new(hosts: &[StaticHost]) -> Self {
+        Self {
+            hosts: hosts.to_vec(),
+        }
+    }
+}
+
+/// Active DHCP lease, built from the connected hosts that hold an address
+/// in the DHCP pool
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DhcpLease {
+    pub ip_address: Ipv4Addr,
+    pub mac_address: String,
+    pub host_name: String,
+    /// Seconds left on the lease, `None` if the device does not report it
+    pub remaining_secs: Option<u64>,
+}
+
+impl DhcpLease {
+    /// Time left on the lease, if known
+    pub fn remaining(&self) -> Option<Duration> {
+        self.remaining_secs.map(Duration::from_secs)
+    }
+
+    /// Leases for the `hosts` whose address falls within the DHCP pool of
+    /// `settings`, one per MAC address, ordered by IP address
+    pub fn from_hosts(settings: &DhcpSettings, hosts: &[WlanHost]) -> Vec<DhcpLease> {
+        let mut leases: Vec<DhcpLease> = Vec::new();
+
+        for host in hosts {
+            let Ok(ip_address) = host.ip_address.trim().parse::<Ipv4Addr>() else {
+                continue;
+            };
+            if !settings.pool_contains(ip_address)
+                || leases
+                    .iter()
+                    .any(|lease| lease.mac_address.eq_ignore_ascii_case(&host.mac_address))
+            {
+                continue;
+            }
+
+            leases.push(DhcpLease {
+                ip_address,
+                mac_address: host.mac_address.clone(),
+                host_name: host.host_name.clone(),
+                remaining_secs: host
+                    .lease_time
+                    .as_deref()
+                    .and_then(|secs| secs.trim().parse().ok()),
+            });
+        }
+
+        leases.sort_by_key(|lease| lease.ip_address);
+        leases
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::{dhcp_settings, dhcp_settings_xml};
 
     #[test]
     fn test_dhcp_settings_request_creation() {
@@ -152,4 +373,138 @@ mod tests {
         assert!(xml.contains("<DhcpIPAddress>192.168.8.1</DhcpIPAddress>"));
         assert!(xml.contains("<DhcpStatus>1</DhcpStatus>"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_static_host_list_serialization() {
+        let request = StaticHostListRequest::new(&[
+            StaticHost::new("AA:BB:CC:DD:EE:01", "192.168.8.10", "nas"),
+            StaticHost::new("AA:BB:CC:DD:EE:02", "192.168.8.11", "printer"),
+        ]);
+
+        let xml = quick_xml::se::to_string(&request).unwrap();
+        assert!(xml.starts_with("<request>"));
+        assert_eq!(xml.matches("<static_addr_info>").count(), 2);
+        assert!(xml.contains("<MacAddress>AA:BB:CC:DD:EE:01</MacAddress>"));
+        assert!(xml.contains("<IpAddress>192.168.8.11</IpAddress>"));
+        assert!(xml.contains("<HostName>printer</HostName>"));
+        assert!(xml.contains("<Enable>1</Enable>"));
+    }
+
+    #[test]
+    fn test_static_host_list_parsing() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<static_addr_info>
+<MacAddress>AA:BB:CC:DD:EE:01</MacAddress>
+<IpAddress>192.168.8.10</IpAddress>
+<HostName>nas</HostName>
+<Enable>1</Enable>
+</static_addr_info>
+<static_addr_info>
+<MacAddress>AA:BB:CC:DD:EE:02</MacAddress>
+<IpAddress>192.168.8.11</IpAddress>
+<HostName></HostName>
+<Enable>0</Enable>
+</static_addr_info>
+</response>"#;
+
+        let list: StaticHostList = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(list.hosts.len(), 2);
+        assert_eq!(list.hosts[0], StaticHost::new("AA:BB:CC:DD:EE:01", "192.168.8.10", "nas"));
+        assert!(!list.hosts[1].is_enabled());
+
+        let empty: StaticHostList = serde_xml_rs::from_str("<response></response>").unwrap();
+        assert!(empty.hosts.is_empty());
+    }
+
+    #[test]
+    fn test_dhcp_settings_addr_accessors() {
+        let xml = dhcp_settings_xml()
+            .replace("<SecondaryDns>192.168.8.1</SecondaryDns>", "<SecondaryDns></SecondaryDns>")
+            .replace("<PrimaryDns>192.168.8.1</PrimaryDns>", "<PrimaryDns>2001:4860:4860::8888</PrimaryDns>");
+
+        let settings: DhcpSettings = serde_xml_rs::from_str(&xml).unwrap();
+        assert_eq!(
+            settings.dhcp_ip_addr(),
+            Some(IpAddr::V4(Ipv4Addr::new(192, 168, 8, 1)))
+        );
+        assert_eq!(
+            settings.primary_dns_addr(),
+            Some("2001:4860:4860::8888".parse().unwrap())
+        );
+        assert_eq!(settings.secondary_dns_addr(), None);
+        assert!(settings.ipv6_dns_status.is_none());
+        assert!(settings.ipv6_dhcp_status.is_none());
+        assert_eq!(settings.ipv6_primary_dns_addr(), None);
+    }
+
+    #[test]
+    fn test_dhcp_settings_with_ipv6_block() {
+        let xml = dhcp_settings_xml().replace(
+            "</response>",
+            "<Ipv6DnsStatus>1</Ipv6DnsStatus><Ipv6PrimaryDns>2001:4860:4860::8888</Ipv6PrimaryDns>\
+<Ipv6SecondaryDns></Ipv6SecondaryDns><Ipv6DhcpStatus>0</Ipv6DhcpStatus></response>",
+        );
+
+        let settings: DhcpSettings = serde_xml_rs::from_str(&xml).unwrap();
+        assert_eq!(settings.ipv6_dns_status, Some(DnsStatus::Enabled));
+        assert_eq!(settings.ipv6_dhcp_status, Some(DhcpStatus::Disabled));
+        assert_eq!(
+            settings.ipv6_primary_dns_addr(),
+            Some("2001:4860:4860::8888".parse().unwrap())
+        );
+        assert_eq!(settings.ipv6_secondary_dns_addr(), None);
+    }
+
+    #[test]
+    fn test_dhcpv6_settings_parsing() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<Ipv6DnsStatus>0</Ipv6DnsStatus>
+<Ipv6PrimaryDns>2a00:1450:4001::8844</Ipv6PrimaryDns>
+<Ipv6SecondaryDns>2001:4860:4860::8888</Ipv6SecondaryDns>
+<Ipv6DhcpStatus>1</Ipv6DhcpStatus>
+</response>"#;
+
+        let settings: Dhcpv6Settings = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(settings.ipv6_dns_status, Some(DnsStatus::Disabled));
+        assert_eq!(settings.ipv6_dhcp_status, Some(DhcpStatus::Enabled));
+        assert_eq!(
+            settings.ipv6_secondary_dns_addr(),
+            Some("2001:4860:4860::8888".parse().unwrap())
+        );
+
+        let empty: Dhcpv6Settings = serde_xml_rs::from_str("<response></response>").unwrap();
+        assert!(empty.ipv6_dhcp_status.is_none());
+        assert_eq!(empty.ipv6_primary_dns_addr(), None);
+    }
+
+    #[test]
+    fn test_leases_from_hosts() {
+        let settings = dhcp_settings();
+
+        let host = |mac: &str, ip: &str, name: &str, lease: Option<&str>| WlanHost {
+            mac_address: mac.to_string(),
+            ip_address: ip.to_string(),
+            host_name: name.to_string(),
+            associated_time: "60".to_string(),
+            associated_ssid: None,
+            lease_time: lease.map(str::to_string),
+        };
+        let hosts = [
+            host("AA:BB:CC:DD:EE:02", "192.168.8.150", "phone", Some("3600")),
+            host("AA:BB:CC:DD:EE:01", "192.168.8.101", "laptop", None),
+            host("aa:bb:cc:dd:ee:01", "192.168.8.102", "laptop", None),
+            host("AA:BB:CC:DD:EE:03", "192.168.8.20", "static", None),
+            host("AA:BB:CC:DD:EE:04", "", "", None),
+        ];
+
+        let leases = DhcpLease::from_hosts(&settings, &hosts);
+        assert_eq!(leases.len(), 2);
+        assert_eq!(leases[0].ip_address, Ipv4Addr::new(192, 168, 8, 101));
+        assert_eq!(leases[0].host_name, "laptop");
+        assert_eq!(leases[0].remaining(), None);
+        assert_eq!(leases[1].mac_address, "AA:BB:CC:DD:EE:02");
+        assert_eq!(leases[1].remaining(), Some(Duration::from_secs(3600)));
+    }
+}
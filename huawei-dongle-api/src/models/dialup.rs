@@ -0,0 +1,291 @@
+//! Dial-up (mobile data connection) models
+
+use serde::{Deserialize, Serialize};
+
+/// Mobile data switch state from `/api/dialup/mobile-dataswitch`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct MobileDataSwitch {
+    /// Data switch state (1=on, 0=off)
+    #[serde(rename = "dataswitch")]
+    pub dataswitch: String,
+}
+
+/// Mobile data switch request for `/api/dialup/mobile-dataswitch`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct MobileDataSwitchRequest {
+    /// Data switch state (1=on, 0=off)
+    #[serde(rename = "dataswitch")]
+    pub dataswitch: String,
+}
+
+/// Connection (APN) profile from `/api/dialup/profiles`
+///
+/// The device never returns stored passwords, so `password` is empty on read.
+/// Callers modifying an existing profile must supply the password again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "Profile")]
+pub struct Profile {
+    #[serde(rename = "Index")]
+    pub index: String,
+
+    #[serde(rename = "IsValid")]
+    pub is_valid: String,
+
+    #[serde(rename = "Name")]
+    pub name: String,
+
+    #[serde(rename = "ApnIsStatic")]
+    pub apn_is_static: String,
+
+    #[serde(rename = "ApnName")]
+    pub apn_name: String,
+
+    #[serde(rename = "DialupNum", default)]
+    pub dialup_num: Option<String>,
+
+    #[serde(rename = "Username", default)]
+    pub username: Option<String>,
+
+    #[serde(rename = "Password", default)]
+    pub password: Option<String>,
+
+    /// Authentication mode (0=auto, 1=PAP, 2=CHAP)
+    #[serde(rename = "AuthMode")]
+    pub auth_mode: String,
+
+    #[serde(rename = "ReadOnly", default)]
+    pub read_only: Option<String>,
+
+    /// IP type (0=IPv4, 1=IPv6, 2=IPv4v6)
+    #[serde(rename = "iptype", default)]
+    pub ip_type: Option<String>,
+}
+
+/// Profiles container from the profile list response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Profiles {
+    #[serde(rename = "$value", default)]
+    pub profiles: Vec<Profile>,
+}
+
+/// Profile list response from `/api/dialup/profiles`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct ProfileList {
+    /// Index of the default profile
+    #[serde(rename = "CurrentProfile")]
+    pub current_profile: String,
+
+    #[serde(rename = "Profiles")]
+    pub profiles: Profiles,
+}
+
+/// Profile management request for `/api/dialup/profiles`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct ProfileRequest {
+    /// Index of the profile to delete (0 for none)
+    #[serde(rename = "Delete")]
+    pub delete: String,
+
+    /// Index of the default profile
+    #[serde(rename = "SetDefault")]
+    pub set_default: String,
+
+    /// Modify operation (0=none, 1=create, 2=edit)
+    #[serde(rename = "Modify")]
+    pub modify: String,
+
+    #[serde(rename = "Profile", skip_serializing_if = "Option::is_none")]
+    pub profile: Option<Profile>,
+}
+
+impl MobileDataSwitch {
+    /// Check if mobile data is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.dataswitch.trim() == "1"
+    }
+}
+
+impl MobileDataSwitchRequest {
+    /// Create a new data switch request
+    pub fn new(on: bool) -> Self {
+        Self {
+            dataswitch: if on { "1" } else { "0" }.to_string(),
+        }
+    }
+}
+
+impl Profile {
+    /// Create a new profile for the given APN
+    pub fn new(name: &str, apn_name: &str) -> Self {
+        Self {
+            index: String::new(),
+            is_valid: "1".to_string(),
+            name: name.to_string(),
+            apn_is_static: "1".to_string(),
+            apn_name: apn_name.to_string(),
+            dialup_num: Some("*99#".to_string()),
+            username: Some(String::new()),
+            password: Some(String::new()),
+            auth_mode: "0".to_string(),
+            read_only: Some("0".to_string()),
+            ip_type: Some("2".to_string()),
+        }
+    }
+
+    /// Set the APN credentials
+    pub fn with_credentials(mut self, username: &str, password: &str) -> Self {
+        self.username = Some(username.to_string());
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Check if the profile is valid
+    pub fn is_valid(&self) -> bool {
+        self.is_valid.trim() == "1"
+    }
+
+    /// Check if the profile is read-only (preconfigured by the operator)
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.as_deref().is_some_and(|r| r.trim() != "0")
+    }
+}
+
+impl ProfileList {
+    /// Get the index of the default profile
+    pub fn default_index(&self) -> Option<u32> {
+        self.current_profile.trim().parse().ok()
+    }
+
+    /// Get the default profile
+    pub fn default_profile(&self) -> Option<&Profile> {
+        self.profiles
+            .profiles
+            .iter()
+            .find(|p| p.index.trim() == self.current_profile.trim())
+    }
+}
+
+impl ProfileRequest {
+    /// Create a request that changes the default profile
+    pub fn set_default(index: u32) -> Self {
+        Self {
+            delete: "0".to_string(),
+            set_default: index.to_string(),
+            modify: "0".to_string(),
+            profile: None,
+        }
+    }
+
+    /// Create a request that adds a new profile, keeping `default_index` as default
+    pub fn create(profile: Profile, default_index: u32) -> Self {
+        Self {
+            delete: "0".to_string(),
+            set_default: default_index.to_string(),
+            modify: "1".to_string(),
+            profile: Some(profile),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mobile_data_switch_parsing() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<dataswitch>1</dataswitch>
+</response>"#;
+
+        let switch: MobileDataSwitch = serde_xml_rs::from_str(xml).unwrap();
+        assert!(switch.is_enabled());
+    }
+
+    #[test]
+    fn test_mobile_data_switch_request_serialization() {
+        let xml = serde_xml_rs::to_string(&MobileDataSwitchRequest::new(true)).unwrap();
+        assert!(xml.contains("<request><dataswitch>1</dataswitch></request>"));
+
+        let xml = serde_xml_rs::to_string(&MobileDataSwitchRequest::new(false)).unwrap();
+        assert!(xml.contains("<request><dataswitch>0</dataswitch></request>"));
+    }
+
+    #[test]
+    fn test_profile_list_parsing() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<CurrentProfile>2</CurrentProfile>
+<Profiles>
+<Profile>
+<Index>1</Index>
+<IsValid>1</IsValid>
+<Name>Orange</Name>
+<ApnIsStatic>1</ApnIsStatic>
+<ApnName>internet</ApnName>
+<DialupNum>*99#</DialupNum>
+<Username>internet</Username>
+<Password></Password>
+<AuthMode>0</AuthMode>
+<IpIsStatic></IpIsStatic>
+<IpAddress></IpAddress>
+<DnsIsStatic></DnsIsStatic>
+<PrimaryDns></PrimaryDns>
+<SecondaryDns></SecondaryDns>
+<ReadOnly>2</ReadOnly>
+<iptype>2</iptype>
+</Profile>
+<Profile>
+<Index>2</Index>
+<IsValid>1</IsValid>
+<Name>Custom</Name>
+<ApnIsStatic>1</ApnIsStatic>
+<ApnName>custom.apn</ApnName>
+<DialupNum>*99#</DialupNum>
+<Username>user</Username>
+<Password></Password>
+<AuthMode>1</AuthMode>
+<IpIsStatic></IpIsStatic>
+<IpAddress></IpAddress>
+<DnsIsStatic></DnsIsStatic>
+<PrimaryDns></PrimaryDns>
+<SecondaryDns></SecondaryDns>
+<ReadOnly>0</ReadOnly>
+<iptype>0</iptype>
+</Profile>
+</Profiles>
+</response>"#;
+
+        let list: ProfileList = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(list.profiles.profiles.len(), 2);
+        assert_eq!(list.default_index(), Some(2));
+
+        let default = list.default_profile().unwrap();
+        assert_eq!(default.name, "Custom");
+        assert_eq!(default.apn_name, "custom.apn");
+        assert!(!default.is_read_only());
+        assert_eq!(default.password.as_deref().unwrap_or_default(), "");
+
+        assert!(list.profiles.profiles[0].is_read_only());
+        assert!(list.profiles.profiles[0].is_valid());
+    }
+
+    #[test]
+    fn test_profile_request_serialization() {
+        let xml = serde_xml_rs::to_string(&ProfileRequest::set_default(3)).unwrap();
+        assert!(xml.contains("<Delete>0</Delete><SetDefault>3</SetDefault><Modify>0</Modify>"));
+        assert!(!xml.contains("<Profile>"));
+
+        let profile = Profile::new("Work", "work.apn").with_credentials("user", "secret");
+        let xml = serde_xml_rs::to_string(&ProfileRequest::create(profile, 1)).unwrap();
+        assert!(xml.contains("<SetDefault>1</SetDefault><Modify>1</Modify><Profile>"));
+        assert!(xml.contains("<Name>Work</Name>"));
+        assert!(xml.contains("<ApnName>work.apn</ApnName>"));
+        assert!(xml.contains("<Username>user</Username>"));
+        assert!(xml.contains("<Password>secret</Password>"));
+    }
+}
@@ -122,10 +122,76 @@ impl NetworkType {
     pub fn is_3g(&self) -> bool {
         matches!(self, NetworkType::Hspa)
     }
+
+    /// Parse a numeric network type code such as `19`
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.trim() {
+            "7" => Some(NetworkType::Hspa),
+            "19" => Some(NetworkType::Lte),
+            "41" => Some(NetworkType::LteCarrierAggregation),
+            "101" => Some(NetworkType::FiveGNsa),
+            "102" => Some(NetworkType::FiveGSa),
+            _ => None,
+        }
+    }
+}
+
+/// Radio access technology a device supports, as listed in the
+/// `supportmode` field of `/api/device/information`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub enum RadioTechnology {
+    Gsm,
+    Wcdma,
+    TdScdma,
+    Cdma,
+    Lte,
+    Nr,
+}
+
+impl fmt::Display for RadioTechnology {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            RadioTechnology::Gsm => "GSM",
+            RadioTechnology::Wcdma => "WCDMA",
+            RadioTechnology::TdScdma => "TD-SCDMA",
+            RadioTechnology::Cdma => "CDMA",
+            RadioTechnology::Lte => "LTE",
+            RadioTechnology::Nr => "NR",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl RadioTechnology {
+    /// Parse a technology name as used by the firmware, e.g. `WCDMA` or `NR`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_uppercase().as_str() {
+            "GSM" | "EDGE" | "GPRS" => Some(RadioTechnology::Gsm),
+            "WCDMA" | "UMTS" | "HSPA" => Some(RadioTechnology::Wcdma),
+            "TDSCDMA" | "TD-SCDMA" => Some(RadioTechnology::TdScdma),
+            "CDMA" | "EVDO" => Some(RadioTechnology::Cdma),
+            "LTE" | "TDLTE" | "TD-LTE" | "FDDLTE" => Some(RadioTechnology::Lte),
+            "NR" | "5G" => Some(RadioTechnology::Nr),
+            _ => None,
+        }
+    }
+
+    /// Parse a two-digit network mode code as used by `/api/net/net-mode`
+    pub fn from_mode_code(code: &str) -> Option<Self> {
+        match code {
+            "01" => Some(RadioTechnology::Gsm),
+            "02" => Some(RadioTechnology::Wcdma),
+            "03" => Some(RadioTechnology::Lte),
+            "04" => Some(RadioTechnology::Cdma),
+            "05" => Some(RadioTechnology::TdScdma),
+            "08" => Some(RadioTechnology::Nr),
+            _ => None,
+        }
+    }
 }
 
 /// Network mode configuration values from `/api/net/net-mode`
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 pub enum NetworkModeType {
     #[serde(rename = "00")]
     Auto,
@@ -143,6 +209,24 @@ pub enum NetworkModeType {
     FourGPreferredThreeGFallback,
 }
 
+impl Serialize for NetworkModeType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let value = match self {
+            NetworkModeType::Auto => "00",
+            NetworkModeType::TwoGOnly => "01",
+            NetworkModeType::ThreeGOnly => "02",
+            NetworkModeType::FourGOnly => "03",
+            NetworkModeType::ThreeGPreferredTwoGFallback => "0201",
+            NetworkModeType::FourGPreferredTwoGFallback => "0301",
+            NetworkModeType::FourGPreferredThreeGFallback => "0302",
+        };
+        serializer.serialize_str(value)
+    }
+}
+
 impl fmt::Display for NetworkModeType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let text = match self {
@@ -158,6 +242,205 @@ impl fmt::Display for NetworkModeType {
     }
 }
 
+/// Operator selection mode for `/api/net/register`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum PlmnSelectionMode {
+    #[serde(rename = "0")]
+    Auto,
+    #[serde(rename = "1")]
+    Manual,
+    #[serde(rename = "2")]
+    ManualThenAuto,
+}
+
+impl Serialize for PlmnSelectionMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let value = match self {
+            PlmnSelectionMode::Auto => "0",
+            PlmnSelectionMode::Manual => "1",
+            PlmnSelectionMode::ManualThenAuto => "2",
+        };
+        serializer.serialize_str(value)
+    }
+}
+
+impl fmt::Display for PlmnSelectionMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            PlmnSelectionMode::Auto => "Automatic",
+            PlmnSelectionMode::Manual => "Manual",
+            PlmnSelectionMode::ManualThenAuto => "Manual, automatic fallback",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// Transport protocol of a port forwarding rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Protocol {
+    #[serde(rename = "6")]
+    Tcp,
+    #[serde(rename = "17")]
+    Udp,
+    /// TCP and UDP
+    #[serde(rename = "0")]
+    Both,
+}
+
+impl fmt::Display for Protocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Protocol::Tcp => "TCP",
+            Protocol::Udp => "UDP",
+            Protocol::Both => "TCP/UDP",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// WiFi MAC address filter mode for `/api/wlan/mac-filter`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MacFilterMode {
+    #[serde(rename = "0")]
+    Disabled,
+    /// Only the listed MAC addresses may connect
+    #[serde(rename = "1")]
+    Allow,
+    /// The listed MAC addresses are blocked
+    #[serde(rename = "2")]
+    Deny,
+}
+
+impl fmt::Display for MacFilterMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            MacFilterMode::Disabled => "Disabled",
+            MacFilterMode::Allow => "Allow list",
+            MacFilterMode::Deny => "Deny list",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// LTE frequency bands, numbered as in 3GPP TS 36.101
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LteBand {
+    B1 = 1,
+    B2 = 2,
+    B3 = 3,
+    B4 = 4,
+    B5 = 5,
+    B7 = 7,
+    B8 = 8,
+    B12 = 12,
+    B13 = 13,
+    B17 = 17,
+    B18 = 18,
+    B19 = 19,
+    B20 = 20,
+    B25 = 25,
+    B26 = 26,
+    B28 = 28,
+    B32 = 32,
+    B34 = 34,
+    B38 = 38,
+    B39 = 39,
+    B40 = 40,
+    B41 = 41,
+    B42 = 42,
+    B43 = 43,
+    B66 = 66,
+    B71 = 71,
+}
+
+impl LteBand {
+    /// All bands known to this library, in ascending order
+    pub const ALL: [LteBand; 26] = [
+        LteBand::B1, LteBand::B2, LteBand::B3, LteBand::B4, LteBand::B5, LteBand::B7, LteBand::B8, LteBand::B12,
+        LteBand::B13, LteBand::B17, LteBand::B18, LteBand::B19, LteBand::B20, LteBand::B25, LteBand::B26, LteBand::B28,
+        LteBand::B32, LteBand::B34, LteBand::B38, LteBand::B39, LteBand::B40, LteBand::B41, LteBand::B42, LteBand::B43,
+        LteBand::B66, LteBand::B71
+    ];
+
+    /// 3GPP band number
+    pub fn number(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Look up a band by its 3GPP band number
+    pub fn from_number(number: u8) -> Option<Self> {
+        Self::ALL.iter().copied().find(|band| band.number() == number)
+    }
+}
+
+impl fmt::Display for LteBand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "B{}", self.number())
+    }
+}
+
+/// 2G/3G bands of the `NetworkBand` mask in `/api/net/net-mode`, as defined
+/// for `AT^SYSCFGEX`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NetworkBand {
+    Gsm1800,
+    Egsm900,
+    Pgsm900,
+    Gsm850,
+    Gsm1900,
+    WcdmaB1,
+    WcdmaB2,
+    WcdmaB4,
+    WcdmaB5,
+    WcdmaB8,
+}
+
+impl NetworkBand {
+    /// All bands known to this library, in ascending bit order
+    pub const ALL: [NetworkBand; 10] = [
+        NetworkBand::Gsm1800, NetworkBand::Egsm900, NetworkBand::Pgsm900, NetworkBand::Gsm850,
+        NetworkBand::Gsm1900, NetworkBand::WcdmaB1, NetworkBand::WcdmaB2, NetworkBand::WcdmaB4,
+        NetworkBand::WcdmaB5, NetworkBand::WcdmaB8,
+    ];
+
+    /// Bit of this band in the `NetworkBand` mask
+    pub fn mask(&self) -> u128 {
+        match self {
+            NetworkBand::Gsm1800 => 0x80,
+            NetworkBand::Egsm900 => 0x100,
+            NetworkBand::Pgsm900 => 0x200,
+            NetworkBand::Gsm850 => 0x80000,
+            NetworkBand::Gsm1900 => 0x200000,
+            NetworkBand::WcdmaB1 => 0x400000,
+            NetworkBand::WcdmaB2 => 0x800000,
+            NetworkBand::WcdmaB4 => 0x2000000,
+            NetworkBand::WcdmaB5 => 0x4000000,
+            NetworkBand::WcdmaB8 => 0x2000000000000,
+        }
+    }
+}
+
+impl fmt::Display for NetworkBand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            NetworkBand::Gsm1800 => "GSM 1800",
+            NetworkBand::Egsm900 => "EGSM 900",
+            NetworkBand::Pgsm900 => "PGSM 900",
+            NetworkBand::Gsm850 => "GSM 850",
+            NetworkBand::Gsm1900 => "GSM 1900",
+            NetworkBand::WcdmaB1 => "WCDMA B1 (2100)",
+            NetworkBand::WcdmaB2 => "WCDMA B2 (1900)",
+            NetworkBand::WcdmaB4 => "WCDMA B4 (1700)",
+            NetworkBand::WcdmaB5 => "WCDMA B5 (850)",
+            NetworkBand::WcdmaB8 => "WCDMA B8 (900)",
+        };
+        write!(f, "{}", text)
+    }
+}
+
 /// SIM status values
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SimStatus {
@@ -432,6 +715,160 @@ impl DnsStatus {
     }
 }
 
+/// Antenna selection on routers with external antenna ports (B525/B528)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AntennaType {
+    Auto,
+    Internal,
+    External,
+}
+
+impl Serialize for AntennaType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let value = match self {
+            AntennaType::Auto => "0",
+            AntennaType::Internal => "1",
+            AntennaType::External => "2",
+        };
+        serializer.serialize_str(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for AntennaType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        match value.trim() {
+            "0" => Ok(AntennaType::Auto),
+            "1" => Ok(AntennaType::Internal),
+            "2" => Ok(AntennaType::External),
+            _ => Err(serde::de::Error::custom(format!("Invalid antenna type: {}", value))),
+        }
+    }
+}
+
+impl fmt::Display for AntennaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            AntennaType::Auto => "Auto",
+            AntennaType::Internal => "Internal",
+            AntennaType::External => "External",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// Firmware update state from `/api/online-update/status`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum UpdateState {
+    UpToDate,
+    Available,
+    Downloading,
+    Installing,
+    Failed,
+}
+
+impl UpdateState {
+    /// Parse a raw status code, `None` for codes not known to this library
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.trim() {
+            "0" => Some(UpdateState::UpToDate),
+            "1" => Some(UpdateState::Available),
+            "2" => Some(UpdateState::Downloading),
+            "3" => Some(UpdateState::Installing),
+            "4" => Some(UpdateState::Failed),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for UpdateState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            UpdateState::UpToDate => "Up to date",
+            UpdateState::Available => "Update available",
+            UpdateState::Downloading => "Downloading",
+            UpdateState::Installing => "Installing",
+            UpdateState::Failed => "Update failed",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// Operator state from `/api/net/current-plmn` and network scans
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlmnState {
+    Unknown,
+    Available,
+    Registered,
+    Forbidden,
+    /// Value not known to this library, kept verbatim
+    Other(String),
+}
+
+impl PlmnState {
+    pub fn as_str(&self) -> &str {
+        match self {
+            PlmnState::Unknown => "0",
+            PlmnState::Available => "1",
+            PlmnState::Registered => "2",
+            PlmnState::Forbidden => "3",
+            PlmnState::Other(value) => value,
+        }
+    }
+
+    /// Parse a raw state value, keeping unknown values as [`PlmnState::Other`]
+    pub fn from_raw(value: &str) -> Self {
+        match value.trim() {
+            "0" => PlmnState::Unknown,
+            "1" => PlmnState::Available,
+            "2" => PlmnState::Registered,
+            "3" => PlmnState::Forbidden,
+            other => PlmnState::Other(other.to_string()),
+        }
+    }
+
+    pub fn is_registered(&self) -> bool {
+        matches!(self, PlmnState::Registered)
+    }
+}
+
+impl Serialize for PlmnState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for PlmnState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PlmnState::from_raw(&value))
+    }
+}
+
+impl fmt::Display for PlmnState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlmnState::Unknown => write!(f, "Unknown"),
+            PlmnState::Available => write!(f, "Available"),
+            PlmnState::Registered => write!(f, "Registered"),
+            PlmnState::Forbidden => write!(f, "Forbidden"),
+            PlmnState::Other(value) => write!(f, "Other ({})", value),
+        }
+    }
+}
+
 /// Device control operation types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DeviceControlType {
@@ -506,6 +943,26 @@ pub enum ApiErrorCode {
     NoRights,
     SystemBusy,
     FormatError,
+
+    // PIN/PUK errors
+    IncorrectPin,
+    IncorrectPuk,
+    PukExhausted,
+
+    // SMS errors
+    PhoneNumberInvalid,
+    SmsCenterNumberInvalid,
+    SmsProcessing,
+    SmsNotEnoughSpace,
+
+    // Voice, SIM and file errors
+    VoiceBusy,
+    SimNotInserted,
+    FileNotFound,
+    FileTooLarge,
+
+    /// Any code without a dedicated variant
+    Other(i32),
 }
 
 impl Serialize for ApiErrorCode {
@@ -513,23 +970,7 @@ impl Serialize for ApiErrorCode {
     where
         S: serde::Serializer,
     {
-        let value = match self {
-            ApiErrorCode::WrongToken => "125001",
-            ApiErrorCode::CsrfTokenInvalid => "125002",
-            ApiErrorCode::WrongSessionToken => "125003",
-            ApiErrorCode::UsernameWrong => "108001",
-            ApiErrorCode::PasswordWrong => "108002",
-            ApiErrorCode::AlreadyLoggedIn => "108003",
-            ApiErrorCode::UsernameOrPasswordWrong => "108006",
-            ApiErrorCode::TooManyLoginAttempts => "108007",
-            ApiErrorCode::PasswordChangeRequired => "115002",
-            ApiErrorCode::SystemUnknown => "100001",
-            ApiErrorCode::SystemNoSupport => "100002",
-            ApiErrorCode::NoRights => "100003",
-            ApiErrorCode::SystemBusy => "100004",
-            ApiErrorCode::FormatError => "100005",
-        };
-        serializer.serialize_str(value)
+        serializer.serialize_str(&self.as_int().to_string())
     }
 }
 
@@ -539,23 +980,11 @@ impl<'de> Deserialize<'de> for ApiErrorCode {
         D: serde::Deserializer<'de>,
     {
         let value = String::deserialize(deserializer)?;
-        match value.as_str() {
-            "125001" => Ok(ApiErrorCode::WrongToken),
-            "125002" => Ok(ApiErrorCode::CsrfTokenInvalid),
-            "125003" => Ok(ApiErrorCode::WrongSessionToken),
-            "108001" => Ok(ApiErrorCode::UsernameWrong),
-            "108002" => Ok(ApiErrorCode::PasswordWrong),
-            "108003" => Ok(ApiErrorCode::AlreadyLoggedIn),
-            "108006" => Ok(ApiErrorCode::UsernameOrPasswordWrong),
-            "108007" => Ok(ApiErrorCode::TooManyLoginAttempts),
-            "115002" => Ok(ApiErrorCode::PasswordChangeRequired),
-            "100001" => Ok(ApiErrorCode::SystemUnknown),
-            "100002" => Ok(ApiErrorCode::SystemNoSupport),
-            "100003" => Ok(ApiErrorCode::NoRights),
-            "100004" => Ok(ApiErrorCode::SystemBusy),
-            "100005" => Ok(ApiErrorCode::FormatError),
-            _ => Err(serde::de::Error::custom(format!("Invalid API error code: {}", value))),
-        }
+        value
+            .trim()
+            .parse::<i32>()
+            .map(ApiErrorCode::from_int)
+            .map_err(|_| serde::de::Error::custom(format!("Invalid API error code: {}", value)))
     }
 }
 
@@ -576,6 +1005,18 @@ impl fmt::Display for ApiErrorCode {
             ApiErrorCode::NoRights => "No rights (login required)",
             ApiErrorCode::SystemBusy => "System busy",
             ApiErrorCode::FormatError => "Format error",
+            ApiErrorCode::IncorrectPin => "Incorrect PIN",
+            ApiErrorCode::IncorrectPuk => "Incorrect PUK",
+            ApiErrorCode::PukExhausted => "PUK attempts exhausted",
+            ApiErrorCode::PhoneNumberInvalid => "Phone number invalid",
+            ApiErrorCode::SmsCenterNumberInvalid => "SMS center number invalid",
+            ApiErrorCode::SmsProcessing => "SMS still processing",
+            ApiErrorCode::SmsNotEnoughSpace => "Not enough space for SMS",
+            ApiErrorCode::VoiceBusy => "Voice busy",
+            ApiErrorCode::SimNotInserted => "SIM not inserted",
+            ApiErrorCode::FileNotFound => "File not found",
+            ApiErrorCode::FileTooLarge => "File too large",
+            ApiErrorCode::Other(code) => return write!(f, "Unknown error {}", code),
         };
         write!(f, "{}", text)
     }
@@ -607,6 +1048,25 @@ impl ApiErrorCode {
         )
     }
 
+    /// Check if this is an SMS error (111xxx)
+    pub fn is_sms_error(&self) -> bool {
+        matches!(
+            self,
+            ApiErrorCode::PhoneNumberInvalid
+                | ApiErrorCode::SmsCenterNumberInvalid
+                | ApiErrorCode::SmsProcessing
+                | ApiErrorCode::SmsNotEnoughSpace
+        )
+    }
+
+    /// Check if this is a PIN/PUK error (106xxx, 107xxx)
+    pub fn is_pin_error(&self) -> bool {
+        matches!(
+            self,
+            ApiErrorCode::IncorrectPin | ApiErrorCode::IncorrectPuk | ApiErrorCode::PukExhausted
+        )
+    }
+
     /// Get the error code as an integer
     pub fn as_int(&self) -> i32 {
         match self {
@@ -624,6 +1084,50 @@ impl ApiErrorCode {
             ApiErrorCode::NoRights => 100003,
             ApiErrorCode::SystemBusy => 100004,
             ApiErrorCode::FormatError => 100005,
+            ApiErrorCode::IncorrectPin => 106001,
+            ApiErrorCode::IncorrectPuk => 107002,
+            ApiErrorCode::PukExhausted => 107003,
+            ApiErrorCode::PhoneNumberInvalid => 111001,
+            ApiErrorCode::SmsCenterNumberInvalid => 111019,
+            ApiErrorCode::SmsProcessing => 111020,
+            ApiErrorCode::SmsNotEnoughSpace => 111022,
+            ApiErrorCode::VoiceBusy => 112001,
+            ApiErrorCode::SimNotInserted => 113017,
+            ApiErrorCode::FileNotFound => 114001,
+            ApiErrorCode::FileTooLarge => 114002,
+            ApiErrorCode::Other(code) => *code,
+        }
+    }
+
+    /// Map an integer code to its variant, falling back to [`ApiErrorCode::Other`]
+    pub fn from_int(code: i32) -> Self {
+        match code {
+            125001 => ApiErrorCode::WrongToken,
+            125002 => ApiErrorCode::CsrfTokenInvalid,
+            125003 => ApiErrorCode::WrongSessionToken,
+            108001 => ApiErrorCode::UsernameWrong,
+            108002 => ApiErrorCode::PasswordWrong,
+            108003 => ApiErrorCode::AlreadyLoggedIn,
+            108006 => ApiErrorCode::UsernameOrPasswordWrong,
+            108007 => ApiErrorCode::TooManyLoginAttempts,
+            115002 => ApiErrorCode::PasswordChangeRequired,
+            100001 => ApiErrorCode::SystemUnknown,
+            100002 => ApiErrorCode::SystemNoSupport,
+            100003 => ApiErrorCode::NoRights,
+            100004 => ApiErrorCode::SystemBusy,
+            100005 => ApiErrorCode::FormatError,
+            106001 => ApiErrorCode::IncorrectPin,
+            107002 => ApiErrorCode::IncorrectPuk,
+            107003 => ApiErrorCode::PukExhausted,
+            111001 => ApiErrorCode::PhoneNumberInvalid,
+            111019 => ApiErrorCode::SmsCenterNumberInvalid,
+            111020 => ApiErrorCode::SmsProcessing,
+            111022 => ApiErrorCode::SmsNotEnoughSpace,
+            112001 => ApiErrorCode::VoiceBusy,
+            113017 => ApiErrorCode::SimNotInserted,
+            114001 => ApiErrorCode::FileNotFound,
+            114002 => ApiErrorCode::FileTooLarge,
+            other => ApiErrorCode::Other(other),
         }
     }
 }
@@ -715,4 +1219,29 @@ mod tests {
         assert!(ApiErrorCode::UsernameWrong.is_auth_error());
         assert!(!ApiErrorCode::CsrfTokenInvalid.is_auth_error());
     }
+
+    #[test]
+    fn test_antenna_type_round_trip() {
+        for kind in [AntennaType::Auto, AntennaType::Internal, AntennaType::External] {
+            let json = serde_json::to_string(&kind).unwrap();
+            assert_eq!(serde_json::from_str::<AntennaType>(&json).unwrap(), kind);
+        }
+        assert_eq!(serde_json::to_string(&AntennaType::External).unwrap(), "\"2\"");
+        assert!(serde_json::from_str::<AntennaType>("\"9\"").is_err());
+    }
+
+    #[test]
+    fn test_plmn_state_serde_round_trip() {
+        for state in [
+            PlmnState::Unknown,
+            PlmnState::Available,
+            PlmnState::Registered,
+            PlmnState::Forbidden,
+            PlmnState::Other("9".to_string()),
+        ] {
+            let json = serde_json::to_string(&state).unwrap();
+            assert_eq!(serde_json::from_str::<PlmnState>(&json).unwrap(), state);
+        }
+        assert_eq!(PlmnState::Other("9".to_string()).to_string(), "Other (9)");
+    }
 }
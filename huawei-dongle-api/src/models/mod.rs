@@ -9,9 +9,15 @@
 //! - [`common`] - Common types like errors and generic responses
 //! - [`device`] - Device information and control structures
 //! - [`dhcp`] - DHCP configuration models
+//! - [`dialup`] - Mobile data connection models
 //! - [`monitoring`] - Connection status and monitoring data
 //! - [`network`] - Network configuration and status
+//! - [`pin`] - SIM PIN/PUK models
+//! - [`plmn`] - Operator name lookup by MCC/MNC
+//! - [`security`] - Port forwarding and firewall models
 //! - [`sms`] - SMS message structures
+//! - [`ussd`] - USSD session structures
+//! - [`wlan`] - WiFi configuration models
 //! 
 //! # XML Format
 //! 
@@ -34,13 +40,21 @@
 //! All models handle this format automatically through serde attributes.
 
 pub mod auth;
+pub mod capabilities;
 pub mod common;
 pub mod device;
 pub mod dhcp;
+pub mod dialup;
 pub mod enums;
 pub mod monitoring;
 pub mod network;
+pub mod pin;
+pub mod plmn;
+pub mod security;
+pub mod snapshot;
 pub mod sms;
+pub mod ussd;
+pub mod wlan;
 
 // Re-export common types
 pub use common::*;
@@ -1,6 +1,12 @@
 //! Monitoring models for connection status and signal information
 
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fmt;
+use std::net::IpAddr;
+use std::time::Duration;
+use super::common::{parse_ip_addr, parse_signal_value};
 use super::enums::{ConnectionStatus, NetworkType, SimStatus, RoamingStatus, ServiceStatus};
 
 /// Connection status response from `/api/monitoring/status`.
@@ -124,6 +130,14 @@ pub struct MonitoringStatus {
 
     #[serde(rename = "poorSignalStatus")]
     pub poor_signal_status: Option<String>,
+
+    /// Seconds since the data connection came up, only on some firmware
+    #[serde(rename = "CurrentConnectTime", default)]
+    pub current_connect_time: Option<String>,
+
+    /// Mobile data switch ("1" on, "0" off), only on some firmware
+    #[serde(rename = "dataswitch", default)]
+    pub data_switch: Option<String>,
 }
 
 impl MonitoringStatus {
@@ -153,6 +167,11 @@ impl MonitoringStatus {
         self.sim_status.is_ready()
     }
 
+    /// Check if airplane (fly) mode has the radio switched off
+    pub fn is_fly_mode(&self) -> bool {
+        self.fly_mode.trim() == "1"
+    }
+
     pub fn is_roaming(&self) -> bool {
         self.roaming_status.is_roaming()
     }
@@ -162,6 +181,16 @@ impl MonitoringStatus {
         self.signal_icon.as_ref().and_then(|s| s.parse().ok())
     }
 
+    /// Signal strength in dBm, parsed from the raw `SignalStrength` value
+    pub fn signal_strength_dbm(&self) -> Option<i32> {
+        parse_signal_value(self.signal_strength.as_deref()).map(|v| v.round() as i32)
+    }
+
+    /// Get signal strength bars (0-5), same as [`signal_level`](Self::signal_level)
+    pub fn signal_bars(&self) -> Option<u8> {
+        self.signal_level()
+    }
+
     /// Get signal strength as percentage (0-100%)
     pub fn signal_percentage(&self) -> Option<u8> {
         self.signal_level().map(|level| match level {
@@ -175,9 +204,571 @@ impl MonitoringStatus {
         })
     }
 
+    /// Primary IPv4 DNS server, or `None` if blank or malformed
+    pub fn primary_dns_addr(&self) -> Option<IpAddr> {
+        self.primary_dns.as_deref().and_then(parse_ip_addr)
+    }
+
+    /// Secondary IPv4 DNS server, or `None` if blank or malformed
+    pub fn secondary_dns_addr(&self) -> Option<IpAddr> {
+        self.secondary_dns.as_deref().and_then(parse_ip_addr)
+    }
+
+    /// Primary IPv6 DNS server, or `None` if blank or malformed
+    pub fn primary_ipv6_dns_addr(&self) -> Option<IpAddr> {
+        self.primary_ipv6_dns.as_deref().and_then(parse_ip_addr)
+    }
+
+    /// Secondary IPv6 DNS server, or `None` if blank or malformed
+    pub fn secondary_ipv6_dns_addr(&self) -> Option<IpAddr> {
+        self.secondary_ipv6_dns.as_deref().and_then(parse_ip_addr)
+    }
+
     pub fn is_service_available(&self) -> bool {
         self.service_status.is_available()
     }
+
+    /// Uptime of the current data connection, if the firmware reports it.
+    /// Use [`TrafficStatistics::current_connect_duration`] otherwise.
+    pub fn connect_duration(&self) -> Option<Duration> {
+        self.current_connect_time
+            .as_deref()
+            .and_then(|secs| secs.trim().parse().ok())
+            .map(Duration::from_secs)
+    }
+
+    /// Whether mobile data is switched on, if the firmware reports it
+    pub fn is_data_enabled(&self) -> Option<bool> {
+        match self.data_switch.as_deref().map(str::trim) {
+            Some("1") => Some(true),
+            Some("0") => Some(false),
+            _ => None,
+        }
+    }
+}
+
+/// Cleaned-up view of [`MonitoringStatus`] for JSON/YAML consumers, with
+/// camelCase names and typed values instead of raw device strings.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonitoringStatusView {
+    pub connected: bool,
+    pub connection_status: String,
+    pub network_type: String,
+    pub network_type_ex: Option<String>,
+    pub signal_level: Option<u8>,
+    pub signal_dbm: Option<i32>,
+    pub sim_ready: bool,
+    pub service_available: bool,
+    pub roaming: bool,
+    pub primary_dns: Option<IpAddr>,
+    pub secondary_dns: Option<IpAddr>,
+}
+
+impl From<&MonitoringStatus> for MonitoringStatusView {
+    fn from(status: &MonitoringStatus) -> Self {
+        Self {
+            connected: status.is_connected(),
+            connection_status: status.connection_status_text(),
+            network_type: status.network_type_text(),
+            network_type_ex: status
+                .current_network_type_ex
+                .map(|network_type| network_type.extended_text().to_string()),
+            signal_level: status.signal_level(),
+            signal_dbm: status.signal_strength_dbm(),
+            sim_ready: status.is_sim_ready(),
+            service_available: status.is_service_available(),
+            roaming: status.is_roaming(),
+            primary_dns: status.primary_dns_addr(),
+            secondary_dns: status.secondary_dns_addr(),
+        }
+    }
+}
+
+/// Traffic statistics response from `/api/monitoring/traffic-statistics`.
+///
+/// The device reports all counters as decimal strings; use the accessors to get
+/// typed values. Byte counters are cumulative since the last reset, times are in seconds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct TrafficStatistics {
+    #[serde(rename = "CurrentConnectTime")]
+    pub current_connect_time: String,
+
+    #[serde(rename = "CurrentUpload")]
+    pub current_upload: String,
+
+    #[serde(rename = "CurrentDownload")]
+    pub current_download: String,
+
+    #[serde(rename = "CurrentDownloadRate")]
+    pub current_download_rate: String,
+
+    #[serde(rename = "CurrentUploadRate")]
+    pub current_upload_rate: String,
+
+    #[serde(rename = "TotalUpload")]
+    pub total_upload: String,
+
+    #[serde(rename = "TotalDownload")]
+    pub total_download: String,
+
+    #[serde(rename = "TotalConnectTime")]
+    pub total_connect_time: String,
+
+    #[serde(rename = "showtraffic", default)]
+    pub show_traffic: Option<String>,
+}
+
+/// Billing-month totals from `/api/monitoring/month_statistics`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct MonthStatistics {
+    #[serde(rename = "CurrentMonthDownload")]
+    pub current_month_download: String,
+
+    #[serde(rename = "CurrentMonthUpload")]
+    pub current_month_upload: String,
+
+    /// Connection time in the current month (seconds)
+    #[serde(rename = "MonthDuration")]
+    pub month_duration: String,
+
+    /// Date the month counters were last cleared (omitted by some firmware)
+    #[serde(rename = "MonthLastClearTime", default)]
+    pub month_last_clear_time: Option<String>,
+}
+
+/// Converged status from `/api/monitoring/converged-status`
+///
+/// Every field is optional; the set reported varies between firmware, and
+/// some firmware reports the names in lowercase (`currentnetworktype`,
+/// `signalbar`), which are accepted as aliases.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct ConvergedStatus {
+    /// SIM state, same codes as `/api/pin/status`
+    #[serde(rename = "SimState", alias = "simstate", default)]
+    pub sim_state: Option<String>,
+
+    /// Active SIM slot on dual-SIM devices
+    #[serde(rename = "SimSlot", alias = "simslot", default)]
+    pub sim_slot: Option<String>,
+
+    #[serde(rename = "CurrentLanguage", alias = "currentlanguage", default)]
+    pub current_language: Option<String>,
+
+    /// Connection status code, `901` when connected
+    #[serde(rename = "ConnectionStatus", alias = "connectionstatus", default)]
+    pub connection_status: Option<String>,
+
+    /// Current network type code, `101` for 5G NSA and `102` for 5G SA
+    #[serde(rename = "CurrentNetworkType", alias = "currentnetworktype", default)]
+    pub current_network_type: Option<String>,
+
+    /// Combined signal bars (0-5)
+    #[serde(rename = "SignalIcon", alias = "signalicon", alias = "signalbar", default)]
+    pub signal_icon: Option<String>,
+
+    /// LTE anchor signal bars (0-5)
+    #[serde(rename = "LteSignalIcon", alias = "ltesignalicon", alias = "ltesignalbar", default)]
+    pub lte_signal_icon: Option<String>,
+
+    /// NR signal bars (0-5), absent on 4G-only devices
+    #[serde(rename = "NrSignalIcon", alias = "nrsignalicon", alias = "nrsignalbar", default)]
+    pub nr_signal_icon: Option<String>,
+
+    /// NR leg status, `1` while the NR carrier is attached
+    #[serde(rename = "NrConnectStatus", alias = "nrconnectstatus", default)]
+    pub nr_connect_status: Option<String>,
+}
+
+impl ConvergedStatus {
+    /// Current network type, if the device reports a known code
+    pub fn network_type(&self) -> Option<NetworkType> {
+        self.current_network_type.as_deref().and_then(NetworkType::from_code)
+    }
+
+    /// Check if the device is connected over 5G (NSA or SA)
+    ///
+    /// A reported connection status other than `901` counts as not connected.
+    pub fn is_5g_connected(&self) -> bool {
+        let connected = self
+            .connection_status
+            .as_deref()
+            .is_none_or(|status| status.trim() == "901");
+        let nr_attached = self.nr_connect_status.as_deref().map(str::trim) == Some("1");
+        connected && (self.network_type().is_some_and(|t| t.is_5g()) || nr_attached)
+    }
+
+    /// Signal bars (0-5) across LTE and NR
+    ///
+    /// Uses the combined `SignalIcon` when reported, otherwise the stronger
+    /// of the per-RAT values.
+    pub fn aggregated_signal_bars(&self) -> Option<u8> {
+        let parse = |value: &Option<String>| value.as_deref().and_then(|v| v.trim().parse::<u8>().ok());
+        parse(&self.signal_icon)
+            .or_else(|| parse(&self.lte_signal_icon).max(parse(&self.nr_signal_icon)))
+            .map(|bars| bars.min(5))
+    }
+}
+
+/// Lightweight notification flags from `/api/monitoring/check-notifications`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct Notifications {
+    /// Number of unread SMS messages
+    #[serde(rename = "UnreadMessage")]
+    pub unread_message: String,
+
+    #[serde(rename = "SmsStorageFull")]
+    pub sms_storage_full: String,
+
+    #[serde(rename = "OnlineUpdateStatus", default)]
+    pub online_update_status: Option<String>,
+
+    #[serde(rename = "SimList", default)]
+    pub sim_list: Option<String>,
+}
+
+/// Clear traffic statistics request for `/api/monitoring/clear-traffic`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct ClearTrafficRequest {
+    #[serde(rename = "ClearTraffic")]
+    pub clear_traffic: String,
+}
+
+impl ClearTrafficRequest {
+    /// Create a new clear traffic request
+    pub fn new() -> Self {
+        Self {
+            clear_traffic: "1".to_string(),
+        }
+    }
+}
+
+impl Default for ClearTrafficRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Monthly data cap in the device's `"10GB"` / `"500MB"` notation
+///
+/// The web UI counts in binary units, so a gigabyte is 1024 megabytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataLimit {
+    Megabytes(u64),
+    Gigabytes(u64),
+}
+
+impl DataLimit {
+    /// Parse a limit such as `"10GB"` or `"500MB"`, ignoring case and
+    /// surrounding whitespace
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().to_ascii_uppercase();
+        if let Some(amount) = value.strip_suffix("GB") {
+            amount.trim().parse().ok().map(DataLimit::Gigabytes)
+        } else if let Some(amount) = value.strip_suffix("MB") {
+            amount.trim().parse().ok().map(DataLimit::Megabytes)
+        } else {
+            None
+        }
+    }
+
+    /// The limit in bytes
+    pub fn bytes(&self) -> u64 {
+        match self {
+            DataLimit::Megabytes(mb) => mb.saturating_mul(1024 * 1024),
+            DataLimit::Gigabytes(gb) => gb.saturating_mul(1024 * 1024 * 1024),
+        }
+    }
+}
+
+impl fmt::Display for DataLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataLimit::Megabytes(mb) => write!(f, "{}MB", mb),
+            DataLimit::Gigabytes(gb) => write!(f, "{}GB", gb),
+        }
+    }
+}
+
+/// Monthly data limit settings from `/api/monitoring/start_date`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct DataLimitSettings {
+    /// Day of the month the billing cycle starts (1-31)
+    #[serde(rename = "StartDay")]
+    pub start_day: String,
+
+    /// Monthly cap, e.g. `10GB`
+    #[serde(rename = "DataLimit")]
+    pub data_limit: String,
+
+    /// Usage percentage at which the device warns
+    #[serde(rename = "MonthThreshold")]
+    pub month_threshold: String,
+
+    /// `1` if the monthly limit is enabled
+    #[serde(rename = "SetMonthData", default)]
+    pub set_month_data: Option<String>,
+}
+
+impl DataLimitSettings {
+    pub fn start_day(&self) -> Option<u8> {
+        self.start_day.trim().parse().ok()
+    }
+
+    /// Typed monthly cap, or `None` if the device reports something unparseable
+    pub fn limit(&self) -> Option<DataLimit> {
+        DataLimit::parse(&self.data_limit)
+    }
+
+    pub fn limit_bytes(&self) -> Option<u64> {
+        self.limit().map(|limit| limit.bytes())
+    }
+
+    pub fn threshold_percent(&self) -> Option<u8> {
+        self.month_threshold.trim().parse().ok()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.set_month_data.as_deref().map(str::trim) == Some("1")
+    }
+
+    /// Check if this month's traffic has reached the warning threshold of
+    /// the cap; `false` when no cap or threshold is set
+    pub fn is_threshold_reached(&self, stats: &MonthStatistics) -> bool {
+        let (Some(limit), Some(threshold)) = (self.limit_bytes(), self.threshold_percent()) else {
+            return false;
+        };
+        if limit == 0 {
+            return false;
+        }
+
+        let used = stats.month_download_bytes().saturating_add(stats.month_upload_bytes());
+        used as u128 * 100 >= limit as u128 * threshold as u128
+    }
+}
+
+/// Set data limit request for `/api/monitoring/start_date`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct DataLimitRequest {
+    #[serde(rename = "StartDay")]
+    pub start_day: String,
+
+    #[serde(rename = "DataLimit")]
+    pub data_limit: String,
+
+    #[serde(rename = "MonthThreshold")]
+    pub month_threshold: String,
+
+    #[serde(rename = "SetMonthData")]
+    pub set_month_data: String,
+}
+
+impl DataLimitRequest {
+    /// Enable a monthly cap of `limit` for billing cycles starting on
+    /// `start_day`, warning at `threshold_percent` of the cap
+    pub fn new(start_day: u8, limit: DataLimit, threshold_percent: u8) -> Self {
+        Self {
+            start_day: start_day.to_string(),
+            data_limit: limit.to_string(),
+            month_threshold: threshold_percent.to_string(),
+            set_month_data: "1".to_string(),
+        }
+    }
+}
+
+/// Parse a numeric counter string, treating blank or malformed values as zero
+fn parse_counter(value: &str) -> u64 {
+    value.trim().parse().unwrap_or(0)
+}
+
+impl TrafficStatistics {
+    /// Duration of the current connection
+    pub fn current_connect_duration(&self) -> Duration {
+        Duration::from_secs(parse_counter(&self.current_connect_time))
+    }
+
+    /// Bytes uploaded during the current connection
+    pub fn current_upload_bytes(&self) -> u64 {
+        parse_counter(&self.current_upload)
+    }
+
+    /// Bytes downloaded during the current connection
+    pub fn current_download_bytes(&self) -> u64 {
+        parse_counter(&self.current_download)
+    }
+
+    /// Current download rate in bytes per second
+    pub fn current_download_rate_bps(&self) -> u64 {
+        parse_counter(&self.current_download_rate)
+    }
+
+    /// Current upload rate in bytes per second
+    pub fn current_upload_rate_bps(&self) -> u64 {
+        parse_counter(&self.current_upload_rate)
+    }
+
+    /// Total bytes uploaded since the counters were last cleared
+    pub fn total_upload_bytes(&self) -> u64 {
+        parse_counter(&self.total_upload)
+    }
+
+    /// Total bytes downloaded since the counters were last cleared
+    pub fn total_download_bytes(&self) -> u64 {
+        parse_counter(&self.total_download)
+    }
+
+    /// Total connection time since the counters were last cleared
+    pub fn total_connect_duration(&self) -> Duration {
+        Duration::from_secs(parse_counter(&self.total_connect_time))
+    }
+
+    /// Check if the web UI is configured to show traffic statistics
+    pub fn is_traffic_shown(&self) -> bool {
+        self.show_traffic.as_deref() == Some("1")
+    }
+}
+
+impl MonthStatistics {
+    /// Bytes downloaded in the current billing month
+    pub fn month_download_bytes(&self) -> u64 {
+        parse_counter(&self.current_month_download)
+    }
+
+    /// Bytes uploaded in the current billing month
+    pub fn month_upload_bytes(&self) -> u64 {
+        parse_counter(&self.current_month_upload)
+    }
+
+    /// Connection time in the current billing month
+    pub fn month_connect_duration(&self) -> Duration {
+        Duration::from_secs(parse_counter(&self.month_duration))
+    }
+
+    /// Date the month counters were last cleared, or `None` if absent or malformed
+    pub fn month_last_clear_date(&self) -> Option<NaiveDate> {
+        self.month_last_clear_time
+            .as_deref()
+            .and_then(|date| NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").ok())
+    }
+}
+
+impl Notifications {
+    /// Number of unread SMS messages
+    pub fn unread_count(&self) -> u64 {
+        parse_counter(&self.unread_message)
+    }
+
+    /// Check if there are unread SMS messages
+    pub fn has_unread(&self) -> bool {
+        self.unread_count() > 0
+    }
+
+    /// Check if the SMS storage is full and new messages cannot be received
+    pub fn storage_full(&self) -> bool {
+        self.sms_storage_full.trim() == "1"
+    }
+}
+
+/// One signal reading recorded by [`SignalHistory`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignalSample {
+    pub timestamp: DateTime<Utc>,
+    pub dbm: Option<i32>,
+    pub bars: u8,
+}
+
+/// Bounded history of signal readings, e.g. for a watch loop.
+///
+/// Once `capacity` samples are stored the oldest one is dropped for each new
+/// sample. Statistics cover the samples currently held.
+#[derive(Debug, Clone)]
+pub struct SignalHistory {
+    capacity: usize,
+    samples: VecDeque<SignalSample>,
+}
+
+/// Sparkline glyphs for 0 to 5 bars
+const SPARK_GLYPHS: [char; 6] = ['▁', '▂', '▃', '▅', '▆', '█'];
+
+impl SignalHistory {
+    /// Create an empty history holding at most `capacity` samples (at least 1)
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            samples: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn push(&mut self, timestamp: DateTime<Utc>, dbm: Option<i32>, bars: u8) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(SignalSample { timestamp, dbm, bars });
+    }
+
+    /// Record the current signal from a status poll
+    pub fn record(&mut self, status: &MonitoringStatus) {
+        self.push(
+            Utc::now(),
+            status.signal_strength_dbm(),
+            status.signal_level().unwrap_or(0),
+        );
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Samples from oldest to newest
+    pub fn samples(&self) -> impl Iterator<Item = &SignalSample> {
+        self.samples.iter()
+    }
+
+    fn dbm_values(&self) -> impl Iterator<Item = i32> + '_ {
+        self.samples.iter().filter_map(|sample| sample.dbm)
+    }
+
+    /// Weakest reading in dBm, ignoring samples without one
+    pub fn min_dbm(&self) -> Option<i32> {
+        self.dbm_values().min()
+    }
+
+    /// Strongest reading in dBm, ignoring samples without one
+    pub fn max_dbm(&self) -> Option<i32> {
+        self.dbm_values().max()
+    }
+
+    /// Mean reading in dBm, ignoring samples without one
+    pub fn avg_dbm(&self) -> Option<f64> {
+        let (sum, count) = self
+            .dbm_values()
+            .fold((0i64, 0u32), |(sum, count), dbm| (sum + dbm as i64, count + 1));
+        (count > 0).then(|| sum as f64 / count as f64)
+    }
+
+    /// One glyph per sample, scaled by signal bars
+    pub fn sparkline(&self) -> String {
+        self.samples
+            .iter()
+            .map(|sample| SPARK_GLYPHS[sample.bars.min(5) as usize])
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -220,6 +811,8 @@ mod tests {
             hvdcp_online: None,
             speed_limit_status: None,
             poor_signal_status: None,
+            current_connect_time: None,
+            data_switch: None,
         };
 
         assert_eq!(status.connection_status, ConnectionStatus::Connected);
@@ -232,5 +825,336 @@ mod tests {
         assert_eq!(status.signal_level(), Some(5));
         assert_eq!(status.signal_percentage(), Some(100));
         assert!(status.is_service_available());
+        assert_eq!(status.primary_dns_addr(), None);
+    }
+
+    const STATUS_XML: &str = r#"<response>
+<ConnectionStatus>901</ConnectionStatus>
+<SignalIcon>4</SignalIcon>
+<CurrentNetworkType>19</CurrentNetworkType>
+<CurrentServiceDomain>3</CurrentServiceDomain>
+<RoamingStatus>0</RoamingStatus>
+<simlockStatus>0</simlockStatus>
+<PrimaryDns>10.11.12.13</PrimaryDns>
+<SecondaryDns></SecondaryDns>
+<flymode>0</flymode>
+<PrimaryIPv6Dns>2a00:1028::1</PrimaryIPv6Dns>
+<SecondaryIPv6Dns></SecondaryIPv6Dns>
+<currenttotalwifiuser>0</currenttotalwifiuser>
+<SimStatus>1</SimStatus>
+<ServiceStatus>2</ServiceStatus>
+<maxsignal>5</maxsignal>
+<wifiindooronly>0</wifiindooronly>
+<usbup>0</usbup>
+<wifiswitchstatus>1</wifiswitchstatus>
+</response>"#;
+
+    #[test]
+    fn test_dns_addr_accessors() {
+        let status: MonitoringStatus = serde_xml_rs::from_str(STATUS_XML).unwrap();
+        assert_eq!(status.primary_dns_addr(), Some("10.11.12.13".parse().unwrap()));
+        assert_eq!(status.secondary_dns_addr(), None);
+        assert_eq!(status.primary_ipv6_dns_addr(), Some("2a00:1028::1".parse().unwrap()));
+        assert_eq!(status.secondary_ipv6_dns_addr(), None);
     }
+
+    #[test]
+    fn test_signal_strength_dbm() {
+        let mut status: MonitoringStatus = serde_xml_rs::from_str(STATUS_XML).unwrap();
+        assert_eq!(status.signal_bars(), Some(4));
+
+        for (raw, expected) in [
+            (Some("-71dBm"), Some(-71)),
+            (Some("-71"), Some(-71)),
+            (Some(""), None),
+            (Some("unknown"), None),
+            (None, None),
+        ] {
+            status.signal_strength = raw.map(str::to_string);
+            assert_eq!(status.signal_strength_dbm(), expected, "input {:?}", raw);
+        }
+    }
+
+    #[test]
+    fn test_monitoring_status_view_json() {
+        let mut status: MonitoringStatus = serde_xml_rs::from_str(STATUS_XML).unwrap();
+        status.signal_strength = Some("-71dBm".to_string());
+
+        let json = serde_json::to_value(MonitoringStatusView::from(&status)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "connected": true,
+                "connectionStatus": "CONNECTED",
+                "networkType": "LTE (4G)",
+                "networkTypeEx": null,
+                "signalLevel": 4,
+                "signalDbm": -71,
+                "simReady": true,
+                "serviceAvailable": true,
+                "roaming": false,
+                "primaryDns": "10.11.12.13",
+                "secondaryDns": null
+            })
+        );
+    }
+
+    #[test]
+    fn test_traffic_statistics_parsing() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<CurrentConnectTime>3725</CurrentConnectTime>
+<CurrentUpload>1048576</CurrentUpload>
+<CurrentDownload>52428800</CurrentDownload>
+<CurrentDownloadRate>125000</CurrentDownloadRate>
+<CurrentUploadRate>4096</CurrentUploadRate>
+<TotalUpload>734003200</TotalUpload>
+<TotalDownload>21474836480</TotalDownload>
+<TotalConnectTime>864000</TotalConnectTime>
+<showtraffic>1</showtraffic>
+</response>"#;
+
+        let stats: TrafficStatistics = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(stats.current_connect_duration(), Duration::from_secs(3725));
+        assert_eq!(stats.current_upload_bytes(), 1_048_576);
+        assert_eq!(stats.current_download_bytes(), 52_428_800);
+        assert_eq!(stats.current_download_rate_bps(), 125_000);
+        assert_eq!(stats.current_upload_rate_bps(), 4096);
+        assert_eq!(stats.total_upload_bytes(), 734_003_200);
+        assert_eq!(stats.total_download_bytes(), 21_474_836_480);
+        assert_eq!(stats.total_connect_duration(), Duration::from_secs(864_000));
+        assert!(stats.is_traffic_shown());
+    }
+
+    #[test]
+    fn test_month_statistics_parsing() {
+        let xml = r#"<response>
+<CurrentMonthDownload>5368709120</CurrentMonthDownload>
+<CurrentMonthUpload>268435456</CurrentMonthUpload>
+<MonthDuration>172800</MonthDuration>
+<MonthLastClearTime>2025-6-1</MonthLastClearTime>
+</response>"#;
+
+        let stats: MonthStatistics = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(stats.month_download_bytes(), 5_368_709_120);
+        assert_eq!(stats.month_upload_bytes(), 268_435_456);
+        assert_eq!(stats.month_connect_duration(), Duration::from_secs(172_800));
+        assert_eq!(stats.month_last_clear_time.as_deref(), Some("2025-6-1"));
+        assert_eq!(stats.month_last_clear_date(), NaiveDate::from_ymd_opt(2025, 6, 1));
+    }
+
+    #[test]
+    fn test_month_statistics_without_clear_time() {
+        let xml = r#"<response>
+<CurrentMonthDownload>1024</CurrentMonthDownload>
+<CurrentMonthUpload>512</CurrentMonthUpload>
+<MonthDuration>60</MonthDuration>
+</response>"#;
+
+        let stats: MonthStatistics = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(stats.month_download_bytes(), 1024);
+        assert_eq!(stats.month_upload_bytes(), 512);
+        assert!(stats.month_last_clear_time.is_none());
+    }
+
+    #[test]
+    fn test_clear_traffic_request_serialization() {
+        let request = ClearTrafficRequest::new();
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+        assert!(xml.contains("<request><ClearTraffic>1</ClearTraffic></request>"));
+    }
+
+    #[test]
+    fn test_notifications_parsing() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<UnreadMessage>3</UnreadMessage>
+<SmsStorageFull>1</SmsStorageFull>
+<OnlineUpdateStatus>10</OnlineUpdateStatus>
+<SimOperEvent>0</SimOperEvent>
+</response>"#;
+
+        let notifications: Notifications = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(notifications.unread_count(), 3);
+        assert!(notifications.has_unread());
+        assert!(notifications.storage_full());
+        assert_eq!(notifications.online_update_status.as_deref(), Some("10"));
+        assert_eq!(notifications.sim_list, None);
+
+        let quiet: Notifications = serde_xml_rs::from_str(
+            "<response><UnreadMessage>0</UnreadMessage><SmsStorageFull>0</SmsStorageFull></response>",
+        )
+        .unwrap();
+        assert!(!quiet.has_unread());
+        assert!(!quiet.storage_full());
+    }
+
+    #[test]
+    fn test_connect_duration_and_data_switch() {
+        let xml = STATUS_XML.replace(
+            "</response>",
+            "<CurrentConnectTime>3725</CurrentConnectTime><dataswitch>1</dataswitch></response>",
+        );
+        let status: MonitoringStatus = serde_xml_rs::from_str(&xml).unwrap();
+        assert_eq!(status.connect_duration(), Some(Duration::from_secs(3725)));
+        assert_eq!(status.is_data_enabled(), Some(true));
+    }
+
+    #[test]
+    fn test_connect_duration_missing() {
+        let status: MonitoringStatus = serde_xml_rs::from_str(STATUS_XML).unwrap();
+        assert_eq!(status.connect_duration(), None);
+        assert_eq!(status.is_data_enabled(), None);
+    }
+
+    fn history(readings: &[(Option<i32>, u8)], capacity: usize) -> SignalHistory {
+        let start = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let mut history = SignalHistory::new(capacity);
+        for (i, (dbm, bars)) in readings.iter().enumerate() {
+            history.push(start + chrono::Duration::seconds(i as i64), *dbm, *bars);
+        }
+        history
+    }
+
+    #[test]
+    fn test_signal_history_statistics() {
+        let history = history(&[(Some(-80), 3), (None, 0), (Some(-70), 4), (Some(-90), 2)], 10);
+
+        assert_eq!(history.len(), 4);
+        assert_eq!(history.min_dbm(), Some(-90));
+        assert_eq!(history.max_dbm(), Some(-70));
+        assert_eq!(history.avg_dbm(), Some(-80.0));
+        assert!(SignalHistory::new(3).avg_dbm().is_none());
+    }
+
+    #[test]
+    fn test_signal_history_is_bounded() {
+        let history = history(&[(Some(-100), 1), (Some(-80), 3), (Some(-70), 4), (Some(-60), 5)], 3);
+
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.min_dbm(), Some(-80));
+        assert_eq!(history.samples().next().unwrap().dbm, Some(-80));
+    }
+
+    #[test]
+    fn test_signal_history_sparkline() {
+        let history = history(&[(None, 0), (None, 1), (None, 3), (None, 5), (None, 9)], 10);
+        assert_eq!(history.sparkline(), "▁▂▅██");
+        assert_eq!(SignalHistory::new(5).sparkline(), "");
+    }
+
+    #[test]
+    fn test_converged_status_5g_nsa() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<SimState>257</SimState>
+<SimSlot>0</SimSlot>
+<CurrentLanguage>en-us</CurrentLanguage>
+<ConnectionStatus>901</ConnectionStatus>
+<CurrentNetworkType>101</CurrentNetworkType>
+<LteSignalIcon>3</LteSignalIcon>
+<NrSignalIcon>4</NrSignalIcon>
+<NrConnectStatus>1</NrConnectStatus>
+</response>"#;
+
+        let status: ConvergedStatus = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(status.network_type(), Some(NetworkType::FiveGNsa));
+        assert!(status.is_5g_connected());
+        assert_eq!(status.aggregated_signal_bars(), Some(4));
+    }
+
+    #[test]
+    fn test_converged_status_lowercase_names() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<SimState>257</SimState>
+<SimSlot>0</SimSlot>
+<CurrentLanguage>en-us</CurrentLanguage>
+<connectionstatus>901</connectionstatus>
+<currentnetworktype>101</currentnetworktype>
+<signalbar>3</signalbar>
+<ltesignalbar>3</ltesignalbar>
+<nrsignalbar>2</nrsignalbar>
+<nrconnectstatus>1</nrconnectstatus>
+</response>"#;
+
+        let status: ConvergedStatus = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(status.sim_state.as_deref(), Some("257"));
+        assert_eq!(status.connection_status.as_deref(), Some("901"));
+        assert_eq!(status.network_type(), Some(NetworkType::FiveGNsa));
+        assert_eq!(status.nr_signal_icon.as_deref(), Some("2"));
+        assert!(status.is_5g_connected());
+        assert_eq!(status.aggregated_signal_bars(), Some(3));
+    }
+
+    #[test]
+    fn test_converged_status_4g_only() {
+        let xml = r#"<response>
+<SimState>257</SimState>
+<CurrentLanguage>en-us</CurrentLanguage>
+</response>"#;
+
+        let status: ConvergedStatus = serde_xml_rs::from_str(xml).unwrap();
+        assert!(!status.is_5g_connected());
+        assert_eq!(status.aggregated_signal_bars(), None);
+
+        let lte = ConvergedStatus {
+            connection_status: Some("901".to_string()),
+            current_network_type: Some("19".to_string()),
+            signal_icon: Some("5".to_string()),
+            ..Default::default()
+        };
+        assert!(!lte.is_5g_connected());
+        assert_eq!(lte.aggregated_signal_bars(), Some(5));
+    }
+
+    #[test]
+    fn test_data_limit_parsing() {
+        assert_eq!(DataLimit::parse("10GB"), Some(DataLimit::Gigabytes(10)));
+        assert_eq!(DataLimit::parse("500MB"), Some(DataLimit::Megabytes(500)));
+        assert_eq!(DataLimit::parse(" 2gb "), Some(DataLimit::Gigabytes(2)));
+        assert_eq!(DataLimit::parse("10GB").unwrap().bytes(), 10 * 1024 * 1024 * 1024);
+        assert_eq!(DataLimit::parse("500MB").unwrap().bytes(), 500 * 1024 * 1024);
+        assert_eq!(DataLimit::parse("10TB"), None);
+        assert_eq!(DataLimit::parse("GB"), None);
+        assert_eq!(DataLimit::parse(""), None);
+    }
+
+    #[test]
+    fn test_data_limit_settings_parsing() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<StartDay>15</StartDay>
+<DataLimit>1GB</DataLimit>
+<MonthThreshold>90</MonthThreshold>
+<SetMonthData>1</SetMonthData>
+<trafficmaxlimit>0</trafficmaxlimit>
+</response>"#;
+
+        let settings: DataLimitSettings = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(settings.start_day(), Some(15));
+        assert_eq!(settings.limit(), Some(DataLimit::Gigabytes(1)));
+        assert_eq!(settings.threshold_percent(), Some(90));
+        assert!(settings.is_enabled());
+
+        let month = |bytes: u64| MonthStatistics {
+            current_month_download: bytes.to_string(),
+            current_month_upload: "0".to_string(),
+            month_duration: "0".to_string(),
+            month_last_clear_time: None,
+        };
+        assert!(!settings.is_threshold_reached(&month(900 * 1024 * 1024)));
+        assert!(settings.is_threshold_reached(&month(1024 * 1024 * 1024)));
+    }
+
+    #[test]
+    fn test_data_limit_request_serialization() {
+        let request = DataLimitRequest::new(1, DataLimit::Megabytes(500), 80);
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+        assert!(xml.contains(
+            "<request><StartDay>1</StartDay><DataLimit>500MB</DataLimit>\
+<MonthThreshold>80</MonthThreshold><SetMonthData>1</SetMonthData></request>"
+        ));
+    }
+
 }
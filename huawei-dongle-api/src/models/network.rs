@@ -1,7 +1,8 @@
 //! Network configuration models
 
 use serde::{Deserialize, Serialize};
-use super::enums::{NetworkModeType, NetworkType};
+use super::enums::{LteBand, NetworkBand, NetworkModeType, NetworkType, PlmnSelectionMode, PlmnState};
+use super::plmn;
 
 /// Network mode configuration response from `/api/net/net-mode`
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +32,62 @@ pub struct NetworkModeRequest {
     pub lte_band: String,
 }
 
+/// Band bitmask as used by `/api/net/net-mode`. For LTE, band N is bit N-1;
+/// the 2G/3G `NetworkBand` mask uses the bits of [`NetworkBand::mask`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BandMask(u128);
+
+impl BandMask {
+    /// Build a mask enabling the given bands
+    pub fn from_bands(bands: &[LteBand]) -> Self {
+        Self(bands.iter().fold(0, |mask, band| mask | Self::bit(*band)))
+    }
+
+    /// Parse a hex mask as reported by the device (case-insensitive, optional
+    /// `0x` prefix). Bits above 128 are ignored, as no known band uses them.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.trim();
+        let hex = hex
+            .strip_prefix("0x")
+            .or_else(|| hex.strip_prefix("0X"))
+            .unwrap_or(hex);
+        if hex.is_empty() || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let low = &hex[hex.len().saturating_sub(32)..];
+        u128::from_str_radix(low, 16).ok().map(Self)
+    }
+
+    /// Uppercase hex representation expected by the device
+    pub fn to_hex(&self) -> String {
+        format!("{:X}", self.0)
+    }
+
+    /// Check if the band is enabled
+    pub fn contains(&self, band: LteBand) -> bool {
+        self.0 & Self::bit(band) != 0
+    }
+
+    /// Known bands enabled in this mask, in ascending order
+    pub fn bands(&self) -> Vec<LteBand> {
+        LteBand::ALL.iter().copied().filter(|band| self.contains(*band)).collect()
+    }
+
+    /// Known 2G/3G bands enabled in this mask, read as a `NetworkBand` mask
+    pub fn network_bands(&self) -> Vec<NetworkBand> {
+        NetworkBand::ALL
+            .iter()
+            .copied()
+            .filter(|band| self.0 & band.mask() != 0)
+            .collect()
+    }
+
+    fn bit(band: LteBand) -> u128 {
+        1 << (band.number() - 1)
+    }
+}
+
 /// Current PLMN (network operator) information from `/api/net/current-plmn`
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename = "response")]
@@ -51,6 +108,56 @@ pub struct CurrentPlmn {
     pub rat: Option<NetworkType>,
 }
 
+/// Operator found by a network scan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlmnNetwork {
+    /// 0 = unknown, 1 = available, 2 = current, 3 = forbidden
+    #[serde(rename = "State")]
+    pub state: String,
+
+    #[serde(rename = "FullName", default)]
+    pub full_name: String,
+
+    #[serde(rename = "ShortName", default)]
+    pub short_name: String,
+
+    #[serde(rename = "Numeric")]
+    pub numeric: String,
+
+    /// Radio access technology (0 = 2G, 2 = 3G, 7 = 4G)
+    #[serde(rename = "Rat")]
+    pub rat: String,
+}
+
+/// Repeated `<Network>` entries of a PLMN list
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlmnNetworks {
+    #[serde(rename = "$value", default)]
+    pub networks: Vec<PlmnNetwork>,
+}
+
+/// Operator scan result from `/api/net/plmn-list`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct PlmnList {
+    #[serde(rename = "Networks", default)]
+    pub networks: PlmnNetworks,
+}
+
+/// Manual operator selection request for `/api/net/register`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct PlmnRegisterRequest {
+    #[serde(rename = "Mode")]
+    pub mode: PlmnSelectionMode,
+
+    #[serde(rename = "Plmn")]
+    pub plmn: String,
+
+    #[serde(rename = "Rat")]
+    pub rat: String,
+}
+
 impl NetworkMode {
     /// Get network mode as human-readable string
     pub fn mode_text(&self) -> String {
@@ -66,6 +173,20 @@ impl NetworkMode {
     pub fn is_auto(&self) -> bool {
         matches!(self.network_mode, NetworkModeType::Auto)
     }
+
+    /// LTE bands enabled in `lte_band`; empty if the mask is not valid hex
+    pub fn lte_bands(&self) -> Vec<LteBand> {
+        BandMask::from_hex(&self.lte_band)
+            .map(|mask| mask.bands())
+            .unwrap_or_default()
+    }
+
+    /// 2G/3G bands enabled in `network_band`; empty if the mask is not valid hex
+    pub fn network_bands(&self) -> Vec<NetworkBand> {
+        BandMask::from_hex(&self.network_band)
+            .map(|mask| mask.network_bands())
+            .unwrap_or_default()
+    }
 }
 
 impl NetworkModeRequest {
@@ -78,6 +199,19 @@ impl NetworkModeRequest {
         }
     }
 
+    /// Create a network mode request with the LTE bands given as a [`BandMask`]
+    pub fn with_band_mask(mode: NetworkModeType, network_band: String, lte_band: BandMask) -> Self {
+        Self::new(mode, network_band, lte_band.to_hex())
+    }
+
+    /// Replace the bands with the ones currently configured on the device,
+    /// so only the mode changes
+    pub fn with_bands_from(mut self, current: &NetworkMode) -> Self {
+        self.network_band = current.network_band.clone();
+        self.lte_band = current.lte_band.clone();
+        self
+    }
+
     /// Create a 4G only mode request with common bands
     pub fn lte_only() -> Self {
         Self::new(
@@ -107,9 +241,83 @@ impl NetworkModeRequest {
 }
 
 impl CurrentPlmn {
-    /// Get operator name (full name if available, otherwise short name)
+    /// Get operator name: the full name if available, otherwise the short
+    /// name, otherwise the built-in [`plmn`](super::plmn) table entry for
+    /// the numeric code
     pub fn operator_name(&self) -> Option<&str> {
-        self.full_name.as_deref().or(self.short_name.as_deref())
+        [&self.full_name, &self.short_name]
+            .into_iter()
+            .find_map(|name| name.as_deref().filter(|n| !n.trim().is_empty()))
+            .or_else(|| self.numeric.as_deref().and_then(plmn::operator_name))
+    }
+
+    /// Typed view of the raw `State` value
+    pub fn plmn_state(&self) -> PlmnState {
+        PlmnState::from_raw(&self.state)
+    }
+
+    /// Check if the device is registered with this operator
+    pub fn is_registered(&self) -> bool {
+        self.plmn_state().is_registered()
+    }
+}
+
+impl PlmnNetwork {
+    /// Get operator name (full name if available, otherwise short name)
+    pub fn operator_name(&self) -> &str {
+        if self.full_name.is_empty() {
+            &self.short_name
+        } else {
+            &self.full_name
+        }
+    }
+
+    /// Typed view of the raw `State` value
+    pub fn plmn_state(&self) -> PlmnState {
+        PlmnState::from_raw(&self.state)
+    }
+
+    /// Check if the device may register with this operator
+    pub fn is_available(&self) -> bool {
+        matches!(self.plmn_state(), PlmnState::Available | PlmnState::Registered)
+    }
+
+    /// Check if the device is currently registered with this operator
+    pub fn is_current(&self) -> bool {
+        self.plmn_state().is_registered()
+    }
+
+    /// Check if registration with this operator is forbidden
+    pub fn is_forbidden(&self) -> bool {
+        self.plmn_state() == PlmnState::Forbidden
+    }
+
+    /// Get the radio access technology as human-readable string
+    pub fn rat_text(&self) -> &'static str {
+        match self.rat.as_str() {
+            "0" => "2G",
+            "2" => "3G",
+            "7" => "4G",
+            _ => "Unknown",
+        }
+    }
+}
+
+impl PlmnList {
+    /// Get the operators found by the scan
+    pub fn networks(&self) -> &[PlmnNetwork] {
+        &self.networks.networks
+    }
+}
+
+impl PlmnRegisterRequest {
+    /// Create a registration request for the given operator and radio technology
+    pub fn new(numeric: impl Into<String>, rat: impl Into<String>, mode: PlmnSelectionMode) -> Self {
+        Self {
+            mode,
+            plmn: numeric.into(),
+            rat: rat.into(),
+        }
     }
 }
 
@@ -130,6 +338,39 @@ mod tests {
         assert!(!mode.is_auto());
     }
 
+    #[test]
+    fn test_current_plmn_state() {
+        let xml = "<response><State>2</State><FullName>Orange</FullName><Numeric>26003</Numeric></response>";
+        let plmn: CurrentPlmn = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(plmn.plmn_state(), PlmnState::Registered);
+        assert!(plmn.is_registered());
+
+        for (raw, state) in [
+            ("0", PlmnState::Unknown),
+            ("1", PlmnState::Available),
+            ("3", PlmnState::Forbidden),
+            ("7", PlmnState::Other("7".to_string())),
+        ] {
+            let plmn = CurrentPlmn { state: raw.to_string(), ..plmn.clone() };
+            assert_eq!(plmn.plmn_state(), state);
+            assert!(!plmn.is_registered());
+        }
+    }
+
+    #[test]
+    fn test_current_plmn_operator_name_fallback() {
+        let xml = "<response><State>0</State><FullName></FullName><ShortName></ShortName>\
+<Numeric>26001</Numeric><Rat>7</Rat></response>";
+        let current: CurrentPlmn = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(current.operator_name(), Some("Plus"));
+
+        let short_only = CurrentPlmn { short_name: Some("Plus PL".to_string()), ..current.clone() };
+        assert_eq!(short_only.operator_name(), Some("Plus PL"));
+
+        let unknown = CurrentPlmn { numeric: Some("99999".to_string()), ..current };
+        assert_eq!(unknown.operator_name(), None);
+    }
+
     #[test]
     fn test_request_creation() {
         let request = NetworkModeRequest::lte_only();
@@ -137,4 +378,144 @@ mod tests {
         assert_eq!(request.network_band, "3fffffff");
         assert_eq!(request.lte_band, "80800C5");
     }
+
+    #[test]
+    fn test_band_mask() {
+        let mask = BandMask::from_bands(&[LteBand::B3, LteBand::B7, LteBand::B20]);
+        assert_eq!(mask.to_hex(), "80044");
+        assert!(mask.contains(LteBand::B7));
+        assert!(!mask.contains(LteBand::B1));
+
+        let parsed = BandMask::from_hex(&mask.to_hex()).unwrap();
+        assert_eq!(parsed, mask);
+        assert_eq!(parsed.bands(), vec![LteBand::B3, LteBand::B7, LteBand::B20]);
+
+        let default = BandMask::from_hex("80800c5").unwrap();
+        assert_eq!(
+            default.bands(),
+            vec![LteBand::B1, LteBand::B3, LteBand::B7, LteBand::B8, LteBand::B20, LteBand::B28]
+        );
+        assert!(BandMask::from_hex("not hex").is_none());
+    }
+
+    #[test]
+    fn test_network_mode_band_accessors() {
+        let mode = NetworkMode {
+            network_mode: NetworkModeType::Auto,
+            network_band: "3FFFFFFF".to_string(),
+            lte_band: "80800c5".to_string(),
+        };
+        assert_eq!(
+            mode.lte_bands(),
+            vec![LteBand::B1, LteBand::B3, LteBand::B7, LteBand::B8, LteBand::B20, LteBand::B28]
+        );
+        assert_eq!(
+            mode.network_bands(),
+            vec![
+                NetworkBand::Gsm1800,
+                NetworkBand::Egsm900,
+                NetworkBand::Pgsm900,
+                NetworkBand::Gsm850,
+                NetworkBand::Gsm1900,
+                NetworkBand::WcdmaB1,
+                NetworkBand::WcdmaB2,
+                NetworkBand::WcdmaB4,
+                NetworkBand::WcdmaB5,
+            ]
+        );
+
+        let mode = NetworkMode {
+            network_band: "0x2000000400180".to_string(),
+            lte_band: "bogus".to_string(),
+            ..mode
+        };
+        assert_eq!(
+            mode.network_bands(),
+            vec![NetworkBand::Gsm1800, NetworkBand::Egsm900, NetworkBand::WcdmaB1, NetworkBand::WcdmaB8]
+        );
+        assert!(mode.lte_bands().is_empty());
+    }
+
+    #[test]
+    fn test_band_mask_wider_than_128_bits() {
+        let mask = BandMask::from_hex("100000000000000000020000000000000004").unwrap();
+        assert_eq!(mask.bands(), vec![LteBand::B3, LteBand::B66]);
+
+        let upper = BandMask::from_hex("7FFFFFFFFFFFFFFF").unwrap();
+        assert_eq!(upper, BandMask::from_hex("7fffffffffffffff").unwrap());
+        assert!(BandMask::from_hex("").is_none());
+    }
+
+    #[test]
+    fn test_request_with_band_mask() {
+        let mask = BandMask::from_bands(&[LteBand::B3, LteBand::B7, LteBand::B20]);
+        let request =
+            NetworkModeRequest::with_band_mask(NetworkModeType::FourGOnly, "3fffffff".to_string(), mask);
+        assert_eq!(request.lte_band, "80044");
+
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+        assert!(xml.contains(
+            "<request><NetworkMode>03</NetworkMode><NetworkBand>3fffffff</NetworkBand><LTEBand>80044</LTEBand></request>"
+        ));
+    }
+
+    #[test]
+    fn test_plmn_list_parsing() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<Networks>
+<Network>
+<Index>0</Index>
+<State>2</State>
+<FullName>Plus</FullName>
+<ShortName>Plus</ShortName>
+<Numeric>26001</Numeric>
+<Rat>7</Rat>
+</Network>
+<Network>
+<Index>1</Index>
+<State>1</State>
+<FullName></FullName>
+<ShortName>T-Mobile.pl</ShortName>
+<Numeric>26002</Numeric>
+<Rat>2</Rat>
+</Network>
+<Network>
+<Index>2</Index>
+<State>3</State>
+<FullName>Orange PL</FullName>
+<ShortName>Orange</ShortName>
+<Numeric>26003</Numeric>
+<Rat>0</Rat>
+</Network>
+</Networks>
+</response>"#;
+
+        let list: PlmnList = serde_xml_rs::from_str(xml).unwrap();
+        let networks = list.networks();
+        assert_eq!(networks.len(), 3);
+
+        assert_eq!(networks[0].operator_name(), "Plus");
+        assert_eq!(networks[0].plmn_state(), PlmnState::Registered);
+        assert!(networks[0].is_current());
+        assert_eq!(networks[0].rat_text(), "4G");
+
+        assert_eq!(networks[1].operator_name(), "T-Mobile.pl");
+        assert_eq!(networks[1].numeric, "26002");
+        assert!(networks[1].is_available());
+        assert!(!networks[1].is_current());
+
+        assert!(networks[2].is_forbidden());
+        assert!(!networks[2].is_available());
+        assert_eq!(networks[2].rat_text(), "2G");
+    }
+
+    #[test]
+    fn test_plmn_register_request_serialization() {
+        let request = PlmnRegisterRequest::new("26001", "7", PlmnSelectionMode::Manual);
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+        assert!(xml.contains("<Mode>1</Mode>"));
+        assert!(xml.contains("<Plmn>26001</Plmn>"));
+        assert!(xml.contains("<Rat>7</Rat>"));
+    }
 }
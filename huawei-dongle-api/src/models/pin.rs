@@ -0,0 +1,212 @@
+//! SIM PIN/PUK models
+
+use serde::{Deserialize, Serialize};
+
+/// PIN status response from `/api/pin/status`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct PinStatus {
+    /// SIM state (255=no SIM, 257=ready, 258=PIN disabled, 259=PIN validated,
+    /// 260=PIN required, 261=PUK required)
+    #[serde(rename = "SimState")]
+    pub sim_state: String,
+
+    /// Whether PIN verification is enabled
+    #[serde(rename = "PinOptState", default)]
+    pub pin_opt_state: Option<String>,
+
+    /// Remaining PIN attempts
+    #[serde(rename = "SimPinTimes")]
+    pub sim_pin_times: String,
+
+    /// Remaining PUK attempts
+    #[serde(rename = "SimPukTimes")]
+    pub sim_puk_times: String,
+}
+
+/// PIN operation types for `/api/pin/operate`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PinOperateType {
+    Enter,
+    Enable,
+    Disable,
+    Change,
+    UnlockPuk,
+}
+
+impl Serialize for PinOperateType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let value = match self {
+            PinOperateType::Enter => 0,
+            PinOperateType::Enable => 1,
+            PinOperateType::Disable => 2,
+            PinOperateType::Change => 3,
+            PinOperateType::UnlockPuk => 4,
+        };
+        serializer.serialize_i32(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for PinOperateType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = i32::deserialize(deserializer)?;
+        match value {
+            0 => Ok(PinOperateType::Enter),
+            1 => Ok(PinOperateType::Enable),
+            2 => Ok(PinOperateType::Disable),
+            3 => Ok(PinOperateType::Change),
+            4 => Ok(PinOperateType::UnlockPuk),
+            _ => Err(serde::de::Error::custom(format!("Invalid PIN operate type: {}", value))),
+        }
+    }
+}
+
+/// PIN operation request for `/api/pin/operate`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct PinOperateRequest {
+    #[serde(rename = "OperateType")]
+    pub operate_type: PinOperateType,
+
+    #[serde(rename = "CurrentPin")]
+    pub current_pin: String,
+
+    #[serde(rename = "NewPin")]
+    pub new_pin: String,
+
+    #[serde(rename = "PukCode")]
+    pub puk_code: String,
+}
+
+impl PinStatus {
+    /// Check if no SIM card is inserted
+    pub fn is_sim_missing(&self) -> bool {
+        self.sim_state.trim() == "255"
+    }
+
+    /// Check if the SIM is unlocked and ready
+    pub fn is_ready(&self) -> bool {
+        matches!(self.sim_state.trim(), "257" | "258" | "259")
+    }
+
+    /// Check if the SIM is waiting for a PIN
+    pub fn is_pin_required(&self) -> bool {
+        self.sim_state.trim() == "260"
+    }
+
+    /// Check if the SIM is waiting for a PUK
+    pub fn is_puk_required(&self) -> bool {
+        self.sim_state.trim() == "261"
+    }
+
+    /// Get remaining PIN attempts
+    pub fn pin_attempts_left(&self) -> Option<u32> {
+        self.sim_pin_times.trim().parse().ok()
+    }
+
+    /// Get remaining PUK attempts
+    pub fn puk_attempts_left(&self) -> Option<u32> {
+        self.sim_puk_times.trim().parse().ok()
+    }
+}
+
+impl PinOperateRequest {
+    fn new(operate_type: PinOperateType, current_pin: &str, new_pin: &str, puk_code: &str) -> Self {
+        Self {
+            operate_type,
+            current_pin: current_pin.to_string(),
+            new_pin: new_pin.to_string(),
+            puk_code: puk_code.to_string(),
+        }
+    }
+
+    /// Create a request that unlocks the SIM with its PIN
+    pub fn enter(pin: &str) -> Self {
+        Self::new(PinOperateType::Enter, pin, "", "")
+    }
+
+    /// Create a request that enables PIN verification
+    pub fn enable(pin: &str) -> Self {
+        Self::new(PinOperateType::Enable, pin, "", "")
+    }
+
+    /// Create a request that disables PIN verification
+    pub fn disable(pin: &str) -> Self {
+        Self::new(PinOperateType::Disable, pin, "", "")
+    }
+
+    /// Create a request that changes the PIN
+    pub fn change(current_pin: &str, new_pin: &str) -> Self {
+        Self::new(PinOperateType::Change, current_pin, new_pin, "")
+    }
+
+    /// Create a request that unlocks the SIM with its PUK and sets a new PIN
+    pub fn unlock_puk(puk: &str, new_pin: &str) -> Self {
+        Self::new(PinOperateType::UnlockPuk, "", new_pin, puk)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pin_status_parsing() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<SimState>260</SimState>
+<PinOptState>258</PinOptState>
+<SimPinTimes>3</SimPinTimes>
+<SimPukTimes>10</SimPukTimes>
+</response>"#;
+
+        let status: PinStatus = serde_xml_rs::from_str(xml).unwrap();
+        assert!(status.is_pin_required());
+        assert!(!status.is_ready());
+        assert_eq!(status.pin_attempts_left(), Some(3));
+        assert_eq!(status.puk_attempts_left(), Some(10));
+    }
+
+    #[test]
+    fn test_enter_pin_request() {
+        let xml = serde_xml_rs::to_string(&PinOperateRequest::enter("1234")).unwrap();
+        assert!(xml.contains("<OperateType>0</OperateType>"));
+        assert!(xml.contains("<CurrentPin>1234</CurrentPin>"));
+    }
+
+    #[test]
+    fn test_enable_pin_request() {
+        let xml = serde_xml_rs::to_string(&PinOperateRequest::enable("1234")).unwrap();
+        assert!(xml.contains("<OperateType>1</OperateType>"));
+        assert!(xml.contains("<CurrentPin>1234</CurrentPin>"));
+    }
+
+    #[test]
+    fn test_disable_pin_request() {
+        let xml = serde_xml_rs::to_string(&PinOperateRequest::disable("1234")).unwrap();
+        assert!(xml.contains("<OperateType>2</OperateType>"));
+        assert!(xml.contains("<CurrentPin>1234</CurrentPin>"));
+    }
+
+    #[test]
+    fn test_change_pin_request() {
+        let xml = serde_xml_rs::to_string(&PinOperateRequest::change("1234", "4321")).unwrap();
+        assert!(xml.contains("<OperateType>3</OperateType>"));
+        assert!(xml.contains("<CurrentPin>1234</CurrentPin>"));
+        assert!(xml.contains("<NewPin>4321</NewPin>"));
+    }
+
+    #[test]
+    fn test_unlock_puk_request() {
+        let xml = serde_xml_rs::to_string(&PinOperateRequest::unlock_puk("12345678", "0000")).unwrap();
+        assert!(xml.contains("<OperateType>4</OperateType>"));
+        assert!(xml.contains("<NewPin>0000</NewPin>"));
+        assert!(xml.contains("<PukCode>12345678</PukCode>"));
+    }
+}
@@ -0,0 +1,58 @@
+//! Operator names by PLMN code
+//!
+//! Some firmware leaves `FullName` and `ShortName` blank in
+//! `/api/net/current-plmn` while still reporting the numeric MCC+MNC. The
+//! table below covers common operators so a name can still be shown.
+
+/// Operator names keyed by MCC+MNC, sorted by code for binary search
+const OPERATORS: &[(&str, &str)] = &[
+    ("20201", "Cosmote"), ("20205", "Vodafone"), ("20404", "Vodafone"),
+    ("20408", "KPN"), ("20416", "Odido"), ("20601", "Proximus"),
+    ("20610", "Orange"), ("20801", "Orange"), ("20810", "SFR"),
+    ("20815", "Free Mobile"), ("20820", "Bouygues Telecom"), ("21401", "Vodafone"),
+    ("21403", "Orange"), ("21407", "Movistar"), ("22201", "TIM"),
+    ("22210", "Vodafone"), ("22288", "WindTre"), ("22801", "Swisscom"),
+    ("22802", "Sunrise"), ("23001", "T-Mobile"), ("23002", "O2"),
+    ("23003", "Vodafone"), ("23201", "A1"), ("23203", "Magenta"),
+    ("23410", "O2"), ("23415", "Vodafone"), ("23420", "Three"),
+    ("23430", "EE"), ("24001", "Telia"), ("24201", "Telenor"),
+    ("24405", "Elisa"), ("24491", "Telia"), ("26001", "Plus"),
+    ("26002", "T-Mobile"), ("26003", "Orange"), ("26006", "Play"),
+    ("26201", "Telekom"), ("26202", "Vodafone"), ("26203", "O2"),
+    ("27201", "Vodafone"), ("302720", "Rogers"), ("310260", "T-Mobile"),
+    ("310410", "AT&T"), ("311480", "Verizon"), ("44010", "NTT docomo"),
+    ("50501", "Telstra"),
+];
+
+/// Look up the operator name for a numeric PLMN code such as `"26001"`
+pub fn operator_name(numeric: &str) -> Option<&'static str> {
+    let numeric = numeric.trim();
+    OPERATORS
+        .binary_search_by(|(code, _)| (*code).cmp(numeric))
+        .ok()
+        .map(|i| OPERATORS[i].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operators_sorted() {
+        assert!(OPERATORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_known_operators() {
+        assert_eq!(operator_name("26001"), Some("Plus"));
+        assert_eq!(operator_name("26006"), Some("Play"));
+        assert_eq!(operator_name(" 23430 "), Some("EE"));
+        assert_eq!(operator_name("310410"), Some("AT&T"));
+    }
+
+    #[test]
+    fn test_unknown_operator() {
+        assert_eq!(operator_name("99999"), None);
+        assert_eq!(operator_name(""), None);
+    }
+}
@@ -0,0 +1,248 @@
+//! Security (firewall and port forwarding) models
+
+use serde::{Deserialize, Serialize};
+use std::net::Ipv4Addr;
+use super::common::flag;
+use super::Protocol;
+
+/// Port forwarding rule from `/api/security/virtual-servers`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename = "Server")]
+pub struct VirtualServer {
+    #[serde(rename = "VsName")]
+    pub name: String,
+
+    /// External port on the WAN side
+    #[serde(rename = "WanPort")]
+    pub wan_port: u16,
+
+    /// Port on the LAN host
+    #[serde(rename = "LanPort")]
+    pub lan_port: u16,
+
+    /// LAN host receiving the forwarded traffic
+    #[serde(rename = "LanIp")]
+    pub lan_ip: String,
+
+    #[serde(rename = "Protocol")]
+    pub protocol: Protocol,
+
+    /// Rule state (1=enabled, 0=disabled)
+    #[serde(rename = "Enable")]
+    pub enable: String,
+}
+
+impl VirtualServer {
+    /// Create a new enabled forwarding rule
+    pub fn new(name: &str, wan_port: u16, lan_port: u16, lan_ip: &str, protocol: Protocol) -> Self {
+        Self {
+            name: name.to_string(),
+            wan_port,
+            lan_port,
+            lan_ip: lan_ip.to_string(),
+            protocol,
+            enable: "1".to_string(),
+        }
+    }
+
+    /// Check if the rule is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.enable.trim() == "1"
+    }
+}
+
+/// Port forwarding rule list response from `/api/security/virtual-servers`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct VirtualServerList {
+    #[serde(rename = "Server", default)]
+    pub servers: Vec<VirtualServer>,
+}
+
+/// Port forwarding rule list request for `/api/security/virtual-servers`
+///
+/// The device replaces the whole table, so the request must carry every
+/// rule that should remain. serde-xml-rs cannot serialize repeated
+/// struct elements, so this request is serialized with `quick_xml::se`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct VirtualServerListRequest {
+    #[serde(rename = "Server", default)]
+    pub servers: Vec<VirtualServer>,
+}
+
+impl VirtualServerListRequest {
+    /// Create a request replacing the table with `servers`
+    pub fn new(servers: &[VirtualServer]) -> Self {
+        Self {
+            servers: servers.to_vec(),
+        }
+    }
+}
+
+/// DMZ host settings from `/api/security/dmz`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct DmzSettings {
+    #[serde(rename = "DmzStatus", with = "flag")]
+    pub enabled: bool,
+
+    /// LAN host receiving all unsolicited inbound traffic
+    #[serde(rename = "DmzIPAddress", default)]
+    pub host_ip: String,
+}
+
+/// DMZ host request for `/api/security/dmz`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct DmzRequest {
+    #[serde(rename = "DmzStatus", with = "flag")]
+    pub enabled: bool,
+
+    #[serde(rename = "DmzIPAddress")]
+    pub host_ip: String,
+}
+
+impl DmzRequest {
+    /// Create a new DMZ request
+    pub fn new(enabled: bool, host_ip: Ipv4Addr) -> Self {
+        Self {
+            enabled,
+            host_ip: host_ip.to_string(),
+        }
+    }
+}
+
+/// Firewall switches from `/api/security/firewall-switch`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct FirewallSwitch {
+    /// Master switch; the other filters only apply while it is on
+    #[serde(rename = "FirewallMainSwitch", with = "flag")]
+    pub firewall: bool,
+
+    #[serde(rename = "FirewallIPFilterSwitch", with = "flag", default)]
+    pub ip_filter: bool,
+
+    /// Whether the device answers ping on its WAN address
+    #[serde(rename = "FirewallWanPortPingSwitch", with = "flag", default)]
+    pub wan_ping: bool,
+}
+
+/// Firewall switch request for `/api/security/firewall-switch`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct FirewallSwitchRequest {
+    #[serde(rename = "FirewallMainSwitch", with = "flag")]
+    pub firewall: bool,
+
+    #[serde(rename = "FirewallIPFilterSwitch", with = "flag")]
+    pub ip_filter: bool,
+
+    #[serde(rename = "FirewallWanPortPingSwitch", with = "flag")]
+    pub wan_ping: bool,
+}
+
+impl FirewallSwitchRequest {
+    /// Create a new firewall switch request
+    pub fn new(firewall: bool, ip_filter: bool, wan_ping: bool) -> Self {
+        Self {
+            firewall,
+            ip_filter,
+            wan_ping,
+        }
+    }
+}
+
+impl From<&FirewallSwitch> for FirewallSwitchRequest {
+    fn from(current: &FirewallSwitch) -> Self {
+        Self::new(current.firewall, current.ip_filter, current.wan_ping)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_virtual_server_tcp_serialization() {
+        let rule = VirtualServer::new("ssh", 2222, 22, "192.168.8.10", Protocol::Tcp);
+        let request = VirtualServerListRequest::new(&[rule]);
+        let xml = quick_xml::se::to_string(&request).unwrap();
+
+        assert_eq!(
+            xml,
+            "<request><Server><VsName>ssh</VsName><WanPort>2222</WanPort>\
+<LanPort>22</LanPort><LanIp>192.168.8.10</LanIp><Protocol>6</Protocol>\
+<Enable>1</Enable></Server></request>"
+        );
+    }
+
+    #[test]
+    fn test_virtual_server_list_parsing() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<Server>
+<VsName>ssh</VsName>
+<WanPort>2222</WanPort>
+<LanPort>22</LanPort>
+<LanIp>192.168.8.10</LanIp>
+<Protocol>6</Protocol>
+<Enable>1</Enable>
+</Server>
+<Server>
+<VsName>game</VsName>
+<WanPort>27015</WanPort>
+<LanPort>27015</LanPort>
+<LanIp>192.168.8.20</LanIp>
+<Protocol>0</Protocol>
+<Enable>0</Enable>
+</Server>
+</response>"#;
+        let list: VirtualServerList = serde_xml_rs::from_str(xml).unwrap();
+
+        assert_eq!(list.servers.len(), 2);
+        assert_eq!(list.servers[0].protocol, Protocol::Tcp);
+        assert!(list.servers[0].is_enabled());
+        assert_eq!(list.servers[1].protocol, Protocol::Both);
+        assert!(!list.servers[1].is_enabled());
+
+        let empty: VirtualServerList = serde_xml_rs::from_str("<response></response>").unwrap();
+        assert!(empty.servers.is_empty());
+    }
+
+    #[test]
+    fn test_firewall_switch_request_mixed_flags() {
+        let request = FirewallSwitchRequest::new(true, false, true);
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+
+        assert!(xml.contains("<FirewallMainSwitch>1</FirewallMainSwitch>"));
+        assert!(xml.contains("<FirewallIPFilterSwitch>0</FirewallIPFilterSwitch>"));
+        assert!(xml.contains("<FirewallWanPortPingSwitch>1</FirewallWanPortPingSwitch>"));
+    }
+
+    #[test]
+    fn test_firewall_switch_parsing() {
+        let xml = "<response><FirewallMainSwitch>1</FirewallMainSwitch>\
+<FirewallIPFilterSwitch>1</FirewallIPFilterSwitch>\
+<FirewallWanPortPingSwitch>0</FirewallWanPortPingSwitch>\
+<firewallmacfilterswitch>0</firewallmacfilterswitch></response>";
+        let switch: FirewallSwitch = serde_xml_rs::from_str(xml).unwrap();
+
+        assert!(switch.firewall);
+        assert!(switch.ip_filter);
+        assert!(!switch.wan_ping);
+
+        let request = FirewallSwitchRequest { wan_ping: true, ..FirewallSwitchRequest::from(&switch) };
+        assert!(request.firewall && request.ip_filter && request.wan_ping);
+    }
+
+    #[test]
+    fn test_dmz_request_serialization() {
+        let request = DmzRequest::new(true, Ipv4Addr::new(192, 168, 8, 50));
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+
+        assert!(xml.contains("<DmzStatus>1</DmzStatus>"));
+        assert!(xml.contains("<DmzIPAddress>192.168.8.50</DmzIPAddress>"));
+    }
+}
@@ -1,5 +1,6 @@
 //! SMS management models
 
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use super::enums::{SmsStatus, SmsPriority, SmsType, SmsBoxType, SmsSortType};
 
@@ -50,6 +51,14 @@ pub struct SmsCount {
 
     #[serde(rename = "NewMsg")]
     pub new_msg: String,
+
+    /// Local storage capacity, on firmware that reports it
+    #[serde(rename = "LocalMax", default)]
+    pub local_max: Option<String>,
+
+    /// SIM storage capacity, on firmware that reports it
+    #[serde(rename = "SimMax", default)]
+    pub sim_max: Option<String>,
 }
 
 /// SMS list request for `/api/sms/sms-list`
@@ -129,7 +138,7 @@ impl SmsListResponse {
     /// Get the message count, either from the Count field or by counting messages
     pub fn message_count(&self) -> usize {
         if let Some(count_str) = &self.count {
-            count_str.parse().unwrap_or_else(|_| self.messages.messages.len())
+            count_str.parse().unwrap_or(self.messages.messages.len())
         } else {
             self.messages.messages.len()
         }
@@ -144,6 +153,197 @@ pub struct SmsDeleteRequest {
     pub index: String,
 }
 
+/// Batch delete request for `/api/sms/delete-sms`, serialized as repeated `<Index>` elements
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct SmsDeleteManyRequest {
+    #[serde(rename = "Index", default)]
+    pub indices: Vec<String>,
+}
+
+/// Request moving messages to another box, serialized as repeated `<Index>`
+/// elements followed by the target `<BoxType>`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct SmsMoveRequest {
+    #[serde(rename = "Index", default)]
+    pub indices: Vec<String>,
+
+    #[serde(rename = "BoxType")]
+    pub box_type: String,
+}
+
+impl SmsMoveRequest {
+    pub fn new(message_ids: &[&str], target: SmsBoxType) -> Self {
+        Self {
+            indices: message_ids.iter().map(|id| id.to_string()).collect(),
+            box_type: target.to_string(),
+        }
+    }
+}
+
+/// Recipient list for [`SmsSendRequest`], serialized as repeated `<Phone>` elements
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmsPhones {
+    #[serde(rename = "Phone", default)]
+    pub phones: Vec<String>,
+}
+
+/// SMS send request for `/api/sms/send-sms`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct SmsSendRequest {
+    /// Message index (-1 for a new message)
+    #[serde(rename = "Index")]
+    pub index: String,
+
+    #[serde(rename = "Phones")]
+    pub phones: SmsPhones,
+
+    #[serde(rename = "Sca")]
+    pub sca: String,
+
+    #[serde(rename = "Content")]
+    pub content: String,
+
+    /// Content length in UTF-16 code units
+    #[serde(rename = "Length")]
+    pub length: String,
+
+    /// Encoding type (1=GSM-7, 5=UCS2)
+    #[serde(rename = "Reserved")]
+    pub reserved: String,
+
+    #[serde(rename = "Date")]
+    pub date: String,
+}
+
+/// SMS send status response from `/api/sms/send-status`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct SmsSendStatus {
+    /// Recipients still pending
+    #[serde(rename = "Phone", default)]
+    pub phone: Option<String>,
+
+    /// Recipients the message was delivered to
+    #[serde(rename = "SucPhone", default)]
+    pub suc_phone: Option<String>,
+
+    /// Recipients the message could not be sent to
+    #[serde(rename = "FailPhone", default)]
+    pub fail_phone: Option<String>,
+
+    #[serde(rename = "TotalCount", default)]
+    pub total_count: Option<String>,
+
+    #[serde(rename = "CurIndex", default)]
+    pub cur_index: Option<String>,
+}
+
+/// SMS settings from `/api/sms/config`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct SmsConfig {
+    #[serde(rename = "SaveMode", default)]
+    pub save_mode: Option<String>,
+
+    #[serde(rename = "Validity", default)]
+    pub validity: Option<String>,
+
+    /// SMS center (SMSC) number, blank on some fresh SIMs
+    #[serde(rename = "Sca", default)]
+    pub sca: Option<String>,
+
+    #[serde(rename = "UseSReport", default)]
+    pub use_s_report: Option<String>,
+
+    #[serde(rename = "SendType", default)]
+    pub send_type: Option<String>,
+
+    #[serde(rename = "Priority", default)]
+    pub priority: Option<String>,
+
+    #[serde(rename = "CdmaModeSelected", default)]
+    pub cdma_mode_selected: Option<String>,
+
+    #[serde(rename = "cbsenable", default)]
+    pub cbs_enable: Option<String>,
+
+    #[serde(rename = "cbschannellist", default)]
+    pub cbs_channel_list: Option<String>,
+
+    #[serde(rename = "pagesize", default)]
+    pub page_size: Option<String>,
+
+    #[serde(rename = "maxphone", default)]
+    pub max_phone: Option<String>,
+}
+
+impl SmsConfig {
+    /// SMS center number, if one is configured
+    pub fn center_number(&self) -> Option<&str> {
+        self.sca.as_deref().map(str::trim).filter(|sca| !sca.is_empty())
+    }
+}
+
+/// Update request for `/api/sms/config`. Built from the current
+/// [`SmsConfig`] so fields that are not changed are written back as read.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct SmsConfigRequest {
+    #[serde(rename = "SaveMode", skip_serializing_if = "Option::is_none")]
+    pub save_mode: Option<String>,
+
+    #[serde(rename = "Validity", skip_serializing_if = "Option::is_none")]
+    pub validity: Option<String>,
+
+    #[serde(rename = "Sca", skip_serializing_if = "Option::is_none")]
+    pub sca: Option<String>,
+
+    #[serde(rename = "UseSReport", skip_serializing_if = "Option::is_none")]
+    pub use_s_report: Option<String>,
+
+    #[serde(rename = "SendType", skip_serializing_if = "Option::is_none")]
+    pub send_type: Option<String>,
+
+    #[serde(rename = "Priority", skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+
+    #[serde(rename = "CdmaModeSelected", skip_serializing_if = "Option::is_none")]
+    pub cdma_mode_selected: Option<String>,
+
+    #[serde(rename = "cbsenable", skip_serializing_if = "Option::is_none")]
+    pub cbs_enable: Option<String>,
+
+    #[serde(rename = "cbschannellist", skip_serializing_if = "Option::is_none")]
+    pub cbs_channel_list: Option<String>,
+}
+
+impl From<&SmsConfig> for SmsConfigRequest {
+    fn from(config: &SmsConfig) -> Self {
+        Self {
+            save_mode: config.save_mode.clone(),
+            validity: config.validity.clone(),
+            sca: config.sca.clone(),
+            use_s_report: config.use_s_report.clone(),
+            send_type: config.send_type.clone(),
+            priority: config.priority.clone(),
+            cdma_mode_selected: config.cdma_mode_selected.clone(),
+            cbs_enable: config.cbs_enable.clone(),
+            cbs_channel_list: config.cbs_channel_list.clone(),
+        }
+    }
+}
+
+impl SmsConfigRequest {
+    /// Replace the SMS center number
+    pub fn with_center_number<S: Into<String>>(mut self, number: S) -> Self {
+        self.sca = Some(number.into());
+        self
+    }
+}
+
 /// SMS set read request for `/api/sms/set-read`
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename = "request")]
@@ -168,10 +368,47 @@ impl SmsCount {
         Ok(local + sim)
     }
 
+    /// Number of messages stored in `box_type`
+    pub fn box_count(&self, box_type: SmsBoxType) -> Result<u32, std::num::ParseIntError> {
+        let count = match box_type {
+            SmsBoxType::LocalInbox => &self.local_inbox,
+            SmsBoxType::LocalOutbox => &self.local_outbox,
+            SmsBoxType::LocalDraft => &self.local_draft,
+            SmsBoxType::SimInbox => &self.sim_inbox,
+            SmsBoxType::SimOutbox => &self.sim_outbox,
+            SmsBoxType::SimDraft => &self.sim_draft,
+        };
+        count.trim().parse()
+    }
+
     /// Check if there are new messages
     pub fn has_new_messages(&self) -> bool {
         self.new_msg.parse::<u32>().unwrap_or(0) > 0
     }
+
+    /// Check if the storage holding `box_type` is full. Inbox, outbox and
+    /// drafts share the storage; `false` if the firmware reports no capacity.
+    pub fn is_full(&self, box_type: SmsBoxType) -> bool {
+        let (max, boxes) = match box_type {
+            SmsBoxType::LocalInbox | SmsBoxType::LocalOutbox | SmsBoxType::LocalDraft => (
+                &self.local_max,
+                [&self.local_inbox, &self.local_outbox, &self.local_draft],
+            ),
+            SmsBoxType::SimInbox | SmsBoxType::SimOutbox | SmsBoxType::SimDraft => (
+                &self.sim_max,
+                [&self.sim_inbox, &self.sim_outbox, &self.sim_draft],
+            ),
+        };
+
+        let Some(max) = max.as_deref().and_then(|max| max.trim().parse::<u32>().ok()) else {
+            return false;
+        };
+        let used: u32 = boxes
+            .iter()
+            .map(|count| count.trim().parse::<u32>().unwrap_or(0))
+            .sum();
+        max > 0 && used >= max
+    }
 }
 
 impl SmsListRequest {
@@ -193,6 +430,82 @@ impl SmsListRequest {
             unread_preferred: if unread_preferred { "1" } else { "0" }.to_string(),
         }
     }
+
+    /// Start building a request. Defaults: page 1, 20 messages, local inbox,
+    /// sorted by time, descending, no unread preference.
+    pub fn builder() -> SmsListRequestBuilder {
+        SmsListRequestBuilder::default()
+    }
+}
+
+/// Builder for [`SmsListRequest`]
+#[derive(Debug, Clone, Copy)]
+pub struct SmsListRequestBuilder {
+    page: u32,
+    count: u32,
+    box_type: SmsBoxType,
+    sort: SmsSortType,
+    ascending: bool,
+    unread_preferred: bool,
+}
+
+impl Default for SmsListRequestBuilder {
+    fn default() -> Self {
+        Self {
+            page: 1,
+            count: 20,
+            box_type: SmsBoxType::LocalInbox,
+            sort: SmsSortType::ByTime,
+            ascending: false,
+            unread_preferred: false,
+        }
+    }
+}
+
+impl SmsListRequestBuilder {
+    /// Page index, starting at 1
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Messages per page
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = count;
+        self
+    }
+
+    pub fn box_type(mut self, box_type: SmsBoxType) -> Self {
+        self.box_type = box_type;
+        self
+    }
+
+    pub fn sort(mut self, sort: SmsSortType) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    pub fn ascending(mut self, ascending: bool) -> Self {
+        self.ascending = ascending;
+        self
+    }
+
+    /// List unread messages first
+    pub fn unread_preferred(mut self, unread_preferred: bool) -> Self {
+        self.unread_preferred = unread_preferred;
+        self
+    }
+
+    pub fn build(self) -> SmsListRequest {
+        SmsListRequest::new(
+            self.page,
+            self.count,
+            self.box_type,
+            self.sort,
+            self.ascending,
+            self.unread_preferred,
+        )
+    }
 }
 
 impl SmsMessage {
@@ -216,15 +529,112 @@ impl SmsMessage {
         &self.phone
     }
 
-    /// Get message text content
+    /// Get message text content as sent by the device
     pub fn text(&self) -> &str {
         &self.content
     }
 
+    /// Get the readable message text.
+    ///
+    /// `Unicode` messages carry their content as UCS2 hex, which is decoded
+    /// here; other types are returned unchanged. Content that is not valid
+    /// UCS2 hex is returned as is.
+    pub fn decoded_text(&self) -> String {
+        match self.sms_type {
+            SmsType::Unicode => {
+                decode_ucs2_hex(&self.content).unwrap_or_else(|| self.content.clone())
+            }
+            _ => self.content.clone(),
+        }
+    }
+
     /// Get formatted date
     pub fn date_str(&self) -> &str {
         &self.date
     }
+
+    /// Parse the date (device-local time), or `None` if malformed
+    pub fn datetime(&self) -> Option<NaiveDateTime> {
+        NaiveDateTime::parse_from_str(self.date.trim(), "%Y-%m-%d %H:%M:%S").ok()
+    }
+}
+
+/// Decode UCS2 (UTF-16BE) hex such as `"004800690021"`, or `None` if malformed
+fn decode_ucs2_hex(hex: &str) -> Option<String> {
+    let hex = hex.trim();
+    if hex.is_empty() || !hex.len().is_multiple_of(4) || !hex.is_ascii() {
+        return None;
+    }
+
+    let units = (0..hex.len())
+        .step_by(4)
+        .map(|i| u16::from_str_radix(&hex[i..i + 4], 16).ok())
+        .collect::<Option<Vec<u16>>>()?;
+    String::from_utf16(&units).ok()
+}
+
+/// GSM 03.38 default alphabet, one septet per character
+const GSM7_BASIC: &str = "@£$¥èéùìòÇ\nØø\rÅåΔ_ΦΓΛΩΠΨΣΘΞÆæßÉ !\"#¤%&'()*+,-./0123456789:;<=>?\
+¡ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÑÜ§¿abcdefghijklmnopqrstuvwxyzäöñüà";
+
+/// GSM 03.38 extension table, sent as an escape plus one septet
+const GSM7_EXTENDED: &str = "\u{0c}^{}\\[~]|€";
+
+/// Septets in a single GSM-7 message and in each part of a multi-part one
+const GSM7_SINGLE_LIMIT: usize = 160;
+const GSM7_PART_LIMIT: usize = 153;
+
+/// UTF-16 units in a single UCS2 message and in each part of a multi-part one
+const UCS2_SINGLE_LIMIT: usize = 70;
+const UCS2_PART_LIMIT: usize = 67;
+
+/// Septets needed for `c` in GSM-7, or `None` if it is not in the alphabet
+fn gsm7_septets(c: char) -> Option<usize> {
+    if GSM7_BASIC.contains(c) {
+        Some(1)
+    } else if GSM7_EXTENDED.contains(c) {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// Check if `content` can be sent with the GSM-7 encoding
+pub fn is_gsm7(content: &str) -> bool {
+    content.chars().all(|c| gsm7_septets(c).is_some())
+}
+
+/// Split `content` into the parts it takes as SMS messages
+///
+/// Content made only of GSM-7 characters fits 160 septets in one message or
+/// 153 per part, where extension characters such as `€` and `{` take two
+/// septets. Anything else is sent as UCS2, which fits 70 UTF-16 units in one
+/// message or 67 per part. Characters are never split across parts, so an
+/// emoji's surrogate pair stays together.
+pub fn split_message(content: &str) -> Vec<String> {
+    let (cost, single_limit, part_limit): (fn(char) -> usize, _, _) = if is_gsm7(content) {
+        (|c| gsm7_septets(c).unwrap_or(1), GSM7_SINGLE_LIMIT, GSM7_PART_LIMIT)
+    } else {
+        (char::len_utf16, UCS2_SINGLE_LIMIT, UCS2_PART_LIMIT)
+    };
+
+    if content.chars().map(cost).sum::<usize>() <= single_limit {
+        return vec![content.to_string()];
+    }
+
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut used = 0;
+    for c in content.chars() {
+        if used + cost(c) > part_limit {
+            parts.push(std::mem::take(&mut current));
+            used = 0;
+        }
+        current.push(c);
+        used += cost(c);
+    }
+    parts.push(current);
+    parts
 }
 
 impl SmsDeleteRequest {
@@ -236,6 +646,56 @@ impl SmsDeleteRequest {
     }
 }
 
+impl SmsDeleteManyRequest {
+    /// Create a new batch delete request
+    pub fn new(message_ids: &[&str]) -> Self {
+        Self {
+            indices: message_ids.iter().map(|id| id.to_string()).collect(),
+        }
+    }
+}
+
+impl SmsSendRequest {
+    /// GSM-7 encoding marker for the `Reserved` field
+    pub const ENCODING_GSM7: &'static str = "1";
+    /// UCS2 encoding marker for the `Reserved` field
+    pub const ENCODING_UCS2: &'static str = "5";
+
+    /// Create a new send request for the given recipients. Content outside
+    /// the GSM-7 alphabet is marked as UCS2.
+    pub fn new(phones: &[&str], content: &str) -> Self {
+        let encoding = if is_gsm7(content) {
+            Self::ENCODING_GSM7
+        } else {
+            Self::ENCODING_UCS2
+        };
+
+        Self {
+            index: "-1".to_string(),
+            phones: SmsPhones {
+                phones: phones.iter().map(|p| p.to_string()).collect(),
+            },
+            sca: String::new(),
+            content: content.to_string(),
+            length: content.encode_utf16().count().to_string(),
+            reserved: encoding.to_string(),
+            date: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        }
+    }
+}
+
+impl SmsSendStatus {
+    /// Check if the device is still sending to some recipients
+    pub fn is_pending(&self) -> bool {
+        self.phone.as_deref().is_some_and(|p| !p.trim().is_empty())
+    }
+
+    /// Check if sending failed for any recipient
+    pub fn is_failed(&self) -> bool {
+        self.fail_phone.as_deref().is_some_and(|p| !p.trim().is_empty())
+    }
+}
+
 impl SmsSetReadRequest {
     /// Create a new set read request
     pub fn new(message_id: &str) -> Self {
@@ -262,6 +722,8 @@ mod tests {
             sim_outbox: "3".to_string(),
             sim_draft: "0".to_string(),
             new_msg: "1".to_string(),
+            local_max: None,
+            sim_max: None,
         };
 
         assert_eq!(count.total_unread().unwrap(), 5);
@@ -308,6 +770,79 @@ mod tests {
         assert_eq!(request.unread_preferred, "1"); // unread preferred
     }
 
+    #[test]
+    fn test_sms_list_request_builder() {
+        let defaults = SmsListRequest::builder().build();
+        assert_eq!(defaults.page_index, "1");
+        assert_eq!(defaults.read_count, "20");
+        assert_eq!(defaults.box_type, "1");
+        assert_eq!(defaults.sort_type, "0");
+        assert_eq!(defaults.ascending, "0");
+        assert_eq!(defaults.unread_preferred, "0");
+
+        let request = SmsListRequest::builder()
+            .page(3)
+            .count(50)
+            .box_type(SmsBoxType::LocalOutbox)
+            .ascending(true)
+            .unread_preferred(true)
+            .build();
+        assert_eq!(request.page_index, "3");
+        assert_eq!(request.read_count, "50");
+        assert_eq!(request.box_type, SmsBoxType::LocalOutbox.to_string());
+        assert_eq!(request.ascending, "1");
+        assert_eq!(request.unread_preferred, "1");
+    }
+
+    #[test]
+    fn test_sms_send_request_single_recipient() {
+        let request = SmsSendRequest::new(&["+48123456789"], "Hello");
+
+        assert_eq!(request.index, "-1");
+        assert_eq!(request.length, "5");
+        assert_eq!(request.reserved, SmsSendRequest::ENCODING_GSM7);
+
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+        assert!(xml.contains("<Index>-1</Index>"));
+        assert!(xml.contains("<Phones><Phone>+48123456789</Phone></Phones>"));
+        assert!(xml.contains("<Content>Hello</Content>"));
+        assert!(xml.contains("<Length>5</Length>"));
+        assert!(xml.contains("<Reserved>1</Reserved>"));
+    }
+
+    #[test]
+    fn test_sms_send_request_multiple_recipients() {
+        let request = SmsSendRequest::new(&["111", "222", "333"], "Zażółć 😀");
+
+        assert_eq!(request.reserved, SmsSendRequest::ENCODING_UCS2);
+        assert_eq!(request.length, "9");
+
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+        assert!(xml.contains(
+            "<Phones><Phone>111</Phone><Phone>222</Phone><Phone>333</Phone></Phones>"
+        ));
+        assert!(xml.contains("<Reserved>5</Reserved>"));
+    }
+
+    #[test]
+    fn test_sms_send_status() {
+        let xml = r#"<response>
+    <Phone></Phone>
+    <SucPhone>+48123456789</SucPhone>
+    <FailPhone></FailPhone>
+    <TotalCount>1</TotalCount>
+    <CurIndex>1</CurIndex>
+</response>"#;
+
+        let status: SmsSendStatus = serde_xml_rs::from_str(xml).unwrap();
+        assert!(!status.is_pending());
+        assert!(!status.is_failed());
+
+        let xml = r#"<response><Phone></Phone><SucPhone></SucPhone><FailPhone>111</FailPhone><TotalCount>1</TotalCount><CurIndex>1</CurIndex></response>"#;
+        let status: SmsSendStatus = serde_xml_rs::from_str(xml).unwrap();
+        assert!(status.is_failed());
+    }
+
     #[test]
     fn test_sms_list_response_missing_count() {
         let xml_without_count = r#"<response>
@@ -400,4 +935,185 @@ mod tests {
         assert_eq!(response.messages.messages[1].phone, "3350");
         assert!(response.messages.messages[1].is_read());
     }
+
+    #[test]
+    fn test_sms_delete_many_request_serialization() {
+        let request = SmsDeleteManyRequest::new(&["40001", "40002", "40003"]);
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+
+        assert_eq!(xml.matches("<Index>").count(), 3);
+        assert!(xml.contains("<Index>40001</Index>"));
+        assert!(xml.contains("<Index>40002</Index>"));
+        assert!(xml.contains("<Index>40003</Index>"));
+
+        let parsed: SmsDeleteManyRequest = serde_xml_rs::from_str(&xml).unwrap();
+        assert_eq!(parsed.indices, vec!["40001", "40002", "40003"]);
+    }
+
+    fn message(sms_type: &str, content: &str, date: &str) -> SmsMessage {
+        let xml = format!(
+            "<Message><Smstat>0</Smstat><Index>40001</Index><Phone>3350</Phone>\
+             <Content>{}</Content><Date>{}</Date><Sca></Sca><SaveType>0</SaveType>\
+             <Priority>0</Priority><SmsType>{}</SmsType></Message>",
+            content, date, sms_type
+        );
+        serde_xml_rs::from_str(&xml).unwrap()
+    }
+
+    #[test]
+    fn test_sms_message_datetime() {
+        let message = message("1", "Test", "2025-06-09 17:08:58");
+        let datetime = message.datetime().unwrap();
+        assert_eq!(datetime.to_string(), "2025-06-09 17:08:58");
+        assert_eq!(message.date_str(), "2025-06-09 17:08:58");
+    }
+
+    #[test]
+    fn test_sms_message_datetime_single_digit_components() {
+        let message = message("1", "Test", "2025-6-9 7:8:5");
+        let datetime = message.datetime().unwrap();
+        assert_eq!(datetime.to_string(), "2025-06-09 07:08:05");
+    }
+
+    #[test]
+    fn test_sms_message_decoded_text_ucs2() {
+        // "Zażółć 😀" as UTF-16BE, including a surrogate pair
+        let message = message("5", "005A0061017C00F3014201070020D83DDE00", "2025-06-09 17:08:58");
+        assert_eq!(message.decoded_text(), "Zażółć 😀");
+        assert_eq!(message.text(), "005A0061017C00F3014201070020D83DDE00");
+    }
+
+    #[test]
+    fn test_sms_message_decoded_text_plain_passthrough() {
+        let message = message("1", "Your balance is 12.50", "2025-06-09 17:08:58");
+        assert_eq!(message.decoded_text(), "Your balance is 12.50");
+    }
+
+    #[test]
+    fn test_sms_message_decoded_text_invalid_hex_falls_back() {
+        assert_eq!(message("5", "004800690", "2025-06-09 17:08:58").decoded_text(), "004800690");
+        assert_eq!(message("5", "Hello!", "2025-06-09 17:08:58").decoded_text(), "Hello!");
+        assert_eq!(message("5", "D83D0041", "2025-06-09 17:08:58").decoded_text(), "D83D0041");
+    }
+
+    #[test]
+    fn test_sms_count_is_full() {
+        let xml = "<response><LocalUnread>0</LocalUnread><LocalInbox>490</LocalInbox><LocalOutbox>8</LocalOutbox>\
+<LocalDraft>2</LocalDraft><SimUnread>0</SimUnread><SimInbox>3</SimInbox><SimOutbox>0</SimOutbox>\
+<SimDraft>0</SimDraft><NewMsg>0</NewMsg><LocalMax>500</LocalMax><SimMax>30</SimMax></response>";
+        let count: SmsCount = serde_xml_rs::from_str(xml).unwrap();
+        assert!(count.is_full(SmsBoxType::LocalInbox));
+        assert!(!count.is_full(SmsBoxType::SimInbox));
+
+        let xml = "<response><LocalUnread>0</LocalUnread><LocalInbox>900</LocalInbox><LocalOutbox>0</LocalOutbox>\
+<LocalDraft>0</LocalDraft><SimUnread>0</SimUnread><SimInbox>0</SimInbox><SimOutbox>0</SimOutbox>\
+<SimDraft>0</SimDraft><NewMsg>0</NewMsg></response>";
+        let count: SmsCount = serde_xml_rs::from_str(xml).unwrap();
+        assert!(!count.is_full(SmsBoxType::LocalInbox));
+    }
+
+    #[test]
+    fn test_sms_message_datetime_malformed() {
+        assert!(message("1", "Test", "garbage").datetime().is_none());
+        assert!(message("1", "Test", "").datetime().is_none());
+        assert!(message("1", "Test", "2025-13-40 25:00:00").datetime().is_none());
+    }
+
+    const SMS_CONFIG_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<SaveMode>0</SaveMode>
+<Validity>10752</Validity>
+<Sca></Sca>
+<UseSReport>0</UseSReport>
+<SendType>1</SendType>
+<pagesize>20</pagesize>
+<maxphone>50</maxphone>
+<CdmaModeSelected>0</CdmaModeSelected>
+<cbsenable>1</cbsenable>
+<cbschannellist>50,4370</cbschannellist>
+</response>"#;
+
+    #[test]
+    fn test_sms_config_deserialization() {
+        let config: SmsConfig = serde_xml_rs::from_str(SMS_CONFIG_XML).unwrap();
+
+        assert_eq!(config.validity.as_deref(), Some("10752"));
+        assert_eq!(config.center_number(), None);
+        assert_eq!(config.cdma_mode_selected.as_deref(), Some("0"));
+        assert_eq!(config.cbs_channel_list.as_deref(), Some("50,4370"));
+        assert!(config.priority.is_none());
+    }
+
+    #[test]
+    fn test_sms_config_request_preserves_fields() {
+        let config: SmsConfig = serde_xml_rs::from_str(SMS_CONFIG_XML).unwrap();
+        let request = SmsConfigRequest::from(&config).with_center_number("+48601000310");
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+
+        assert!(xml.contains("<Sca>+48601000310</Sca>"));
+        assert!(xml.contains("<SaveMode>0</SaveMode>"));
+        assert!(xml.contains("<Validity>10752</Validity>"));
+        assert!(xml.contains("<UseSReport>0</UseSReport>"));
+        assert!(xml.contains("<SendType>1</SendType>"));
+        assert!(xml.contains("<cbsenable>1</cbsenable>"));
+        assert!(xml.contains("<cbschannellist>50,4370</cbschannellist>"));
+        assert!(!xml.contains("Priority"));
+        assert!(!xml.contains("pagesize"));
+    }
+
+    #[test]
+    fn test_sms_move_request_serialization() {
+        let request = SmsMoveRequest::new(&["40001", "40002"], SmsBoxType::LocalInbox);
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+
+        assert!(xml.contains("<Index>40001</Index><Index>40002</Index><BoxType>1</BoxType>"));
+    }
+
+    #[test]
+    fn test_split_message_long_ascii() {
+        let content = "a".repeat(200);
+        let parts = split_message(&content);
+
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].len(), 153);
+        assert_eq!(parts[1].len(), 47);
+        assert_eq!(parts.concat(), content);
+
+        assert_eq!(split_message(&"a".repeat(160)).len(), 1);
+    }
+
+    #[test]
+    fn test_split_message_extended_gsm_characters() {
+        // 80 euro signs take 160 septets, one more character overflows
+        assert_eq!(split_message(&"€".repeat(80)).len(), 1);
+
+        let parts = split_message(&"{".repeat(81));
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].chars().count(), 76);
+        assert_eq!(parts[1].chars().count(), 5);
+    }
+
+    #[test]
+    fn test_split_message_emoji() {
+        // 33 emoji fill 66 of 67 units; the 34th would straddle the boundary
+        // and moves to the next part whole
+        let content = "😀".repeat(43);
+        assert!(!is_gsm7(&content));
+
+        let parts = split_message(&content);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].encode_utf16().count(), 66);
+        assert_eq!(parts[1], "😀".repeat(10));
+
+        assert_eq!(split_message("Hi 😀"), vec!["Hi 😀".to_string()]);
+    }
+
+    #[test]
+    fn test_is_gsm7() {
+        assert!(is_gsm7("Hello, é €{}"));
+        assert!(!is_gsm7("back`tick"));
+        assert!(!is_gsm7("Zażółć"));
+        assert_eq!(SmsSendRequest::new(&["1"], "café").reserved, SmsSendRequest::ENCODING_GSM7);
+    }
+
 }
@@ -0,0 +1,18 @@
+//! Combined dashboard snapshot
+
+use super::device::DeviceInformation;
+use super::monitoring::{MonitoringStatus, TrafficStatistics};
+use super::network::CurrentPlmn;
+use serde::Serialize;
+
+/// Device state gathered by [`Client::snapshot`](crate::Client::snapshot).
+///
+/// Endpoints that need a login are `None` when the client is not logged in,
+/// so a dashboard can still show the public information.
+#[derive(Debug, Clone, Serialize)]
+pub struct DeviceSnapshot {
+    pub device: DeviceInformation,
+    pub plmn: CurrentPlmn,
+    pub status: Option<MonitoringStatus>,
+    pub traffic: Option<TrafficStatistics>,
+}
@@ -0,0 +1,102 @@
+//! USSD session models
+
+use serde::{Deserialize, Serialize};
+
+/// USSD send request for `/api/ussd/send`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct UssdSendRequest {
+    /// USSD code to send (e.g. `*100#`)
+    #[serde(rename = "content")]
+    pub content: String,
+
+    /// Code type, always `CodeType` for HiLink devices
+    #[serde(rename = "codeType")]
+    pub code_type: String,
+
+    #[serde(rename = "timeout")]
+    pub timeout: String,
+}
+
+/// USSD session status from `/api/ussd/status`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct UssdStatus {
+    /// Session state (0=idle/response ready, 1=waiting for network)
+    #[serde(rename = "result")]
+    pub result: String,
+}
+
+/// USSD reply from `/api/ussd/get`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct UssdResponse {
+    #[serde(rename = "content", default)]
+    pub content: String,
+}
+
+/// USSD release request for `/api/ussd/release`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct UssdReleaseRequest {}
+
+impl UssdSendRequest {
+    /// Create a new USSD send request
+    pub fn new(code: &str) -> Self {
+        Self {
+            content: code.to_string(),
+            code_type: "CodeType".to_string(),
+            timeout: String::new(),
+        }
+    }
+}
+
+impl UssdStatus {
+    /// Check if the device is still waiting for the network reply
+    pub fn is_waiting(&self) -> bool {
+        self.result.trim() == "1"
+    }
+}
+
+impl UssdResponse {
+    /// Get the reply text
+    pub fn text(&self) -> &str {
+        self.content.trim()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ussd_send_request_serialization() {
+        let request = UssdSendRequest::new("*100#");
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+        assert!(xml.contains("<content>*100#</content>"));
+        assert!(xml.contains("<codeType>CodeType</codeType>"));
+        assert!(xml.contains("<timeout"));
+    }
+
+    #[test]
+    fn test_ussd_status_parsing() {
+        let status: UssdStatus =
+            serde_xml_rs::from_str("<response><result>1</result></response>").unwrap();
+        assert!(status.is_waiting());
+
+        let status: UssdStatus =
+            serde_xml_rs::from_str("<response><result>0</result></response>").unwrap();
+        assert!(!status.is_waiting());
+    }
+
+    #[test]
+    fn test_ussd_response_parsing() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<content>Your balance is 12.50 PLN. Valid until 2025-07-01.</content>
+</response>"#;
+
+        let response: UssdResponse = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(response.text(), "Your balance is 12.50 PLN. Valid until 2025-07-01.");
+    }
+}
@@ -0,0 +1,478 @@
+//! WiFi (WLAN) configuration models
+
+use super::common::flag;
+use super::enums::MacFilterMode;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// WiFi basic settings from `/api/wlan/basic-settings`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct WlanBasicSettings {
+    #[serde(rename = "WifiSsid")]
+    pub wifi_ssid: String,
+
+    /// Channel number (0=auto)
+    #[serde(rename = "WifiChannel")]
+    pub wifi_channel: String,
+
+    /// 802.11 mode (e.g. `b/g/n`)
+    #[serde(rename = "WifiMode")]
+    pub wifi_mode: String,
+
+    /// Channel bandwidth (0=auto, 20, 40)
+    #[serde(rename = "WifiBandwidth", default)]
+    pub wifi_bandwidth: Option<String>,
+
+    /// Whether the SSID is hidden (device sends 0/1)
+    #[serde(rename = "WifiHideBroadcast", with = "flag")]
+    pub wifi_hide_broadcast: bool,
+
+    /// Regulatory country code (e.g. `PL`)
+    #[serde(rename = "WifiCountry", default)]
+    pub wifi_country: Option<String>,
+}
+
+/// WiFi basic settings request for `/api/wlan/basic-settings`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct WlanBasicSettingsRequest {
+    #[serde(rename = "WifiSsid")]
+    pub wifi_ssid: String,
+
+    #[serde(rename = "WifiChannel")]
+    pub wifi_channel: String,
+
+    #[serde(rename = "WifiMode")]
+    pub wifi_mode: String,
+
+    #[serde(rename = "WifiBandwidth", skip_serializing_if = "Option::is_none", default)]
+    pub wifi_bandwidth: Option<String>,
+
+    #[serde(rename = "WifiHideBroadcast", with = "flag")]
+    pub wifi_hide_broadcast: bool,
+
+    #[serde(rename = "WifiCountry", skip_serializing_if = "Option::is_none", default)]
+    pub wifi_country: Option<String>,
+}
+
+impl WlanBasicSettingsRequest {
+    /// Create a request from the current settings, so unchanged values are kept
+    pub fn from_settings(settings: &WlanBasicSettings) -> Self {
+        Self {
+            wifi_ssid: settings.wifi_ssid.clone(),
+            wifi_channel: settings.wifi_channel.clone(),
+            wifi_mode: settings.wifi_mode.clone(),
+            wifi_bandwidth: settings.wifi_bandwidth.clone(),
+            wifi_hide_broadcast: settings.wifi_hide_broadcast,
+            wifi_country: settings.wifi_country.clone(),
+        }
+    }
+
+    /// Set a new SSID
+    pub fn with_ssid(mut self, ssid: &str) -> Self {
+        self.wifi_ssid = ssid.to_string();
+        self
+    }
+
+    /// Hide or show the SSID broadcast
+    pub fn with_hidden(mut self, hidden: bool) -> Self {
+        self.wifi_hide_broadcast = hidden;
+        self
+    }
+}
+
+/// Guest WiFi network from `/api/wlan/guest-network`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct GuestNetwork {
+    #[serde(rename = "GuestEnable", with = "flag")]
+    pub enabled: bool,
+
+    #[serde(rename = "GuestSsid", default)]
+    pub ssid: String,
+
+    /// Hours until the guest network switches itself off (0 = no limit)
+    #[serde(rename = "GuestDuration", default)]
+    pub duration: String,
+
+    /// WPA key; empty for an open network
+    #[serde(rename = "GuestPassword", default)]
+    pub password: Option<String>,
+}
+
+impl GuestNetwork {
+    /// Time limit of the guest network, `None` if it stays on
+    pub fn time_limit(&self) -> Option<Duration> {
+        match self.duration.trim().parse::<u64>() {
+            Ok(hours) if hours > 0 => Some(Duration::from_secs(hours * 3600)),
+            _ => None,
+        }
+    }
+}
+
+/// Guest network request for `/api/wlan/guest-network`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct GuestNetworkRequest {
+    #[serde(rename = "GuestEnable", with = "flag")]
+    pub enabled: bool,
+
+    #[serde(rename = "GuestSsid", skip_serializing_if = "Option::is_none", default)]
+    pub ssid: Option<String>,
+
+    #[serde(rename = "GuestDuration", skip_serializing_if = "Option::is_none", default)]
+    pub duration: Option<String>,
+
+    #[serde(rename = "GuestPassword", skip_serializing_if = "Option::is_none", default)]
+    pub password: Option<String>,
+}
+
+impl GuestNetworkRequest {
+    /// Enable the guest network with the given SSID and WPA key, without a time limit
+    pub fn enable(ssid: &str, password: &str) -> Self {
+        Self {
+            enabled: true,
+            ssid: Some(ssid.to_string()),
+            duration: Some("0".to_string()),
+            password: Some(password.to_string()),
+        }
+    }
+
+    /// Switch the guest network off, keeping its SSID and password
+    pub fn disable() -> Self {
+        Self {
+            enabled: false,
+            ssid: None,
+            duration: None,
+            password: None,
+        }
+    }
+
+    /// Switch the guest network off automatically after `hours`
+    pub fn with_duration_hours(mut self, hours: u32) -> Self {
+        self.duration = Some(hours.to_string());
+        self
+    }
+}
+
+/// MAC address filter from `/api/wlan/mac-filter`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct MacFilter {
+    #[serde(rename = "WifiMacFilterStatus")]
+    pub mode: MacFilterMode,
+
+    #[serde(rename = "WifiMacFilterMac", default)]
+    pub mac_addresses: Vec<String>,
+}
+
+/// MAC address filter request for `/api/wlan/mac-filter`
+///
+/// The device replaces the whole list, so the request must carry every
+/// entry that should remain. serde-xml-rs cannot serialize repeated
+/// elements, so this request is serialized with `quick_xml::se`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "request")]
+pub struct MacFilterRequest {
+    #[serde(rename = "WifiMacFilterStatus")]
+    pub mode: MacFilterMode,
+
+    #[serde(rename = "WifiMacFilterMac", default)]
+    pub mac_addresses: Vec<String>,
+}
+
+impl MacFilterRequest {
+    /// Create a request setting `mode` with the given MAC addresses
+    pub fn new<S: AsRef<str>>(mode: MacFilterMode, mac_addresses: &[S]) -> Self {
+        Self {
+            mode,
+            mac_addresses: mac_addresses
+                .iter()
+                .map(|mac| mac.as_ref().trim().to_ascii_uppercase())
+                .collect(),
+        }
+    }
+
+    /// Turn the filter off, clearing the list
+    pub fn disabled() -> Self {
+        Self {
+            mode: MacFilterMode::Disabled,
+            mac_addresses: Vec::new(),
+        }
+    }
+}
+
+/// Whether `mac` has the `AA:BB:CC:DD:EE:FF` form expected by the device
+pub fn is_valid_mac_address(mac: &str) -> bool {
+    let octets: Vec<&str> = mac.split(':').collect();
+    octets.len() == 6
+        && octets
+            .iter()
+            .all(|octet| octet.len() == 2 && octet.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Connected WiFi client from `/api/wlan/host-list`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "Host")]
+pub struct WlanHost {
+    #[serde(rename = "MacAddress")]
+    pub mac_address: String,
+
+    /// Assigned IP address (empty until the client completes DHCP)
+    #[serde(rename = "IpAddress", default)]
+    pub ip_address: String,
+
+    #[serde(rename = "HostName", default)]
+    pub host_name: String,
+
+    /// Seconds since the client associated
+    #[serde(rename = "AssociatedTime", default)]
+    pub associated_time: String,
+
+    #[serde(rename = "AssociatedSsid", default)]
+    pub associated_ssid: Option<String>,
+
+    /// Seconds left on the DHCP lease, only on some firmware
+    #[serde(rename = "LeaseTime", default)]
+    pub lease_time: Option<String>,
+}
+
+/// Hosts container from the host list response
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WlanHosts {
+    #[serde(rename = "$value", default)]
+    pub hosts: Vec<WlanHost>,
+}
+
+/// Host list response from `/api/wlan/host-list`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename = "response")]
+pub struct WlanHostList {
+    #[serde(rename = "Hosts", default)]
+    pub hosts: WlanHosts,
+}
+
+impl WlanHost {
+    /// Time since the client associated
+    pub fn associated_duration(&self) -> Duration {
+        Duration::from_secs(self.associated_time.trim().parse().unwrap_or(0))
+    }
+
+    /// Check if the client has been assigned an IP address
+    pub fn has_ip(&self) -> bool {
+        !self.ip_address.trim().is_empty()
+    }
+}
+
+impl WlanHostList {
+    /// Get the connected clients
+    pub fn hosts(&self) -> &[WlanHost] {
+        &self.hosts.hosts
+    }
+
+    /// Number of connected clients that have an IP address
+    pub fn active_count(&self) -> usize {
+        self.hosts.hosts.iter().filter(|host| host.has_ip()).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASIC_SETTINGS_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<WifiSsid>HUAWEI-B525-1A2B</WifiSsid>
+<WifiChannel>0</WifiChannel>
+<WifiHide>0</WifiHide>
+<WifiCountry>PL</WifiCountry>
+<WifiMode>b/g/n</WifiMode>
+<WifiRate>0</WifiRate>
+<WifiTxPwrPcnt>100</WifiTxPwrPcnt>
+<WifiMaxAssoc>32</WifiMaxAssoc>
+<WifiEnable>1</WifiEnable>
+<WifiFrgThrshld>2346</WifiFrgThrshld>
+<WifiRtsThrshld>2347</WifiRtsThrshld>
+<WifiDtmIntvl>1</WifiDtmIntvl>
+<WifiBcnIntvl>100</WifiBcnIntvl>
+<WifiWme>1</WifiWme>
+<WifiPamode>0</WifiPamode>
+<WifiIsolate>0</WifiIsolate>
+<WifiProtectionmode>1</WifiProtectionmode>
+<Wifioffenable>0</Wifioffenable>
+<Wifiofftime>600</Wifiofftime>
+<wifibandwidth>0</wifibandwidth>
+<WifiBandwidth>20</WifiBandwidth>
+<WifiHideBroadcast>1</WifiHideBroadcast>
+</response>"#;
+
+    #[test]
+    fn test_guest_network_enable_with_duration() {
+        let request = GuestNetworkRequest::enable("Guests", "welcome123").with_duration_hours(4);
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+
+        assert!(xml.contains("<GuestEnable>1</GuestEnable>"));
+        assert!(xml.contains("<GuestSsid>Guests</GuestSsid>"));
+        assert!(xml.contains("<GuestDuration>4</GuestDuration>"));
+        assert!(xml.contains("<GuestPassword>welcome123</GuestPassword>"));
+    }
+
+    #[test]
+    fn test_guest_network_disable() {
+        let xml = serde_xml_rs::to_string(&GuestNetworkRequest::disable()).unwrap();
+
+        assert!(xml.contains("<GuestEnable>0</GuestEnable>"));
+        assert!(!xml.contains("GuestSsid"));
+        assert!(!xml.contains("GuestDuration"));
+        assert!(!xml.contains("GuestPassword"));
+    }
+
+    #[test]
+    fn test_guest_network_parsing() {
+        let xml = "<response><GuestEnable>1</GuestEnable><GuestSsid>Guests</GuestSsid>\
+<GuestDuration>24</GuestDuration><GuestPassword></GuestPassword></response>";
+        let guest: GuestNetwork = serde_xml_rs::from_str(xml).unwrap();
+
+        assert!(guest.enabled);
+        assert_eq!(guest.ssid, "Guests");
+        assert_eq!(guest.time_limit(), Some(Duration::from_secs(24 * 3600)));
+
+        let guest = GuestNetwork { duration: "0".to_string(), ..guest };
+        assert_eq!(guest.time_limit(), None);
+    }
+
+    #[test]
+    fn test_mac_filter_request_allow_mode() {
+        let request = MacFilterRequest::new(
+            MacFilterMode::Allow,
+            &["aa:bb:cc:dd:ee:01", "AA:BB:CC:DD:EE:02"],
+        );
+        let xml = quick_xml::se::to_string(&request).unwrap();
+
+        assert_eq!(
+            xml,
+            "<request><WifiMacFilterStatus>1</WifiMacFilterStatus>\
+<WifiMacFilterMac>AA:BB:CC:DD:EE:01</WifiMacFilterMac>\
+<WifiMacFilterMac>AA:BB:CC:DD:EE:02</WifiMacFilterMac></request>"
+        );
+    }
+
+    #[test]
+    fn test_mac_filter_parsing() {
+        let xml = "<response><WifiMacFilterStatus>2</WifiMacFilterStatus>\
+<WifiMacFilterMac>AA:BB:CC:DD:EE:01</WifiMacFilterMac>\
+<WifiMacFilterMac>AA:BB:CC:DD:EE:02</WifiMacFilterMac></response>";
+        let filter: MacFilter = serde_xml_rs::from_str(xml).unwrap();
+
+        assert_eq!(filter.mode, MacFilterMode::Deny);
+        assert_eq!(filter.mac_addresses.len(), 2);
+
+        let empty: MacFilter = serde_xml_rs::from_str(
+            "<response><WifiMacFilterStatus>0</WifiMacFilterStatus></response>",
+        )
+        .unwrap();
+        assert_eq!(empty.mode, MacFilterMode::Disabled);
+        assert!(empty.mac_addresses.is_empty());
+    }
+
+    #[test]
+    fn test_mac_address_validation() {
+        assert!(is_valid_mac_address("AA:BB:CC:DD:EE:FF"));
+        assert!(is_valid_mac_address("00:1a:2b:3c:4d:5e"));
+        assert!(!is_valid_mac_address("AA:BB:CC:DD:EE"));
+        assert!(!is_valid_mac_address("AA-BB-CC-DD-EE-FF"));
+        assert!(!is_valid_mac_address("AABBCCDDEEFF"));
+        assert!(!is_valid_mac_address("AA:BB:CC:DD:EE:GG"));
+        assert!(!is_valid_mac_address("AA:BB:CC:DD:EE:F"));
+        assert!(!is_valid_mac_address(""));
+    }
+
+    #[test]
+    fn test_basic_settings_parsing() {
+        let settings: WlanBasicSettings = serde_xml_rs::from_str(BASIC_SETTINGS_XML).unwrap();
+        assert_eq!(settings.wifi_ssid, "HUAWEI-B525-1A2B");
+        assert_eq!(settings.wifi_channel, "0");
+        assert_eq!(settings.wifi_mode, "b/g/n");
+        assert_eq!(settings.wifi_bandwidth.as_deref(), Some("20"));
+        assert!(settings.wifi_hide_broadcast);
+        assert_eq!(settings.wifi_country.as_deref(), Some("PL"));
+    }
+
+    #[test]
+    fn test_basic_settings_request_round_trip() {
+        let settings: WlanBasicSettings = serde_xml_rs::from_str(BASIC_SETTINGS_XML).unwrap();
+        let request = WlanBasicSettingsRequest::from_settings(&settings)
+            .with_ssid("MyNetwork")
+            .with_hidden(false);
+
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+        assert!(xml.contains("<WifiSsid>MyNetwork</WifiSsid>"));
+        assert!(xml.contains("<WifiHideBroadcast>0</WifiHideBroadcast>"));
+
+        let parsed: WlanBasicSettingsRequest = serde_xml_rs::from_str(&xml).unwrap();
+        assert_eq!(parsed.wifi_ssid, "MyNetwork");
+        assert_eq!(parsed.wifi_channel, "0");
+        assert_eq!(parsed.wifi_mode, "b/g/n");
+        assert_eq!(parsed.wifi_bandwidth.as_deref(), Some("20"));
+        assert!(!parsed.wifi_hide_broadcast);
+        assert_eq!(parsed.wifi_country.as_deref(), Some("PL"));
+    }
+
+    #[test]
+    fn test_host_list_parsing() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+<Hosts>
+<Host>
+<ID>1</ID>
+<MacAddress>AA:BB:CC:DD:EE:01</MacAddress>
+<IpAddress>192.168.8.100</IpAddress>
+<HostName>laptop</HostName>
+<AssociatedTime>3600</AssociatedTime>
+<AssociatedSsid>HUAWEI-B525-1A2B</AssociatedSsid>
+<Frequency>2.4GHz</Frequency>
+</Host>
+<Host>
+<ID>2</ID>
+<MacAddress>AA:BB:CC:DD:EE:02</MacAddress>
+<IpAddress></IpAddress>
+<HostName></HostName>
+<AssociatedTime>5</AssociatedTime>
+<AssociatedSsid>HUAWEI-B525-1A2B</AssociatedSsid>
+</Host>
+</Hosts>
+</response>"#;
+
+        let list: WlanHostList = serde_xml_rs::from_str(xml).unwrap();
+        assert_eq!(list.hosts().len(), 2);
+        assert_eq!(list.active_count(), 1);
+
+        let host = &list.hosts()[0];
+        assert_eq!(host.mac_address, "AA:BB:CC:DD:EE:01");
+        assert_eq!(host.ip_address, "192.168.8.100");
+        assert_eq!(host.host_name, "laptop");
+        assert_eq!(host.associated_duration(), Duration::from_secs(3600));
+        assert_eq!(host.associated_ssid.as_deref(), Some("HUAWEI-B525-1A2B"));
+        assert!(!list.hosts()[1].has_ip());
+    }
+
+    #[test]
+    fn test_empty_host_list_parsing() {
+        let list: WlanHostList =
+            serde_xml_rs::from_str("<response><Hosts></Hosts></response>").unwrap();
+        assert!(list.hosts().is_empty());
+        assert_eq!(list.active_count(), 0);
+
+        let list: WlanHostList = serde_xml_rs::from_str("<response></response>").unwrap();
+        assert!(list.hosts().is_empty());
+    }
+
+    #[test]
+    fn test_hide_broadcast_flag_serialization() {
+        let settings: WlanBasicSettings = serde_xml_rs::from_str(BASIC_SETTINGS_XML).unwrap();
+        let request = WlanBasicSettingsRequest::from_settings(&settings);
+        let xml = serde_xml_rs::to_string(&request).unwrap();
+        assert!(xml.contains("<WifiHideBroadcast>1</WifiHideBroadcast>"));
+    }
+}
@@ -0,0 +1,78 @@
+//! Hooks around each HTTP request.
+//!
+//! A [`ClientObserver`] set with
+//! [`ConfigBuilder::observer`](crate::config::ConfigBuilder::observer) is
+//! called before every HTTP request the client sends and after its response
+//! (or failure) arrives, including each retry attempt. Use it to bridge
+//! requests into your own logging or tracing without this crate choosing a
+//! logging stack.
+//!
+//! Observers run inline on the request path, so they should be quick. A
+//! panicking observer is caught and logged; it never fails the request.
+//!
+//! # Example
+//!
+//! ```
+//! use huawei_dongle_api::observer::ClientObserver;
+//! use huawei_dongle_api::{Config, Error};
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! #[derive(Debug)]
+//! struct PrintObserver;
+//!
+//! impl ClientObserver for PrintObserver {
+//!     fn on_request(&self, method: &str, path: &str) {
+//!         println!("-> {} {}", method, path);
+//!     }
+//!
+//!     fn on_response(
+//!         &self,
+//!         path: &str,
+//!         status: Option<u16>,
+//!         latency: Duration,
+//!         outcome: Result<(), &Error>,
+//!     ) {
+//!         println!("<- {} {:?} in {:?}: {:?}", path, status, latency, outcome);
+//!     }
+//! }
+//!
+//! let config = Config::builder()
+//!     .observer(Arc::new(PrintObserver))
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use crate::error::Error;
+use std::fmt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::Duration;
+use tracing::warn;
+
+/// Receives a callback before and after every HTTP request
+pub trait ClientObserver: fmt::Debug + Send + Sync {
+    /// Called before a request is sent
+    fn on_request(&self, method: &str, path: &str) {
+        let _ = (method, path);
+    }
+
+    /// Called when a request finished. `status` is `None` if no response
+    /// arrived (connect error, timeout); `outcome` is the error the request
+    /// failed with, if any.
+    fn on_response(
+        &self,
+        path: &str,
+        status: Option<u16>,
+        latency: Duration,
+        outcome: Result<(), &Error>,
+    ) {
+        let _ = (path, status, latency, outcome);
+    }
+}
+
+/// Run an observer callback, logging instead of propagating a panic
+pub(crate) fn notify<F: FnOnce()>(callback: F) {
+    if catch_unwind(AssertUnwindSafe(callback)).is_err() {
+        warn!("Client observer panicked; ignoring");
+    }
+}
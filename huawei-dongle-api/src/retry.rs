@@ -16,21 +16,137 @@
 //!     max_delay: Duration::from_secs(10),
 //!     backoff_multiplier: 2.0,
 //!     jitter: true,
+//!     ..Default::default()
 //! };
 //! ```
 
 use crate::error::{Error, Result};
-use std::time::Duration;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::time::sleep;
 use tracing::debug;
 
+/// Jitter strategy applied to backoff delays, following the AWS "Exponential
+/// Backoff And Jitter" recipes. Selected with
+/// [`RetryStrategy::jitter_strategy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jitter {
+    /// No jitter; every attempt waits exactly the backoff delay
+    None,
+    /// Random delay in `[0, base]`
+    Full,
+    /// Half the delay plus a random amount in `[0, base / 2]`
+    Equal,
+    /// Random delay in `[initial_delay, previous_delay * 3]`, capped at
+    /// `max_delay`
+    Decorrelated,
+}
+
+/// Callback invoked before each retry with the failed attempt number
+/// (starting at 1), the error and the delay before the next attempt
+pub type RetryHook = Arc<dyn Fn(usize, &Error, Duration) + Send + Sync>;
+
+/// Decides which errors are retried, replacing the built-in classifier
+/// ([`Error::is_retryable_with`]) when set on [`RetryStrategy::retry_on`]
+#[derive(Clone)]
+pub struct RetryOn(Arc<dyn Fn(&Error) -> bool + Send + Sync>);
+
+impl RetryOn {
+    /// Retry the errors for which `predicate` returns `true`
+    pub fn new<F>(predicate: F) -> Self
+    where
+        F: Fn(&Error) -> bool + Send + Sync + 'static,
+    {
+        Self(Arc::new(predicate))
+    }
+
+    /// Retry only connect failures and timeouts, e.g. while the device
+    /// reboots; any HTTP or device error status fails immediately
+    pub fn transient_network_only() -> Self {
+        Self::new(|error| matches!(error, Error::Http(e) if e.is_connect() || e.is_timeout()))
+    }
+
+    /// Check if `error` should be retried
+    pub fn matches(&self, error: &Error) -> bool {
+        (self.0)(error)
+    }
+}
+
+/// Same classification as [`Error::is_retryable`]
+impl Default for RetryOn {
+    fn default() -> Self {
+        Self::new(Error::is_retryable)
+    }
+}
+
+impl fmt::Debug for RetryOn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RetryOn(<predicate>)")
+    }
+}
+
+/// Token bucket capping retries across every call that shares it.
+///
+/// Each retry takes one token; the bucket holds at most `per_minute` tokens
+/// and refills continuously at `per_minute` tokens per minute. First attempts
+/// never take a token, so an empty bucket only turns off retrying.
+#[derive(Debug)]
+pub struct RetryBudget {
+    capacity: f64,
+    state: Mutex<BudgetState>,
+}
+
+#[derive(Debug)]
+struct BudgetState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RetryBudget {
+    /// Create a full budget allowing `per_minute` retries per minute
+    pub fn per_minute(per_minute: u32) -> Self {
+        let capacity = f64::from(per_minute);
+        Self {
+            capacity,
+            state: Mutex::new(BudgetState {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Take a token for one retry, returning `false` if the budget is spent
+    pub fn try_acquire(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.capacity / 60.0).min(self.capacity);
+        state.last_refill = now;
+
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Retries currently available, rounded down
+    pub fn available(&self) -> u32 {
+        self.state.lock().unwrap().tokens as u32
+    }
+}
+
 /// Retry strategy configuration.
 /// 
 /// Controls how failed requests are retried, including the number of attempts,
 /// delays between attempts, and backoff behavior.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct RetryStrategy {
-    /// Maximum number of retry attempts
+    /// Total number of attempts, including the first one. `0` and `1` both
+    /// mean a single attempt without retries.
     pub max_attempts: usize,
     /// Initial delay between retries
     pub initial_delay: Duration,
@@ -40,6 +156,41 @@ pub struct RetryStrategy {
     pub backoff_multiplier: f64,
     /// Whether to add random jitter to delays to prevent thundering herd
     pub jitter: bool,
+    /// Optional jitter strategy; when set it replaces the default
+    /// ±25% jitter, regardless of `jitter`
+    pub jitter_strategy: Option<Jitter>,
+    /// Device API error codes to retry in addition to the built-in ones,
+    /// e.g. `111020` (SMS processing)
+    pub retryable_api_codes: HashSet<i32>,
+    /// Minimum delay before retrying after [`Error::SystemBusy`], which the
+    /// device returns while it is still applying a previous change
+    pub system_busy_delay: Duration,
+    /// Optional predicate overriding which errors are retried; when set,
+    /// `retryable_api_codes` and the built-in classification are ignored
+    pub retry_on: Option<RetryOn>,
+    /// Optional hook for counting or logging retries
+    pub on_retry: Option<RetryHook>,
+    /// Optional budget shared with other strategies; once it is spent,
+    /// failures are returned immediately instead of being retried
+    pub budget: Option<Arc<RetryBudget>>,
+}
+
+impl fmt::Debug for RetryStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryStrategy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_delay", &self.initial_delay)
+            .field("max_delay", &self.max_delay)
+            .field("backoff_multiplier", &self.backoff_multiplier)
+            .field("jitter", &self.jitter)
+            .field("jitter_strategy", &self.jitter_strategy)
+            .field("retryable_api_codes", &self.retryable_api_codes)
+            .field("system_busy_delay", &self.system_busy_delay)
+            .field("retry_on", &self.retry_on)
+            .field("on_retry", &self.on_retry.as_ref().map(|_| "<hook>"))
+            .field("budget", &self.budget)
+            .finish()
+    }
 }
 
 impl Default for RetryStrategy {
@@ -50,25 +201,60 @@ impl Default for RetryStrategy {
             max_delay: Duration::from_secs(30),
             backoff_multiplier: 2.0,
             jitter: true,
+            jitter_strategy: None,
+            retryable_api_codes: HashSet::new(),
+            system_busy_delay: Duration::from_secs(2),
+            retry_on: None,
+            on_retry: None,
+            budget: None,
         }
     }
 }
 
 impl RetryStrategy {
     /// Calculate the delay for a given attempt
+    #[cfg(test)]
     fn calculate_delay(&self, attempt: usize) -> Duration {
+        self.calculate_delay_with(attempt, self.initial_delay, &mut fastrand::Rng::new())
+    }
+
+    /// Calculate the delay for a given attempt using `rng` for jitter.
+    ///
+    /// `previous` is the delay used before the last attempt and only matters
+    /// for [`Jitter::Decorrelated`].
+    fn calculate_delay_with(&self, attempt: usize, previous: Duration, rng: &mut fastrand::Rng) -> Duration {
         let base_delay = self.initial_delay.as_millis() as f64;
         let multiplier = self.backoff_multiplier.powi(attempt as i32);
         let delay_ms = (base_delay * multiplier) as u64;
 
         let delay = Duration::from_millis(delay_ms).min(self.max_delay);
+        let delay_ms = delay.as_millis() as u64;
 
-        if self.jitter {
-            let jitter_factor = 0.75 + (fastrand::f64() * 0.5);
-            let jittered_ms = (delay.as_millis() as f64 * jitter_factor) as u64;
-            Duration::from_millis(jittered_ms)
-        } else {
-            delay
+        match self.jitter_strategy {
+            Some(Jitter::None) => delay,
+            Some(Jitter::Full) => Duration::from_millis(rng.u64(0..=delay_ms)),
+            Some(Jitter::Equal) => {
+                let half = delay_ms / 2;
+                Duration::from_millis(half + rng.u64(0..=delay_ms - half))
+            }
+            Some(Jitter::Decorrelated) => {
+                let low = self.initial_delay.as_millis() as u64;
+                let high = (previous.as_millis() as u64).saturating_mul(3).max(low);
+                Duration::from_millis(rng.u64(low..=high)).min(self.max_delay)
+            }
+            None if self.jitter => {
+                let jitter_factor = 0.75 + (rng.f64() * 0.5);
+                Duration::from_millis((delay_ms as f64 * jitter_factor) as u64)
+            }
+            None => delay,
+        }
+    }
+
+    /// Strategy that makes a single attempt and never retries
+    pub fn no_retries() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
         }
     }
 
@@ -79,8 +265,11 @@ impl RetryStrategy {
         Fut: std::future::Future<Output = Result<T>>,
     {
         let mut last_error = None;
+        let mut rng = fastrand::Rng::new();
+        let mut previous_delay = self.initial_delay;
+        let max_attempts = self.max_attempts.max(1);
 
-        for attempt in 0..self.max_attempts {
+        for attempt in 0..max_attempts {
             match operation().await {
                 Ok(result) => {
                     if attempt > 0 {
@@ -89,18 +278,34 @@ impl RetryStrategy {
                     return Ok(result);
                 }
                 Err(error) => {
-                    if !error.is_retryable() {
+                    if !error.is_retryable_with(self) {
                         debug!("Error is not retryable, failing immediately: {}", error);
                         return Err(error);
                     }
 
                     debug!("Attempt {} failed: {}", attempt + 1, error);
-                    last_error = Some(error);
 
-                    if attempt < self.max_attempts - 1 {
-                        let delay = self.calculate_delay(attempt);
+                    if attempt < max_attempts - 1 {
+                        if let Some(budget) = &self.budget {
+                            if !budget.try_acquire() {
+                                debug!("Retry budget exhausted, failing immediately: {}", error);
+                                return Err(error);
+                            }
+                        }
+
+                        let mut delay = self.calculate_delay_with(attempt, previous_delay, &mut rng);
+                        if matches!(error, Error::SystemBusy) {
+                            delay = delay.max(self.system_busy_delay);
+                        }
+                        previous_delay = delay;
+                        if let Some(on_retry) = &self.on_retry {
+                            on_retry(attempt + 1, &error, delay);
+                        }
                         debug!("Retrying in {:?}", delay);
+                        last_error = Some(error);
                         sleep(delay).await;
+                    } else {
+                        last_error = Some(error);
                     }
                 }
             }
@@ -188,6 +393,234 @@ mod tests {
         assert_eq!(attempt_count.load(Ordering::SeqCst), 1);
     }
 
+    #[tokio::test]
+    async fn test_on_retry_hook() {
+        let calls = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let calls_clone = calls.clone();
+
+        let strategy = RetryStrategy {
+            max_attempts: 5,
+            initial_delay: Duration::from_millis(1),
+            jitter: false,
+            on_retry: Some(Arc::new(move |attempt, _error: &Error, delay| {
+                calls_clone.lock().unwrap().push((attempt, delay));
+            })),
+            ..Default::default()
+        };
+
+        let attempt_count = Arc::new(AtomicUsize::new(0));
+        let attempt_count_clone = attempt_count.clone();
+
+        let result = strategy
+            .execute(|| async {
+                let count = attempt_count_clone.fetch_add(1, Ordering::SeqCst);
+                if count < 2 {
+                    Err(Error::session("Temporary failure"))
+                } else {
+                    Ok::<i32, Error>(42)
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(
+            *calls.lock().unwrap(),
+            vec![(1, Duration::from_millis(1)), (2, Duration::from_millis(2))]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_single_attempt_without_retries() {
+        for max_attempts in [0, 1] {
+            let strategy = RetryStrategy {
+                max_attempts,
+                initial_delay: Duration::from_secs(60),
+                ..Default::default()
+            };
+
+            let attempt_count = AtomicUsize::new(0);
+            let started = std::time::Instant::now();
+            let result: Result<()> = strategy
+                .execute(|| async {
+                    attempt_count.fetch_add(1, Ordering::SeqCst);
+                    Err(Error::session("Temporary failure"))
+                })
+                .await;
+
+            assert!(matches!(result, Err(Error::Session { .. })));
+            assert_eq!(attempt_count.load(Ordering::SeqCst), 1, "max_attempts {}", max_attempts);
+            assert!(started.elapsed() < Duration::from_secs(1));
+        }
+    }
+
+    fn jitter_strategy(jitter: Option<Jitter>) -> RetryStrategy {
+        RetryStrategy {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(60),
+            jitter: false,
+            jitter_strategy: jitter,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_default_jitter_bounds() {
+        let strategy = RetryStrategy {
+            jitter: true,
+            ..jitter_strategy(None)
+        };
+        let mut rng = fastrand::Rng::with_seed(3);
+
+        for attempt in 0..6 {
+            let base = jitter_strategy(None).calculate_delay_with(attempt, Duration::ZERO, &mut rng);
+            for _ in 0..50 {
+                let delay = strategy.calculate_delay_with(attempt, Duration::ZERO, &mut rng);
+                assert!(
+                    delay >= base.mul_f64(0.75) && delay <= base.mul_f64(1.25),
+                    "attempt {}: {:?} not within 25% of {:?}",
+                    attempt,
+                    delay,
+                    base
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_no_jitter_is_deterministic() {
+        let strategy = RetryStrategy {
+            jitter: true,
+            ..jitter_strategy(Some(Jitter::None))
+        };
+        let mut rng = fastrand::Rng::with_seed(5);
+
+        for attempt in 0..6 {
+            let expected = jitter_strategy(None).calculate_delay_with(attempt, Duration::ZERO, &mut rng);
+            for _ in 0..10 {
+                assert_eq!(strategy.calculate_delay_with(attempt, Duration::ZERO, &mut rng), expected);
+            }
+        }
+        assert_eq!(strategy.calculate_delay(3), Duration::from_millis(800));
+    }
+
+    #[test]
+    fn test_full_jitter_bounds() {
+        let strategy = jitter_strategy(Some(Jitter::Full));
+        let mut rng = fastrand::Rng::with_seed(42);
+
+        for attempt in 0..6 {
+            let base = jitter_strategy(None).calculate_delay_with(attempt, Duration::ZERO, &mut rng);
+            for _ in 0..50 {
+                let delay = strategy.calculate_delay_with(attempt, Duration::ZERO, &mut rng);
+                assert!(delay <= base, "attempt {}: {:?} > {:?}", attempt, delay, base);
+            }
+        }
+    }
+
+    #[test]
+    fn test_equal_jitter_bounds() {
+        let strategy = jitter_strategy(Some(Jitter::Equal));
+        let mut rng = fastrand::Rng::with_seed(7);
+
+        for attempt in 0..6 {
+            let base = jitter_strategy(None).calculate_delay_with(attempt, Duration::ZERO, &mut rng);
+            for _ in 0..50 {
+                let delay = strategy.calculate_delay_with(attempt, Duration::ZERO, &mut rng);
+                assert!(delay >= base / 2 && delay <= base, "attempt {}: {:?} not in [{:?}, {:?}]", attempt, delay, base / 2, base);
+            }
+        }
+    }
+
+    #[test]
+    fn test_decorrelated_jitter_bounds() {
+        let strategy = RetryStrategy {
+            max_delay: Duration::from_secs(1),
+            ..jitter_strategy(Some(Jitter::Decorrelated))
+        };
+        let mut rng = fastrand::Rng::with_seed(1);
+        let mut previous = strategy.initial_delay;
+
+        for attempt in 0..10 {
+            let delay = strategy.calculate_delay_with(attempt, previous, &mut rng);
+            assert!(delay >= strategy.initial_delay);
+            assert!(delay <= (previous * 3).min(strategy.max_delay));
+            previous = delay;
+        }
+    }
+
+    async fn count_api_error_attempts(strategy: &RetryStrategy, code: i32) -> (Result<i32>, usize) {
+        let attempt_count = Arc::new(AtomicUsize::new(0));
+        let attempt_count_clone = attempt_count.clone();
+
+        let result = strategy
+            .execute(|| async {
+                attempt_count_clone.fetch_add(1, Ordering::SeqCst);
+                Err::<i32, Error>(Error::api(code, "device error".to_string()))
+            })
+            .await;
+
+        (result, attempt_count.load(Ordering::SeqCst))
+    }
+
+    #[tokio::test]
+    async fn test_retryable_api_codes() {
+        use crate::error::error_codes::{SMS_PROCESSING, SYSTEM_BUSY};
+
+        let mut strategy = RetryStrategy {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(1),
+            system_busy_delay: Duration::from_millis(1),
+            jitter: false,
+            ..Default::default()
+        };
+        strategy.retryable_api_codes.insert(SYSTEM_BUSY);
+
+        let (result, attempts) = count_api_error_attempts(&strategy, SYSTEM_BUSY).await;
+        assert!(matches!(result, Err(Error::SystemBusy)));
+        assert_eq!(attempts, 3);
+
+        // Codes outside the allowlist still fail fast
+        let (result, attempts) = count_api_error_attempts(&strategy, SMS_PROCESSING).await;
+        assert!(matches!(result, Err(Error::Api { code: SMS_PROCESSING, .. })));
+        assert_eq!(attempts, 1);
+
+        strategy.retryable_api_codes.insert(SMS_PROCESSING);
+        let (result, attempts) = count_api_error_attempts(&strategy, SMS_PROCESSING).await;
+        assert!(matches!(result, Err(Error::Api { code: SMS_PROCESSING, .. })));
+        assert_eq!(attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn test_system_busy_retried_with_extended_delay() {
+        let delays = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let delays_clone = delays.clone();
+        let strategy = RetryStrategy {
+            max_attempts: 4,
+            initial_delay: Duration::from_millis(1),
+            jitter: false,
+            system_busy_delay: Duration::from_millis(20),
+            on_retry: Some(Arc::new(move |_, _error: &Error, delay| {
+                delays_clone.lock().unwrap().push(delay);
+            })),
+            ..Default::default()
+        };
+
+        let attempt_count = AtomicUsize::new(0);
+        let result = strategy
+            .execute(|| async {
+                if attempt_count.fetch_add(1, Ordering::SeqCst) < 2 {
+                    Err(Error::api(crate::error::error_codes::SYSTEM_BUSY, String::new()))
+                } else {
+                    Ok::<i32, Error>(42)
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(attempt_count.load(Ordering::SeqCst), 3);
+        assert_eq!(*delays.lock().unwrap(), vec![Duration::from_millis(20); 2]);
+    }
+
     #[test]
     fn test_delay_calculation() {
         let strategy = RetryStrategy {
@@ -202,4 +635,90 @@ mod tests {
         assert_eq!(strategy.calculate_delay(1), Duration::from_millis(200));
         assert_eq!(strategy.calculate_delay(2), Duration::from_millis(400));
     }
+
+    #[tokio::test]
+    async fn test_retry_budget_shared_across_calls() {
+        let budget = Arc::new(RetryBudget::per_minute(3));
+        let strategy = RetryStrategy {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(1),
+            jitter: false,
+            budget: Some(budget.clone()),
+            ..Default::default()
+        };
+
+        let attempt_count = Arc::new(AtomicUsize::new(0));
+        for _ in 0..5 {
+            let attempt_count = attempt_count.clone();
+            let result = strategy
+                .execute(|| {
+                    let attempt_count = attempt_count.clone();
+                    async move {
+                        attempt_count.fetch_add(1, Ordering::SeqCst);
+                        Err::<(), _>(Error::session("Temporary failure"))
+                    }
+                })
+                .await;
+            assert!(matches!(result, Err(Error::Session { .. })));
+        }
+
+        // 5 first attempts plus the 3 retries the budget allowed
+        assert_eq!(attempt_count.load(Ordering::SeqCst), 8);
+        assert_eq!(budget.available(), 0);
+    }
+
+    #[test]
+    fn test_retry_budget_refills_over_time() {
+        let budget = RetryBudget::per_minute(60);
+        for _ in 0..60 {
+            assert!(budget.try_acquire());
+        }
+        assert!(!budget.try_acquire());
+
+        budget.state.lock().unwrap().last_refill -= Duration::from_secs(2);
+        assert!(budget.try_acquire());
+        assert!(budget.try_acquire());
+        assert!(!budget.try_acquire());
+    }
+
+    /// Attempts made fetching `url` under `strategy`
+    async fn count_fetch_attempts(strategy: &RetryStrategy, url: &str) -> usize {
+        let attempt_count = AtomicUsize::new(0);
+        let _: Result<()> = strategy
+            .execute(|| async {
+                attempt_count.fetch_add(1, Ordering::SeqCst);
+                reqwest::get(url).await?.error_for_status()?;
+                Ok(())
+            })
+            .await;
+        attempt_count.load(Ordering::SeqCst)
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_presets() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/").with_status(500).create_async().await;
+        let internal_error_url = server.url();
+
+        // Bind then drop a listener so connecting to the port is refused
+        let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let refused_url = format!("http://127.0.0.1:{}", port);
+
+        let strategy = |retry_on| RetryStrategy {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(1),
+            jitter: false,
+            retry_on: Some(retry_on),
+            ..Default::default()
+        };
+
+        let network_only = strategy(RetryOn::transient_network_only());
+        assert_eq!(count_fetch_attempts(&network_only, &refused_url).await, 3);
+        assert_eq!(count_fetch_attempts(&network_only, &internal_error_url).await, 1);
+
+        let default = strategy(RetryOn::default());
+        assert_eq!(count_fetch_attempts(&default, &refused_url).await, 3);
+        assert_eq!(count_fetch_attempts(&default, &internal_error_url).await, 3);
+    }
+
 }
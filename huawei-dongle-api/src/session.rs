@@ -1,7 +1,14 @@
 //! Session management and CSRF token handling
 
+use crate::encoding::read_text;
 use crate::error::{Error, Result};
-use reqwest::Client as HttpClient;
+use crate::models::auth::SesTokInfo;
+use reqwest::{
+    cookie::{CookieStore, Jar},
+    Client as HttpClient,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{debug, trace};
@@ -10,8 +17,10 @@ use url::Url;
 /// Session state for managing authentication and CSRF tokens
 #[derive(Debug, Clone, Default)]
 pub struct SessionState {
-    /// Current CSRF token
+    /// Current CSRF token, reused until the device rotates it
     pub csrf_token: Option<String>,
+    /// Single-use tokens from `__RequestVerificationTokenone`/`two` headers, consumed in order
+    pub token_queue: VecDeque<String>,
     /// Session cookies are managed by reqwest's cookie store
     pub is_authenticated: bool,
     /// Username of the authenticated user
@@ -20,27 +29,90 @@ pub struct SessionState {
     pub last_auth_time: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+/// Persistable snapshot of a session, produced by [`SessionManager::export_state`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SerializableSession {
+    /// Cached CSRF token
+    pub csrf_token: Option<String>,
+    /// Cookies for the device URL in `name=value; name=value` form
+    pub cookies: Option<String>,
+    /// Username of the authenticated user
+    pub username: Option<String>,
+    /// Last authentication time
+    pub last_auth_time: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Where [`SessionManager::fetch_token_debug`] found a CSRF token, with the token
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenSource {
+    /// `/api/webserver/SesTokInfo`
+    SesTokInfo(String),
+    /// `/api/webserver/token`
+    ApiEndpoint(String),
+    /// `csrf_token` meta tag in the homepage HTML
+    HomepageHtml(String),
+}
+
+impl TokenSource {
+    /// The token value
+    pub fn token(&self) -> &str {
+        match self {
+            TokenSource::SesTokInfo(token)
+            | TokenSource::ApiEndpoint(token)
+            | TokenSource::HomepageHtml(token) => token,
+        }
+    }
+}
+
 /// Session manager handles CSRF tokens and authentication state
 #[derive(Debug)]
 pub struct SessionManager {
     http_client: HttpClient,
     base_url: Url,
+    /// Cookie store shared with `http_client`, used to install the SesTokInfo session cookie
+    cookie_jar: Option<Arc<Jar>>,
+    /// Client without the cookie store for [`fetch_token_debug`](Self::fetch_token_debug)
+    probe_client: HttpClient,
     state: Arc<RwLock<SessionState>>,
 }
 
 impl SessionManager {
     pub fn new(http_client: HttpClient, base_url: Url) -> Self {
         Self {
+            probe_client: http_client.clone(),
             http_client,
             base_url,
+            cookie_jar: None,
             state: Arc::new(RwLock::new(SessionState::default())),
         }
     }
 
+    /// Create a session manager that can install session cookies into `cookie_jar`,
+    /// which must be the cookie provider of `http_client`
+    pub fn with_cookie_jar(http_client: HttpClient, base_url: Url, cookie_jar: Arc<Jar>) -> Self {
+        Self {
+            cookie_jar: Some(cookie_jar),
+            ..Self::new(http_client, base_url)
+        }
+    }
+
+    /// Use `probe_client` for token diagnosis, so the device's cookies do not
+    /// reach the cookie jar. It should share `http_client`'s settings without
+    /// a cookie store; defaults to `http_client`.
+    pub fn with_probe_client(mut self, probe_client: HttpClient) -> Self {
+        self.probe_client = probe_client;
+        self
+    }
+
     /// Get the current CSRF token, fetching one if needed
     pub async fn get_csrf_token(&self) -> Result<String> {
         {
-            let state = self.state.read().await;
+            let mut state = self.state.write().await;
+            if let Some(token) = state.token_queue.pop_front() {
+                trace!("Using queued CSRF token ({} left)", state.token_queue.len());
+                return Ok(token);
+            }
+
             if let Some(ref token) = state.csrf_token {
                 trace!("Using cached CSRF token");
                 return Ok(token.clone());
@@ -50,9 +122,22 @@ impl SessionManager {
         self.refresh_csrf_token().await
     }
 
-    /// Refresh the CSRF token by fetching from the token endpoint
+    /// Refresh the CSRF token, trying `/api/webserver/SesTokInfo`, then
+    /// `/api/webserver/token`, then the homepage HTML
     pub async fn refresh_csrf_token(&self) -> Result<String> {
-        debug!("Fetching new CSRF token from /api/webserver/token");
+        debug!("Fetching new CSRF token from /api/webserver/SesTokInfo");
+
+        self.state.write().await.token_queue.clear();
+
+        match self.try_sestokinfo().await {
+            Ok(token) => {
+                debug!("Successfully fetched session and token from SesTokInfo");
+                return Ok(token);
+            }
+            Err(e) => {
+                debug!("SesTokInfo fetch failed: {}, trying token endpoint", e);
+            }
+        }
 
         match self.try_api_token().await {
             Ok(token) => {
@@ -67,36 +152,137 @@ impl SessionManager {
         self.try_homepage_token().await
     }
 
-    /// Try to get CSRF token from the API endpoint
-    async fn try_api_token(&self) -> Result<String> {
-        let url = self.base_url.join("/api/webserver/token")?;
-        let response = self.http_client.get(url).send().await?;
+    /// Report which token source works on this device, trying them in the
+    /// same order as [`refresh_csrf_token`](Self::refresh_csrf_token).
+    ///
+    /// Meant for diagnosing token problems. Unless `store` is set, the
+    /// requests go through the probe client (see
+    /// [`with_probe_client`](Self::with_probe_client)) and the cached token is
+    /// left alone. With `store`, the found token is cached and the SesTokInfo
+    /// session cookie installed, as `refresh_csrf_token` would.
+    pub async fn fetch_token_debug(&self, store: bool) -> Result<TokenSource> {
+        let client = if store { &self.http_client } else { &self.probe_client };
+
+        let sestokinfo_error = match self.fetch_sestokinfo(client).await {
+            Ok(info) => {
+                if store {
+                    self.install_session_cookie(&info);
+                    self.store_token(info.token()).await;
+                }
+                return Ok(TokenSource::SesTokInfo(info.token().to_string()));
+            }
+            Err(e) => e,
+        };
+        debug!("SesTokInfo fetch failed: {}", sestokinfo_error);
+
+        let source = match self.fetch_api_token(client).await {
+            Ok(token) => TokenSource::ApiEndpoint(token),
+            Err(api_error) => {
+                debug!("API token fetch failed: {}", api_error);
+                match self.fetch_homepage_token(client).await {
+                    Ok(token) => TokenSource::HomepageHtml(token),
+                    Err(homepage_error) => {
+                        return Err(Error::session(format!(
+                            "No token source succeeded (SesTokInfo: {}; token endpoint: {}; homepage: {})",
+                            sestokinfo_error, api_error, homepage_error
+                        )))
+                    }
+                }
+            }
+        };
+
+        if store {
+            self.store_token(source.token()).await;
+        }
+        Ok(source)
+    }
+
+    /// Cache `token` as the reusable CSRF token
+    async fn store_token(&self, token: &str) {
+        self.state.write().await.csrf_token = Some(token.to_string());
+    }
+
+    /// Try to get the session cookie and CSRF token from SesTokInfo
+    async fn try_sestokinfo(&self) -> Result<String> {
+        let info = self.fetch_sestokinfo(&self.http_client).await?;
+        self.install_session_cookie(&info);
+
+        let token = info.token().to_string();
+        self.store_token(&token).await;
+        Ok(token)
+    }
+
+    fn install_session_cookie(&self, info: &SesTokInfo) {
+        match &self.cookie_jar {
+            Some(jar) => {
+                jar.add_cookie_str(&info.session_cookie(), &self.base_url);
+                debug!("Installed session cookie from SesTokInfo");
+            }
+            None => debug!("No cookie jar available, ignoring SesTokInfo session cookie"),
+        }
+    }
+
+    /// Fetch `/api/webserver/SesTokInfo`, failing if it has no token
+    async fn fetch_sestokinfo(&self, client: &HttpClient) -> Result<SesTokInfo> {
+        let url = self.base_url.join("/api/webserver/SesTokInfo")?;
+        let response = client.get(url).send().await?;
 
         if !response.status().is_success() {
             return Err(Error::session(format!(
-                "Failed to fetch token: HTTP {}",
+                "Failed to fetch SesTokInfo: HTTP {}",
                 response.status()
             )));
         }
 
-        let xml = response.text().await?;
-        trace!("Token response XML: {}", xml);
+        let xml = read_text(response).await?;
+        trace!("SesTokInfo response received ({} bytes)", xml.len());
 
-        let token = self.extract_token_from_xml(&xml)?;
+        let info: SesTokInfo = serde_xml_rs::from_str(&xml)
+            .map_err(|e| Error::session(format!("Failed to parse SesTokInfo: {}", e)))?;
 
-        {
-            let mut state = self.state.write().await;
-            state.csrf_token = Some(token.clone());
+        if info.token().is_empty() {
+            return Err(Error::session("SesTokInfo returned an empty token"));
         }
 
+        Ok(info)
+    }
+
+    /// Try to get CSRF token from the API endpoint
+    async fn try_api_token(&self) -> Result<String> {
+        let token = self.fetch_api_token(&self.http_client).await?;
+        self.store_token(&token).await;
         Ok(token)
     }
 
+    async fn fetch_api_token(&self, client: &HttpClient) -> Result<String> {
+        let url = self.base_url.join("/api/webserver/token")?;
+        let response = client.get(url).send().await?;
+
+        if !response.status().is_success() {
+            return Err(Error::session(format!(
+                "Failed to fetch token: HTTP {}",
+                response.status()
+            )));
+        }
+
+        let xml = read_text(response).await?;
+        trace!("Token response received ({} bytes)", xml.len());
+
+        self.extract_token_from_xml(&xml)
+    }
+
     /// Try to get CSRF token from homepage HTML
     async fn try_homepage_token(&self) -> Result<String> {
+        let token = self.fetch_homepage_token(&self.http_client).await?;
+        self.store_token(&token).await;
+        debug!("Successfully extracted token from homepage HTML");
+        Ok(token)
+    }
+
+    async fn fetch_homepage_token(&self, client: &HttpClient) -> Result<String> {
         debug!("Fetching CSRF token from homepage HTML");
 
-        let response = self.http_client.get(self.base_url.clone()).send().await?;
+        let response = client.get(self.base_url.clone()).send().await?;
 
         if !response.status().is_success() {
             return Err(Error::session(format!(
@@ -105,18 +291,10 @@ impl SessionManager {
             )));
         }
 
-        let html = response.text().await?;
+        let html = read_text(response).await?;
         trace!("Homepage HTML length: {} chars", html.len());
 
-        let token = self.extract_token_from_html(&html)?;
-
-        {
-            let mut state = self.state.write().await;
-            state.csrf_token = Some(token.clone());
-        }
-
-        debug!("Successfully extracted token from homepage HTML");
-        Ok(token)
+        self.extract_token_from_html(&html)
     }
 
 
@@ -185,7 +363,7 @@ impl SessionManager {
         for meta_element in document.select(&all_meta_selector) {
             if let Some(content) = meta_element.value().attr("content") {
                 if content.len() > 20 && content.chars().all(|c| c.is_alphanumeric()) {
-                    debug!("Found potential token in meta tag: {}...", &content[..10]);
+                    debug!("Found potential token in meta tag");
                     return Ok(content.to_string());
                 }
             }
@@ -194,9 +372,45 @@ impl SessionManager {
         Err(Error::session("Could not find CSRF token in HTML"))
     }
 
+    /// Capture the CSRF token, session cookie and login info for later reuse
+    pub async fn export_state(&self) -> SerializableSession {
+        let cookies = self
+            .cookie_jar
+            .as_ref()
+            .and_then(|jar| jar.cookies(&self.base_url))
+            .and_then(|value| value.to_str().ok().map(str::to_string));
+
+        let state = self.state.read().await;
+        SerializableSession {
+            csrf_token: state.csrf_token.clone(),
+            cookies,
+            username: state.username.clone(),
+            last_auth_time: state.last_auth_time,
+        }
+    }
+
+    /// Restore a session captured with [`export_state`](Self::export_state).
+    /// The session is treated as authenticated if it carries a username.
+    pub async fn import_state(&self, session: SerializableSession) {
+        if let (Some(jar), Some(cookies)) = (&self.cookie_jar, &session.cookies) {
+            for cookie in cookies.split(';').map(str::trim).filter(|c| !c.is_empty()) {
+                jar.add_cookie_str(cookie, &self.base_url);
+            }
+        }
+
+        let mut state = self.state.write().await;
+        state.csrf_token = session.csrf_token;
+        state.token_queue.clear();
+        state.is_authenticated = session.username.is_some();
+        state.username = session.username;
+        state.last_auth_time = session.last_auth_time;
+        debug!("Session state imported");
+    }
+
     pub async fn clear_session(&self) {
         let mut state = self.state.write().await;
         state.csrf_token = None;
+        state.token_queue.clear();
         state.is_authenticated = false;
         state.username = None;
         state.last_auth_time = None;
@@ -245,25 +459,41 @@ impl SessionManager {
         }
     }
 
-    /// Update CSRF token from response headers if available
+    /// Update CSRF token from response headers if available.
+    ///
+    /// Firmware that rotates two tokens (`__RequestVerificationTokenone` and
+    /// `__RequestVerificationTokentwo`) has them queued so each is used once, in order.
+    /// A single `__RequestVerificationToken` replaces the reusable cached token.
     pub async fn update_token_from_headers(&self, headers: &reqwest::header::HeaderMap) {
-        let token_headers = [
-            "__RequestVerificationToken",
-            "__RequestVerificationTokenone", 
+        let header_token = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .filter(|token| !token.is_empty())
+                .map(str::to_string)
+        };
+
+        let rotated: VecDeque<String> = [
+            "__RequestVerificationTokenone",
             "__RequestVerificationTokentwo",
-        ];
-
-        for header_name in &token_headers {
-            if let Some(token_value) = headers.get(*header_name) {
-                if let Ok(token_str) = token_value.to_str() {
-                    if !token_str.is_empty() {
-                        let mut state = self.state.write().await;
-                        state.csrf_token = Some(token_str.to_string());
-                        debug!("Updated CSRF token from response header {}: {}", header_name, token_str);
-                        return;
-                    }
-                }
-            }
+        ]
+        .iter()
+        .filter_map(|name| header_token(name))
+        .collect();
+
+        if !rotated.is_empty() {
+            let mut state = self.state.write().await;
+            debug!("Queued {} rotated CSRF tokens from response headers", rotated.len());
+            state.token_queue = rotated;
+            state.csrf_token = None;
+            return;
+        }
+
+        if let Some(token) = header_token("__RequestVerificationToken") {
+            let mut state = self.state.write().await;
+            debug!("Updated CSRF token from response header");
+            state.token_queue.clear();
+            state.csrf_token = Some(token);
         }
     }
 }
@@ -301,17 +531,57 @@ mod tests {
         let mut headers = HeaderMap::new();
         headers.insert("__RequestVerificationTokenone", "token_one".parse().unwrap());
         session.update_token_from_headers(&headers).await;
-        
-        let state = session.state.read().await;
-        assert_eq!(state.csrf_token, Some("token_one".to_string()));
-        drop(state);
+        assert_eq!(session.get_csrf_token().await.unwrap(), "token_one");
 
         let mut headers = HeaderMap::new();
         headers.insert("__RequestVerificationTokentwo", "token_two".parse().unwrap());
         session.update_token_from_headers(&headers).await;
-        
+        assert_eq!(session.get_csrf_token().await.unwrap(), "token_two");
+    }
+
+    #[tokio::test]
+    async fn test_two_token_rotation() {
+        let http_client = reqwest::Client::new();
+        let base_url = Url::parse("http://192.168.8.1").unwrap();
+        let session = SessionManager::new(http_client, base_url);
+
+        session.state.write().await.csrf_token = Some("login_token".to_string());
+
+        // Login response hands out two tokens that must be used in order
+        let mut headers = HeaderMap::new();
+        headers.insert("__RequestVerificationTokenone", "token_a1".parse().unwrap());
+        headers.insert("__RequestVerificationTokentwo", "token_a2".parse().unwrap());
+        session.update_token_from_headers(&headers).await;
+
+        assert_eq!(session.get_csrf_token().await.unwrap(), "token_a1");
+        assert_eq!(session.get_csrf_token().await.unwrap(), "token_a2");
+
+        // The next response rotates a fresh pair
+        let mut headers = HeaderMap::new();
+        headers.insert("__RequestVerificationTokenone", "token_b1".parse().unwrap());
+        headers.insert("__RequestVerificationTokentwo", "token_b2".parse().unwrap());
+        session.update_token_from_headers(&headers).await;
+
+        assert_eq!(session.get_csrf_token().await.unwrap(), "token_b1");
+        assert_eq!(session.get_csrf_token().await.unwrap(), "token_b2");
+
         let state = session.state.read().await;
-        assert_eq!(state.csrf_token, Some("token_two".to_string()));
+        assert!(state.token_queue.is_empty());
+        assert!(state.csrf_token.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_single_token_is_reused() {
+        let http_client = reqwest::Client::new();
+        let base_url = Url::parse("http://192.168.8.1").unwrap();
+        let session = SessionManager::new(http_client, base_url);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("__RequestVerificationToken", "single".parse().unwrap());
+        session.update_token_from_headers(&headers).await;
+
+        assert_eq!(session.get_csrf_token().await.unwrap(), "single");
+        assert_eq!(session.get_csrf_token().await.unwrap(), "single");
     }
 
     #[tokio::test]
@@ -330,4 +600,164 @@ mod tests {
         let state = session.state.read().await;
         assert_eq!(state.csrf_token, Some("existing_token".to_string()));
     }
+
+    #[tokio::test]
+    async fn test_refresh_prefers_sestokinfo_and_sets_cookie() {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/api/webserver/SesTokInfo")
+            .with_body(
+                "<response><SesInfo>SessionID=abc123</SesInfo><TokInfo>tok456</TokInfo></response>",
+            )
+            .create_async()
+            .await;
+
+        let token_endpoint = server
+            .mock("GET", "/api/webserver/token")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let with_cookie = server
+            .mock("GET", "/api/device/information")
+            .match_header("cookie", "SessionID=abc123")
+            .with_body("<response></response>")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let jar = Arc::new(Jar::default());
+        let http_client = reqwest::Client::builder()
+            .cookie_provider(jar.clone())
+            .build()
+            .unwrap();
+        let base_url = Url::parse(&server.url()).unwrap();
+        let session = SessionManager::with_cookie_jar(http_client.clone(), base_url.clone(), jar);
+
+        let token = session.refresh_csrf_token().await.unwrap();
+        assert_eq!(token, "tok456");
+        assert_eq!(session.get_csrf_token().await.unwrap(), "tok456");
+
+        http_client
+            .get(base_url.join("/api/device/information").unwrap())
+            .send()
+            .await
+            .unwrap();
+
+        with_cookie.assert_async().await;
+        token_endpoint.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_fetch_token_debug_reports_homepage_fallback() {
+        let mut server = mockito::Server::new_async().await;
+
+        server
+            .mock("GET", "/api/webserver/SesTokInfo")
+            .with_status(404)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/api/webserver/token")
+            .with_body("<error><code>100002</code><message></message></error>")
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/")
+            .with_body(r#"<html><head><meta name="csrf_token" content="html-token"></head></html>"#)
+            .create_async()
+            .await;
+
+        let base_url = Url::parse(&server.url()).unwrap();
+        let session = SessionManager::new(reqwest::Client::new(), base_url);
+
+        let source = session.fetch_token_debug(false).await.unwrap();
+        assert_eq!(source, TokenSource::HomepageHtml("html-token".to_string()));
+        assert_eq!(source.token(), "html-token");
+        assert_eq!(session.state.read().await.csrf_token, None);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_token_debug_leaves_cookie_jar_alone() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/webserver/SesTokInfo")
+            .with_header("set-cookie", "SessionID=from-debug; path=/")
+            .with_body("<response><SesInfo>SessionID=abc</SesInfo><TokInfo>tok</TokInfo></response>")
+            .create_async()
+            .await;
+
+        let base_url = Url::parse(&server.url()).unwrap();
+        let jar = Arc::new(Jar::default());
+        let http_client = reqwest::Client::builder()
+            .cookie_provider(jar.clone())
+            .build()
+            .unwrap();
+        let session = SessionManager::with_cookie_jar(http_client, base_url, jar)
+            .with_probe_client(reqwest::Client::new());
+
+        let source = session.fetch_token_debug(false).await.unwrap();
+        assert_eq!(source, TokenSource::SesTokInfo("tok".to_string()));
+
+        let state = session.export_state().await;
+        assert_eq!(state.csrf_token, None);
+        assert_eq!(state.cookies, None);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_token_debug_stores_token_when_asked() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/webserver/SesTokInfo")
+            .with_status(404)
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/api/webserver/token")
+            .with_body("<response><token>api-token</token></response>")
+            .create_async()
+            .await;
+
+        let base_url = Url::parse(&server.url()).unwrap();
+        let session = SessionManager::new(reqwest::Client::new(), base_url);
+
+        let source = session.fetch_token_debug(true).await.unwrap();
+        assert_eq!(source, TokenSource::ApiEndpoint("api-token".to_string()));
+        assert_eq!(session.state.read().await.csrf_token.as_deref(), Some("api-token"));
+    }
+
+    #[tokio::test]
+    async fn test_session_export_import_round_trip() {
+        let base_url = Url::parse("http://192.168.8.1").unwrap();
+
+        let jar = Arc::new(Jar::default());
+        jar.add_cookie_str("SessionID=abc123", &base_url);
+        let session = SessionManager::with_cookie_jar(reqwest::Client::new(), base_url.clone(), jar);
+        session.state.write().await.csrf_token = Some("token".to_string());
+        session.mark_authenticated("admin").await;
+
+        let exported = session.export_state().await;
+        assert_eq!(exported.cookies.as_deref(), Some("SessionID=abc123"));
+
+        let json = serde_json::to_string(&exported).unwrap();
+        let parsed: SerializableSession = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, exported);
+
+        let restored_jar = Arc::new(Jar::default());
+        let restored = SessionManager::with_cookie_jar(
+            reqwest::Client::new(),
+            base_url.clone(),
+            restored_jar.clone(),
+        );
+        restored.import_state(parsed).await;
+
+        assert!(restored.is_authenticated().await);
+        assert_eq!(restored.current_username().await.as_deref(), Some("admin"));
+        assert_eq!(restored.get_csrf_token().await.unwrap(), "token");
+        assert_eq!(
+            restored_jar.cookies(&base_url).unwrap().to_str().unwrap(),
+            "SessionID=abc123"
+        );
+    }
 }
@@ -0,0 +1,226 @@
+//! Test support for code built on this crate
+//!
+//! Available in this crate's own tests and, for downstream crates, with the
+//! `test-support` feature.
+
+use crate::{
+    error::{Error, Result},
+    models::{
+        common::check_for_api_error, device::DeviceInformation, dhcp::DhcpSettings,
+        monitoring::MonitoringStatus,
+    },
+    transport::Transport,
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Request seen by a [`MockTransport`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedRequest {
+    pub method: &'static str,
+    pub path: String,
+    /// XML body of a POST, `None` for a GET
+    pub body: Option<String>,
+}
+
+/// [`Transport`] returning canned response bodies per path
+///
+/// GET and POST requests to the same path share a response. Error bodies
+/// (`<error><code>..</code></error>`) are turned into [`Error::api`] like the
+/// HTTP transport does, and requests to a path without a canned response
+/// fail with an `Error::Api` code `404`. Every request is recorded.
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    responses: HashMap<String, String>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer requests to `path` with `body`
+    pub fn with_response(mut self, path: &str, body: &str) -> Self {
+        self.responses.insert(path.to_string(), body.to_string());
+        self
+    }
+
+    /// Requests received so far, oldest first
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn respond(&self, method: &'static str, path: &str, body: Option<&str>) -> Result<String> {
+        self.requests.lock().unwrap().push(RecordedRequest {
+            method,
+            path: path.to_string(),
+            body: body.map(str::to_string),
+        });
+
+        let text = self
+            .responses
+            .get(path)
+            .ok_or_else(|| Error::api(404, format!("No canned response for {}", path)))?;
+
+        if let Some(api_error) = check_for_api_error(text) {
+            return Err(Error::api(api_error.code.as_int(), api_error.error_message()));
+        }
+
+        Ok(text.clone())
+    }
+}
+
+#[async_trait]
+impl Transport for MockTransport {
+    async fn get(&self, path: &str) -> Result<String> {
+        self.respond("GET", path, None)
+    }
+
+    async fn get_authenticated(&self, path: &str) -> Result<String> {
+        self.respond("GET", path, None)
+    }
+
+    async fn post_xml(&self, path: &str, xml: &str) -> Result<String> {
+        self.respond("POST", path, Some(xml))
+    }
+}
+
+/// `/api/monitoring/status` body of a device on LTE with four signal bars
+/// at -71 dBm, reporting `connection_status` (e.g. `901` when connected)
+pub fn monitoring_status_xml(connection_status: &str) -> String {
+    format!(
+        "<response><ConnectionStatus>{}</ConnectionStatus><SignalIcon>4</SignalIcon>\
+<SignalStrength>-71dBm</SignalStrength><CurrentNetworkType>19</CurrentNetworkType>\
+<RoamingStatus>0</RoamingStatus><simlockStatus>0</simlockStatus><flymode>0</flymode>\
+<currenttotalwifiuser>0</currenttotalwifiuser><SimStatus>1</SimStatus><ServiceStatus>2</ServiceStatus>\
+<maxsignal>5</maxsignal><wifiindooronly>0</wifiindooronly><usbup>0</usbup>\
+<wifiswitchstatus>1</wifiswitchstatus></response>",
+        connection_status
+    )
+}
+
+/// [`monitoring_status_xml`] parsed into a [`MonitoringStatus`]
+pub fn monitoring_status(connection_status: &str) -> MonitoringStatus {
+    serde_xml_rs::from_str(&monitoring_status_xml(connection_status))
+        .expect("monitoring status fixture parses")
+}
+
+/// Builder for `/api/device/information` bodies, see [`device_information_xml`]
+#[derive(Debug, Clone)]
+pub struct DeviceInformationXml {
+    fields: Vec<(String, String)>,
+}
+
+impl DeviceInformationXml {
+    /// Set `element` to `value`, replacing its default or adding it
+    pub fn field(mut self, element: &str, value: &str) -> Self {
+        match self.fields.iter_mut().find(|(name, _)| name == element) {
+            Some((_, current)) => *current = value.to_string(),
+            None => self.fields.push((element.to_string(), value.to_string())),
+        }
+        self
+    }
+
+    pub fn build(&self) -> String {
+        let fields: String = self
+            .fields
+            .iter()
+            .map(|(name, value)| format!("<{0}>{1}</{0}>", name, value))
+            .collect();
+        format!("<response>{}</response>", fields)
+    }
+
+    /// The body parsed into [`DeviceInformation`]
+    pub fn parse(&self) -> DeviceInformation {
+        serde_xml_rs::from_str(&self.build()).expect("device information fixture parses")
+    }
+}
+
+/// `/api/device/information` body of an E3372 with serial `SN`, IMEI `IMEI`,
+/// hardware `HW` and software `SW`. Other elements are left out unless set
+/// with [`DeviceInformationXml::field`].
+pub fn device_information_xml() -> DeviceInformationXml {
+    DeviceInformationXml {
+        fields: [
+            ("DeviceName", "E3372"),
+            ("SerialNumber", "SN"),
+            ("Imei", "IMEI"),
+            ("HardwareVersion", "HW"),
+            ("SoftwareVersion", "SW"),
+        ]
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect(),
+    }
+}
+
+/// `/api/dhcp/settings` body of a device serving 192.168.8.100-200 on
+/// 192.168.8.0/24 with itself (192.168.8.1) as gateway and DNS server
+pub fn dhcp_settings_xml() -> String {
+    "<response><DnsStatus>1</DnsStatus><DhcpStartIPAddress>192.168.8.100</DhcpStartIPAddress>\
+<DhcpIPAddress>192.168.8.1</DhcpIPAddress><DhcpStatus>1</DhcpStatus>\
+<DhcpLanNetmask>255.255.255.0</DhcpLanNetmask><SecondaryDns>192.168.8.1</SecondaryDns>\
+<PrimaryDns>192.168.8.1</PrimaryDns><DhcpEndIPAddress>192.168.8.200</DhcpEndIPAddress>\
+<DhcpLeaseTime>86400</DhcpLeaseTime></response>"
+        .to_string()
+}
+
+/// [`dhcp_settings_xml`] parsed into [`DhcpSettings`]
+pub fn dhcp_settings() -> DhcpSettings {
+    serde_xml_rs::from_str(&dhcp_settings_xml()).expect("DHCP settings fixture parses")
+}
+
+/// Mock `/api/webserver/token` on `server`, handing out the token `test-token`
+#[cfg(test)]
+pub(crate) async fn token_mock(server: &mut mockito::ServerGuard) -> mockito::Mock {
+    server
+        .mock("GET", "/api/webserver/token")
+        .with_body("<response><token>test-token</token></response>")
+        .create_async()
+        .await
+}
+
+/// `/api/user/state-login` body of a logged-out admin account using
+/// `password_type` encoding
+#[cfg(test)]
+pub(crate) fn login_state_xml(password_type: &str) -> String {
+    format!(
+        "<response><password_type>{}</password_type><extern_password_type>1</extern_password_type>\
+         <history_login_flag>0</history_login_flag><State>-1</State>\
+         <guidemodifypwdpageflag>0</guidemodifypwdpageflag><rsapadingtype>1</rsapadingtype>\
+         <accounts_number>1</accounts_number><wifipwdsamewithwebpwd>0</wifipwdsamewithwebpwd>\
+         <remainwaittime>0</remainwaittime><lockstatus>0</lockstatus>\
+         <forceskipguide>0</forceskipguide><username></username><firstlogin>0</firstlogin>\
+         <userlevel></userlevel></response>",
+        password_type
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_mock_transport_records_requests() {
+        let transport = MockTransport::new()
+            .with_response("/api/sms/set-read", "<response>OK</response>")
+            .with_response("/api/sms/config", "<error><code>100002</code><message></message></error>");
+
+        let text = transport.post_xml("/api/sms/set-read", "<request/>").await.unwrap();
+        assert_eq!(text, "<response>OK</response>");
+
+        let err = transport.get_authenticated("/api/sms/config").await.unwrap_err();
+        assert!(matches!(err, Error::Api { code: 100002, .. }));
+
+        let err = transport.get("/api/unknown").await.unwrap_err();
+        assert!(matches!(err, Error::Api { code: 404, .. }));
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].body.as_deref(), Some("<request/>"));
+        assert_eq!(requests[2].path, "/api/unknown");
+    }
+}
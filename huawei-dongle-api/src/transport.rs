@@ -0,0 +1,63 @@
+//! Request transport used by the API structs
+//!
+//! [`Transport`] is the narrow interface API methods use to talk to the
+//! device: fetch a path or post an XML body and get the checked response
+//! text back. [`Client`] implements it over HTTP with the usual token refresh
+//! and re-login handling. Tests can substitute `testing::MockTransport`
+//! (enabled by the `test-support` feature) to exercise the parsing and error
+//! handling of an endpoint without a device or mock server.
+//!
+//! ```
+//! # #[cfg(feature = "test-support")]
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> huawei_dongle_api::Result<()> {
+//! use huawei_dongle_api::{api::sms::SmsApi, testing::MockTransport};
+//!
+//! let transport = MockTransport::new().with_response(
+//!     "/api/sms/sms-count",
+//!     "<response><LocalUnread>2</LocalUnread><LocalInbox>5</LocalInbox><LocalOutbox>0</LocalOutbox>\
+//!      <LocalDraft>0</LocalDraft><SimUnread>0</SimUnread><SimInbox>0</SimInbox>\
+//!      <SimOutbox>0</SimOutbox><SimDraft>0</SimDraft><NewMsg>0</NewMsg></response>",
+//! );
+//!
+//! let count = SmsApi::new(&transport).count().await?;
+//! assert_eq!(count.total_unread(), Ok(2));
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "test-support"))]
+//! # fn main() {}
+//! ```
+
+use crate::{client::Client, error::Result};
+use async_trait::async_trait;
+
+/// Sends requests to the device and returns response bodies
+///
+/// Implementations report device error responses (`<error>` bodies) as
+/// [`Error::api`](crate::Error::api), so callers only parse successful bodies.
+#[async_trait]
+pub trait Transport: Send + Sync {
+    /// GET an endpoint that does not require a session
+    async fn get(&self, path: &str) -> Result<String>;
+
+    /// GET an endpoint that requires a session
+    async fn get_authenticated(&self, path: &str) -> Result<String>;
+
+    /// POST an XML request body
+    async fn post_xml(&self, path: &str, xml: &str) -> Result<String>;
+}
+
+#[async_trait]
+impl Transport for Client {
+    async fn get(&self, path: &str) -> Result<String> {
+        self.get_uncached_text(path).await
+    }
+
+    async fn get_authenticated(&self, path: &str) -> Result<String> {
+        self.get_raw(path).await
+    }
+
+    async fn post_xml(&self, path: &str, xml: &str) -> Result<String> {
+        self.post_raw(path, xml).await
+    }
+}
@@ -31,6 +31,15 @@ pub struct Cli {
     #[arg(short, long)]
     pub verbose: bool,
 
+    /// Print changes instead of sending them to the device
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Skip TLS certificate verification for HTTPS device URLs, e.g. with a
+    /// self-signed certificate. Disables both the chain and host name checks.
+    #[arg(long)]
+    pub insecure: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -40,21 +49,27 @@ pub enum OutputFormat {
     Table,
     Json,
     Yaml,
+    Csv,
 }
 
 impl Cli {
     /// Execute the CLI command
     pub async fn execute(self) -> Result<()> {
-        if self.verbose {
-            let _ = tracing_subscriber::fmt()
-                .with_max_level(tracing::Level::DEBUG)
-                .try_init();
+        if self.verbose || self.dry_run {
+            let level = if self.verbose {
+                tracing::Level::DEBUG
+            } else {
+                tracing::Level::INFO
+            };
+            let _ = tracing_subscriber::fmt().with_max_level(level).try_init();
         }
 
         let config = Config::builder()
             .base_url(self.url)
             .timeout(Duration::from_secs(self.timeout))
             .max_retries(self.retries)
+            .dry_run(self.dry_run)
+            .accept_invalid_certs(self.insecure)
             .build()?;
 
         let client = Client::new(config)?;
@@ -4,6 +4,7 @@ use crate::{cli::OutputFormat, output::format_output};
 use anyhow::Result;
 use clap::Subcommand;
 use huawei_dongle_api::Client;
+use std::path::PathBuf;
 
 #[derive(Subcommand)]
 pub enum DeviceCommands {
@@ -21,6 +22,34 @@ pub enum DeviceCommands {
         #[arg(long)]
         confirm: bool,
     },
+    /// Reset the device to factory settings
+    FactoryReset {
+        /// Skip confirmation prompt
+        #[arg(long)]
+        confirm: bool,
+    },
+    /// Back up the device configuration to a file
+    Backup {
+        /// File to write the backup to
+        #[arg(short, long)]
+        output: PathBuf,
+        /// Skip confirmation prompt
+        #[arg(long)]
+        confirm: bool,
+    },
+    /// Show or switch airplane mode
+    Airplane {
+        /// New state (on, off); shows the current state if omitted
+        state: Option<String>,
+    },
+    /// Restore a configuration backup from a file
+    Restore {
+        /// Backup file to restore
+        file: PathBuf,
+        /// Skip confirmation prompt
+        #[arg(long)]
+        confirm: bool,
+    },
 }
 
 impl DeviceCommands {
@@ -48,6 +77,51 @@ impl DeviceCommands {
                 client.device().power_off().await?;
                 println!("Device power off initiated successfully");
             }
+            DeviceCommands::FactoryReset { confirm } => {
+                if !confirm {
+                    println!("Are you sure you want to reset the device to factory settings? All configuration will be lost. Use --confirm to skip this prompt.");
+                    return Ok(());
+                }
+
+                client.device().factory_reset().await?;
+                println!("Factory reset initiated successfully");
+            }
+            DeviceCommands::Backup { output, confirm } => {
+                if !confirm {
+                    println!("Are you sure you want to back up the device configuration? Use --confirm to skip this prompt.");
+                    return Ok(());
+                }
+
+                let backup = client.device().backup_configuration().await?;
+                std::fs::write(output, &backup)?;
+                println!("Configuration backup saved to {} ({} bytes)", output.display(), backup.len());
+            }
+            DeviceCommands::Airplane { state } => match state.as_deref() {
+                None => {
+                    let enabled = client.device().fly_mode_enabled().await?;
+                    println!("Airplane mode: {}", if enabled { "on" } else { "off" });
+                }
+                Some(state) => {
+                    let enabled = match state.to_ascii_lowercase().as_str() {
+                        "on" => true,
+                        "off" => false,
+                        _ => anyhow::bail!("Invalid airplane mode state: {}. Valid states: on, off", state),
+                    };
+
+                    client.device().set_fly_mode(enabled).await?;
+                    println!("Airplane mode turned {}", if enabled { "on" } else { "off" });
+                }
+            },
+            DeviceCommands::Restore { file, confirm } => {
+                if !confirm {
+                    println!("Are you sure you want to restore the configuration from {}? The device will reboot. Use --confirm to skip this prompt.", file.display());
+                    return Ok(());
+                }
+
+                let xml_blob = std::fs::read_to_string(file)?;
+                client.device().restore_configuration(&xml_blob).await?;
+                println!("Configuration restore initiated successfully");
+            }
         }
         Ok(())
     }
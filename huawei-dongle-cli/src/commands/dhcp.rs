@@ -4,11 +4,14 @@ use crate::{cli::OutputFormat, output::format_output};
 use anyhow::Result;
 use clap::Subcommand;
 use huawei_dongle_api::{models::dhcp::DhcpSettingsRequest, Client};
+use tabled::{builder::Builder, settings::Style};
 
 #[derive(Subcommand)]
 pub enum DhcpCommands {
     /// Show DHCP settings
     Show,
+    /// List clients holding a DHCP lease
+    Leases,
     /// Set gateway IP address
     SetIp {
         /// New gateway IP address (must be in format 192.168.x.1)
@@ -23,6 +26,39 @@ impl DhcpCommands {
                 let settings = client.dhcp().settings().await?;
                 format_output(&settings, format)?;
             }
+            DhcpCommands::Leases => {
+                let leases = client.dhcp().active_leases().await?;
+
+                if leases.is_empty() {
+                    println!("No active DHCP leases");
+                    return Ok(());
+                }
+
+                match format {
+                    OutputFormat::Table => {
+                        let mut builder = Builder::default();
+                        builder.push_record(["IP Address", "MAC Address", "Host Name", "Remaining"]);
+
+                        for lease in &leases {
+                            let remaining = lease.remaining_secs.map_or("-".to_string(), |secs| {
+                                format!("{}h {}m {}s", secs / 3600, (secs % 3600) / 60, secs % 60)
+                            });
+                            builder.push_record([
+                                lease.ip_address.to_string(),
+                                lease.mac_address.clone(),
+                                lease.host_name.clone(),
+                                remaining,
+                            ]);
+                        }
+
+                        println!("DHCP Leases ({}):", leases.len());
+                        println!("{}", builder.build().with(Style::rounded()));
+                    }
+                    _ => {
+                        format_output(&leases, format)?;
+                    }
+                }
+            }
             DhcpCommands::SetIp { ip } => {
                 if !ip.starts_with("192.168.") || !ip.ends_with(".1") {
                     anyhow::bail!("Gateway IP must be in format 192.168.x.1");
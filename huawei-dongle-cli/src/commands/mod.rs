@@ -9,7 +9,9 @@ pub mod device;
 pub mod dhcp;
 pub mod monitoring;
 pub mod network;
+pub mod security;
 pub mod sms;
+pub mod wlan;
 
 #[derive(Subcommand)]
 pub enum Commands {
@@ -38,6 +40,18 @@ pub enum Commands {
         #[command(subcommand)]
         command: dhcp::DhcpCommands,
     },
+    /// WiFi access point
+    Wlan {
+        #[command(subcommand)]
+        command: wlan::WlanCommands,
+    },
+    /// Firewall and port forwarding
+    Security {
+        #[command(subcommand)]
+        command: security::SecurityCommands,
+    },
+    /// Check that the device is reachable
+    Ping,
 }
 
 impl Commands {
@@ -48,6 +62,13 @@ impl Commands {
             Commands::Sms { command } => command.execute(client, format).await,
             Commands::Monitoring { command } => command.execute(client, format).await,
             Commands::Dhcp { command } => command.execute(client, format).await,
+            Commands::Wlan { command } => command.execute(client, format).await,
+            Commands::Security { command } => command.execute(client, format).await,
+            Commands::Ping => {
+                let rtt = client.ping().await?;
+                println!("Device reachable ({} ms)", rtt.as_millis());
+                Ok(())
+            }
         }
     }
 }
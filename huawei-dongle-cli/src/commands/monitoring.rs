@@ -1,9 +1,27 @@
 //! Monitoring CLI commands
 
-use crate::{cli::OutputFormat, output::format_output};
+use crate::{
+    cli::OutputFormat,
+    output::{csv_row, format_output},
+};
 use anyhow::Result;
 use clap::Subcommand;
-use huawei_dongle_api::Client;
+use huawei_dongle_api::{
+    models::{
+        monitoring::{MonitoringStatusView, SignalHistory},
+        MonitoringStatus,
+    },
+    Client,
+};
+
+/// Columns of the CSV status output
+const CSV_HEADER: [&str; 5] = [
+    "timestamp",
+    "connection_status",
+    "network_type",
+    "signal_level",
+    "signal_dbm",
+];
 
 #[derive(Subcommand)]
 pub enum MonitoringCommands {
@@ -16,15 +34,29 @@ pub enum MonitoringCommands {
         /// Watch interval in seconds
         #[arg(long, default_value = "5")]
         interval: u64,
+
+        /// Emit clean camelCase fields with typed values (JSON/YAML)
+        #[arg(long)]
+        clean: bool,
+
+        /// Signal samples kept for the summary printed on Ctrl+C in watch mode
+        #[arg(long, default_value = "120")]
+        history: usize,
     },
 }
 
 impl MonitoringCommands {
     pub async fn execute(&self, client: &Client, format: &OutputFormat) -> Result<()> {
         match self {
-            MonitoringCommands::Status { watch, interval } => {
+            MonitoringCommands::Status {
+                watch,
+                interval,
+                clean,
+                history,
+            } => {
                 if *watch {
-                    self.watch_status(client, format, *interval).await?;
+                    self.watch_status(client, format, *interval, *clean, *history)
+                        .await?;
                 } else {
                     let status = client.monitoring().status().await?;
 
@@ -74,8 +106,12 @@ impl MonitoringCommands {
                                 println!("Secondary DNS: {}", secondary_dns);
                             }
                         }
+                        OutputFormat::Csv => {
+                            println!("{}", csv_row(&CSV_HEADER));
+                            println!("{}", status_csv_row(&timestamp_now(), &status));
+                        }
                         _ => {
-                            format_output(&status, format)?;
+                            print_status(&status, format, *clean)?;
                         }
                     }
                 }
@@ -89,17 +125,47 @@ impl MonitoringCommands {
         client: &Client,
         format: &OutputFormat,
         interval: u64,
+        clean: bool,
+        history_capacity: usize,
     ) -> Result<()> {
-        use tokio::time::{sleep, Duration};
+        use futures_util::StreamExt;
+        use tokio::time::Duration;
+
+        let mut history = SignalHistory::new(history_capacity);
+
+        if let OutputFormat::Csv = format {
+            eprintln!("Monitoring status (Press Ctrl+C to stop)...");
+            println!("{}", csv_row(&CSV_HEADER));
+        } else {
+            println!("Monitoring status (Press Ctrl+C to stop)...\n");
+        }
 
-        println!("Monitoring status (Press Ctrl+C to stop)...\n");
+        let mut statuses = client
+            .monitoring()
+            .watch(Duration::from_secs(interval.max(1)));
 
         loop {
-            match client.monitoring().status().await {
-                Ok(status) => {
+            let next = tokio::select! {
+                next = statuses.next() => next,
+                _ = tokio::signal::ctrl_c() => {
+                    // Keep CSV/JSON on stdout parseable
+                    match format {
+                        OutputFormat::Table => println!("\n{}", signal_summary(&history)),
+                        _ => eprintln!("{}", signal_summary(&history)),
+                    }
+                    return Ok(());
+                }
+            };
+
+            match next {
+                Some(Ok(status)) => {
+                    history.record(&status);
                     let timestamp = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC");
 
                     match format {
+                        OutputFormat::Csv => {
+                            println!("{}", status_csv_row(&timestamp_now(), &status));
+                        }
                         OutputFormat::Table => {
                             println!(
                                 "[{}] {} | {} | Signal: {}/5 | SIM: {} | Service: {}",
@@ -121,18 +187,93 @@ impl MonitoringCommands {
                         }
                         _ => {
                             println!("--- {} ---", timestamp);
-                            format_output(&status, format)?;
+                            print_status(&status, format, clean)?;
                             println!();
                         }
                     }
                 }
-                Err(e) => {
+                Some(Err(e)) => {
                     let timestamp = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC");
                     eprintln!("[{}] Error fetching status: {}", timestamp, e);
                 }
+                None => return Ok(()),
             }
-
-            sleep(Duration::from_secs(interval)).await;
         }
     }
 }
+
+/// Summary of the signal readings collected in watch mode
+fn signal_summary(history: &SignalHistory) -> String {
+    if history.is_empty() {
+        return "No signal samples recorded".to_string();
+    }
+
+    let dbm = |value: Option<i32>| value.map_or("n/a".to_string(), |v| format!("{} dBm", v));
+    format!(
+        "Signal over {} samples: min {} | max {} | avg {}\n{}",
+        history.len(),
+        dbm(history.min_dbm()),
+        dbm(history.max_dbm()),
+        history
+            .avg_dbm()
+            .map_or("n/a".to_string(), |v| format!("{:.1} dBm", v)),
+        history.sparkline()
+    )
+}
+
+/// Print the raw device status, or the clean view with `--clean`
+fn print_status(status: &MonitoringStatus, format: &OutputFormat, clean: bool) -> Result<()> {
+    if clean {
+        format_output(&MonitoringStatusView::from(status), format)
+    } else {
+        format_output(status, format)
+    }
+}
+
+fn timestamp_now() -> String {
+    chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+/// One CSV line matching [`CSV_HEADER`]
+fn status_csv_row(timestamp: &str, status: &MonitoringStatus) -> String {
+    csv_row(&[
+        timestamp.to_string(),
+        status.connection_status_text(),
+        status.network_type_text(),
+        status.signal_level().map(|v| v.to_string()).unwrap_or_default(),
+        status.signal_strength_dbm().map(|v| v.to_string()).unwrap_or_default(),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_csv_row() {
+        let status = huawei_dongle_api::testing::monitoring_status("901");
+
+        assert_eq!(
+            csv_row(&CSV_HEADER),
+            "timestamp,connection_status,network_type,signal_level,signal_dbm"
+        );
+        assert_eq!(
+            status_csv_row("2024-05-01T12:00:00Z", &status),
+            "2024-05-01T12:00:00Z,CONNECTED,LTE (4G),4,-71"
+        );
+    }
+
+    #[test]
+    fn test_signal_summary() {
+        let mut history = SignalHistory::new(5);
+        assert_eq!(signal_summary(&history), "No signal samples recorded");
+
+        let now = chrono::Utc::now();
+        history.push(now, Some(-80), 3);
+        history.push(now, Some(-71), 5);
+        assert_eq!(
+            signal_summary(&history),
+            "Signal over 2 samples: min -80 dBm | max -71 dBm | avg -75.5 dBm\n▅█"
+        );
+    }
+}
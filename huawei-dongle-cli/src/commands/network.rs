@@ -4,6 +4,8 @@ use crate::{cli::OutputFormat, output::format_output};
 use anyhow::Result;
 use clap::Subcommand;
 use huawei_dongle_api::{models::{network::NetworkModeRequest, NetworkModeType}, Client};
+use std::time::Duration;
+use tabled::{builder::Builder, settings::Style};
 
 #[derive(Subcommand)]
 pub enum NetworkCommands {
@@ -30,6 +32,9 @@ pub enum NetworkCommands {
 
     /// Get current network operator (PLMN) information
     Operator,
+
+    /// Scan for available network operators (may take a minute)
+    Scan,
 }
 
 impl NetworkCommands {
@@ -63,7 +68,7 @@ impl NetworkCommands {
                 println!(
                     "Changing network mode to: {} ({})",
                     mode,
-                    request.network_mode.to_string()
+                    request.network_mode
                 );
                 println!("Warning: This will temporarily disconnect the device!");
 
@@ -71,8 +76,12 @@ impl NetworkCommands {
                 println!("Network mode changed successfully");
 
                 if *wait {
-                    println!("Note: Mode change will cause temporary disconnection");
-                    println!("You may need to wait manually for reconnection");
+                    println!("Waiting for reconnection...");
+                    let status = client
+                        .network()
+                        .wait_for_connection(Duration::from_secs(120), Duration::from_secs(2))
+                        .await?;
+                    println!("Reconnected: {}", status.network_type_text());
                 }
             }
 
@@ -94,6 +103,46 @@ impl NetworkCommands {
                     }
                 }
             }
+
+            NetworkCommands::Scan => {
+                eprintln!("Scanning for operators, this may take a minute...");
+                let list = client.network().plmn_list().await?;
+
+                if list.networks().is_empty() && matches!(format, OutputFormat::Table) {
+                    println!("No operators found");
+                    return Ok(());
+                }
+
+                match format {
+                    OutputFormat::Table => {
+                        let mut builder = Builder::default();
+                        builder.push_record(["Operator", "Numeric", "Technology", "Status"]);
+
+                        for network in list.networks() {
+                            let status = if network.is_current() {
+                                "Current"
+                            } else if network.is_forbidden() {
+                                "Forbidden"
+                            } else if network.is_available() {
+                                "Available"
+                            } else {
+                                "Unknown"
+                            };
+                            builder.push_record([
+                                network.operator_name().to_string(),
+                                network.numeric.clone(),
+                                network.rat_text().to_string(),
+                                status.to_string(),
+                            ]);
+                        }
+
+                        println!("{}", builder.build().with(Style::rounded()));
+                    }
+                    _ => {
+                        format_output(&list.networks(), format)?;
+                    }
+                }
+            }
         }
         Ok(())
     }
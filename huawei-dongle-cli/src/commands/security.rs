@@ -0,0 +1,145 @@
+//! Security CLI commands
+
+use crate::{cli::OutputFormat, output::format_output};
+use anyhow::Result;
+use clap::Subcommand;
+use huawei_dongle_api::{
+    models::{security::VirtualServer, Protocol},
+    Client,
+};
+use tabled::{builder::Builder, settings::Style};
+
+#[derive(Subcommand)]
+pub enum SecurityCommands {
+    /// Port forwarding (virtual servers)
+    Forward {
+        #[command(subcommand)]
+        command: ForwardCommands,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ForwardCommands {
+    /// List port forwarding rules
+    List,
+    /// Add a port forwarding rule
+    Add {
+        /// Rule name
+        name: String,
+
+        /// External (WAN) port
+        #[arg(long)]
+        wan_port: u16,
+
+        /// Port on the LAN host (default: same as WAN port)
+        #[arg(long)]
+        lan_port: Option<u16>,
+
+        /// LAN host IP address
+        #[arg(long)]
+        lan_ip: String,
+
+        /// Protocol (tcp, udp, both)
+        #[arg(long, default_value = "tcp")]
+        protocol: String,
+    },
+    /// Delete a port forwarding rule by name
+    Del {
+        /// Rule name
+        name: String,
+    },
+}
+
+impl SecurityCommands {
+    pub async fn execute(&self, client: &Client, format: &OutputFormat) -> Result<()> {
+        match self {
+            SecurityCommands::Forward { command } => command.execute(client, format).await,
+        }
+    }
+}
+
+impl ForwardCommands {
+    pub async fn execute(&self, client: &Client, format: &OutputFormat) -> Result<()> {
+        match self {
+            ForwardCommands::List => {
+                let servers = client.security().virtual_servers().await?;
+
+                if servers.is_empty() && matches!(format, OutputFormat::Table) {
+                    println!("No port forwarding rules");
+                    return Ok(());
+                }
+
+                match format {
+                    OutputFormat::Table => {
+                        let mut builder = Builder::default();
+                        builder.push_record(["Name", "WAN Port", "LAN IP", "LAN Port", "Protocol", "Enabled"]);
+
+                        for server in &servers {
+                            builder.push_record([
+                                server.name.clone(),
+                                server.wan_port.to_string(),
+                                server.lan_ip.clone(),
+                                server.lan_port.to_string(),
+                                server.protocol.to_string(),
+                                if server.is_enabled() { "yes" } else { "no" }.to_string(),
+                            ]);
+                        }
+
+                        println!("Port Forwarding ({} rules):", servers.len());
+                        println!("{}", builder.build().with(Style::rounded()));
+                    }
+                    _ => {
+                        format_output(&servers, format)?;
+                    }
+                }
+            }
+            ForwardCommands::Add {
+                name,
+                wan_port,
+                lan_port,
+                lan_ip,
+                protocol,
+            } => {
+                let protocol = parse_protocol(protocol)?;
+                let mut servers = client.security().virtual_servers().await?;
+
+                if servers.iter().any(|server| server.name == *name) {
+                    anyhow::bail!("Port forwarding rule '{}' already exists", name);
+                }
+
+                servers.push(VirtualServer::new(
+                    name,
+                    *wan_port,
+                    lan_port.unwrap_or(*wan_port),
+                    lan_ip,
+                    protocol,
+                ));
+
+                client.security().set_virtual_servers(&servers).await?;
+                println!("Port forwarding rule '{}' added", name);
+            }
+            ForwardCommands::Del { name } => {
+                let mut servers = client.security().virtual_servers().await?;
+                let before = servers.len();
+                servers.retain(|server| server.name != *name);
+
+                if servers.len() == before {
+                    anyhow::bail!("Port forwarding rule '{}' not found", name);
+                }
+
+                client.security().set_virtual_servers(&servers).await?;
+                println!("Port forwarding rule '{}' deleted", name);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_protocol(protocol: &str) -> Result<Protocol> {
+    match protocol.to_ascii_lowercase().as_str() {
+        "tcp" => Ok(Protocol::Tcp),
+        "udp" => Ok(Protocol::Udp),
+        "both" | "all" => Ok(Protocol::Both),
+        _ => Err(anyhow::anyhow!("Invalid protocol: {}. Valid protocols: tcp, udp, both", protocol)),
+    }
+}
@@ -1,12 +1,18 @@
 //! SMS CLI commands
 
 use crate::{cli::OutputFormat, output::format_output};
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use clap::Subcommand;
 use huawei_dongle_api::{
-    models::{sms::SmsListRequest, SmsBoxType, SmsSortType},
+    models::sms::{SmsListRequest, SmsSendRequest, SmsSendStatus},
     Client,
 };
+use std::io::Read;
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+
+/// Ask for confirmation before sending to more recipients than this
+const CONFIRM_RECIPIENTS: usize = 5;
 
 #[derive(Subcommand)]
 pub enum SmsCommands {
@@ -47,6 +53,31 @@ pub enum SmsCommands {
         /// Message ID to mark as read
         message_id: String,
     },
+
+    /// Send an SMS message
+    Send {
+        /// Recipient phone number
+        phone: String,
+
+        /// Message text (omit when using --stdin)
+        message: Option<String>,
+
+        /// Additional recipient (can be repeated)
+        #[arg(long = "to")]
+        to: Vec<String>,
+
+        /// Read the message text from stdin
+        #[arg(long)]
+        stdin: bool,
+
+        /// Force UCS2 encoding even for plain ASCII text
+        #[arg(long)]
+        unicode: bool,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        yes: bool,
+    },
 }
 
 impl SmsCommands {
@@ -89,14 +120,11 @@ impl SmsCommands {
                 unread,
                 show_content,
             } => {
-                let request = SmsListRequest::new(
-                    *page,
-                    *count,
-                    SmsBoxType::LocalInbox,
-                    SmsSortType::ByTime,
-                    false,
-                    *unread, // unread preferred if filtering for unread
-                );
+                let request = SmsListRequest::builder()
+                    .page(*page)
+                    .count(*count)
+                    .unread_preferred(*unread)
+                    .build();
 
                 let response = client.sms().list(&request).await?;
                 let mut messages = response.messages.messages;
@@ -130,7 +158,7 @@ impl SmsCommands {
                             );
 
                             if *show_content {
-                                println!("Content: {}", message.text());
+                                println!("Content: {}", message.decoded_text());
                             }
                             println!();
                         }
@@ -163,7 +191,162 @@ impl SmsCommands {
                 client.sms().mark_read(message_id).await?;
                 println!("SMS message {} marked as read", message_id);
             }
+
+            SmsCommands::Send {
+                phone,
+                message,
+                to,
+                stdin,
+                unicode,
+                yes,
+            } => {
+                let content = match (message, stdin) {
+                    (Some(_), true) => {
+                        return Err(anyhow!("Pass the message either as an argument or with --stdin, not both"));
+                    }
+                    (Some(message), false) => message.clone(),
+                    (None, true) => {
+                        let mut input = String::new();
+                        std::io::stdin().read_to_string(&mut input)?;
+                        input.trim_end().to_string()
+                    }
+                    (None, false) => return Err(anyhow!("Missing message text (or use --stdin)")),
+                };
+
+                if content.is_empty() {
+                    return Err(anyhow!("Message text is empty"));
+                }
+
+                let recipients: Vec<&str> = std::iter::once(phone.as_str())
+                    .chain(to.iter().map(String::as_str))
+                    .collect();
+
+                if !yes && recipients.len() > CONFIRM_RECIPIENTS {
+                    println!(
+                        "Are you sure you want to send this SMS to {} recipients? [y/N]",
+                        recipients.len()
+                    );
+                    let mut input = String::new();
+                    std::io::stdin().read_line(&mut input)?;
+                    if !input.trim().to_lowercase().starts_with('y') {
+                        println!("Cancelled");
+                        return Ok(());
+                    }
+                }
+
+                let result = if *unicode {
+                    send_ucs2(client, &recipients, &content).await
+                } else {
+                    client.sms().send(&recipients, &content).await
+                };
+
+                let statuses = result.map_err(|e| anyhow!("Failed to send SMS: {}", e))?;
+                for (i, status) in statuses.iter().enumerate() {
+                    let part = if statuses.len() > 1 {
+                        format!(" (part {}/{})", i + 1, statuses.len())
+                    } else {
+                        String::new()
+                    };
+                    println!(
+                        "SMS sent to {} recipient(s){}, message index: {}",
+                        recipients.len(),
+                        part,
+                        status.cur_index.as_deref().unwrap_or("unknown")
+                    );
+                }
+            }
         }
         Ok(())
     }
 }
+
+/// UTF-16 units in a single UCS2 message and in each part of a long one
+const UCS2_SINGLE_LIMIT: usize = 70;
+const UCS2_PART_LIMIT: usize = 67;
+
+/// Split `content` at the UCS2 limits, keeping surrogate pairs together
+fn ucs2_parts(content: &str) -> Vec<String> {
+    if content.encode_utf16().count() <= UCS2_SINGLE_LIMIT {
+        return vec![content.to_string()];
+    }
+
+    let mut parts = Vec::new();
+    let mut part = String::new();
+    let mut units = 0;
+    for c in content.chars() {
+        if units + c.len_utf16() > UCS2_PART_LIMIT {
+            parts.push(std::mem::take(&mut part));
+            units = 0;
+        }
+        part.push(c);
+        units += c.len_utf16();
+    }
+    parts.push(part);
+    parts
+}
+
+/// Send with the UCS2 encoding marker regardless of the content, one part
+/// at a time
+async fn send_ucs2(
+    client: &Client,
+    recipients: &[&str],
+    content: &str,
+) -> huawei_dongle_api::Result<Vec<SmsSendStatus>> {
+    let mut statuses = Vec::new();
+    for part in ucs2_parts(content) {
+        statuses.push(send_ucs2_part(client, recipients, &part).await?);
+    }
+    Ok(statuses)
+}
+
+async fn send_ucs2_part(
+    client: &Client,
+    recipients: &[&str],
+    content: &str,
+) -> huawei_dongle_api::Result<SmsSendStatus> {
+    let mut request = SmsSendRequest::new(recipients, content);
+    request.reserved = SmsSendRequest::ENCODING_UCS2.to_string();
+
+    let xml = serde_xml_rs::to_string(&request).map_err(|e| {
+        huawei_dongle_api::Error::generic(format!("Failed to serialize SMS send request: {}", e))
+    })?;
+    client.post_raw("/api/sms/send-sms", &xml).await?;
+
+    let deadline = Instant::now() + Duration::from_secs(30);
+    loop {
+        let status = client.sms().send_status().await?;
+        if status.is_failed() {
+            return Err(huawei_dongle_api::Error::generic(format!(
+                "SMS send failed for: {}",
+                status.fail_phone.as_deref().unwrap_or_default()
+            )));
+        }
+        if !status.is_pending() {
+            return Ok(status);
+        }
+        if Instant::now() >= deadline {
+            return Err(huawei_dongle_api::Error::generic("Timed out waiting for SMS send status"));
+        }
+        sleep(Duration::from_millis(500)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ucs2_parts() {
+        assert_eq!(ucs2_parts("Hello"), vec!["Hello".to_string()]);
+        assert_eq!(ucs2_parts(&"a".repeat(70)).len(), 1);
+
+        let parts = ucs2_parts(&"a".repeat(100));
+        assert_eq!(parts.iter().map(String::len).collect::<Vec<_>>(), vec![67, 33]);
+
+        // An emoji straddling the part limit moves to the next part whole
+        let content = format!("{}😀", "a".repeat(66));
+        let parts = ucs2_parts(&format!("{}{}", content, "b".repeat(10)));
+        assert_eq!(parts[0], "a".repeat(66));
+        assert!(parts[1].starts_with('😀'));
+    }
+}
@@ -0,0 +1,53 @@
+//! WLAN CLI commands
+
+use crate::{cli::OutputFormat, output::format_output};
+use anyhow::Result;
+use clap::Subcommand;
+use huawei_dongle_api::Client;
+use tabled::{builder::Builder, settings::Style};
+
+#[derive(Subcommand)]
+pub enum WlanCommands {
+    /// List connected WiFi clients
+    Clients,
+}
+
+impl WlanCommands {
+    pub async fn execute(&self, client: &Client, format: &OutputFormat) -> Result<()> {
+        match self {
+            WlanCommands::Clients => {
+                let list = client.wlan().host_list().await?;
+
+                if list.hosts().is_empty() {
+                    println!("No WiFi clients connected");
+                    return Ok(());
+                }
+
+                match format {
+                    OutputFormat::Table => {
+                        let mut builder = Builder::default();
+                        builder.push_record(["MAC Address", "IP Address", "Host Name", "Connected", "SSID"]);
+
+                        for host in list.hosts() {
+                            let secs = host.associated_duration().as_secs();
+                            builder.push_record([
+                                host.mac_address.clone(),
+                                host.ip_address.clone(),
+                                host.host_name.clone(),
+                                format!("{}h {}m {}s", secs / 3600, (secs % 3600) / 60, secs % 60),
+                                host.associated_ssid.clone().unwrap_or_default(),
+                            ]);
+                        }
+
+                        println!("WiFi Clients ({} active):", list.active_count());
+                        println!("{}", builder.build().with(Style::rounded()));
+                    }
+                    _ => {
+                        format_output(&list.hosts(), format)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
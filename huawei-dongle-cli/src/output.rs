@@ -16,9 +16,10 @@ where
             println!("{}", json);
         }
         OutputFormat::Yaml => {
-            // For now, use JSON format as yaml isn't in dependencies
-            let json = serde_json::to_string_pretty(data)?;
-            println!("{}", json);
+            print!("{}", serde_yaml::to_string(data)?);
+        }
+        OutputFormat::Csv => {
+            print_csv_from_json(&serde_json::to_value(data)?);
         }
         OutputFormat::Table => {
             // Convert to JSON value for table formatting
@@ -66,3 +67,114 @@ fn print_table_from_json(value: &serde_json::Value) {
         }
     }
 }
+
+/// Quote a CSV field if it contains a separator, quote or line break
+pub fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Join fields into a single CSV line
+pub fn csv_row<S: AsRef<str>>(fields: &[S]) -> String {
+    fields
+        .iter()
+        .map(|field| csv_field(field.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Print an object as header plus one row, or an array of objects as header plus one row each
+fn print_csv_from_json(value: &serde_json::Value) {
+    let rows: Vec<&serde_json::Map<String, serde_json::Value>> = match value {
+        serde_json::Value::Object(map) => vec![map],
+        serde_json::Value::Array(items) => items.iter().filter_map(|item| item.as_object()).collect(),
+        _ => {
+            println!("{}", csv_field(&csv_value(value)));
+            return;
+        }
+    };
+
+    let Some(first) = rows.first() else {
+        return;
+    };
+    let header: Vec<&String> = first.keys().collect();
+    println!("{}", csv_row(&header));
+
+    for row in rows {
+        let fields: Vec<String> = header
+            .iter()
+            .map(|key| row.get(*key).map(csv_value).unwrap_or_default())
+            .collect();
+        println!("{}", csv_row(&fields));
+    }
+}
+
+fn csv_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Null => String::new(),
+        _ => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct Sample {
+        name: String,
+        index: String,
+        count: u32,
+        connected: bool,
+        note: Option<String>,
+        tags: Vec<String>,
+        nested: Nested,
+    }
+
+    #[derive(Serialize)]
+    struct Nested {
+        content: String,
+        empty: Vec<u8>,
+    }
+
+    #[test]
+    fn test_yaml_output_round_trips() {
+        let sample = Sample {
+            name: "LTE (4G)".to_string(),
+            index: "40001".to_string(),
+            count: 3,
+            connected: true,
+            note: None,
+            tags: vec!["inbox".to_string(), "yes".to_string()],
+            nested: Nested {
+                content: "Balance: 10.00 PLN\n#1".to_string(),
+                empty: Vec::new(),
+            },
+        };
+
+        let yaml = serde_yaml::to_string(&sample).unwrap();
+        let parsed: serde_json::Value = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed, serde_json::to_value(&sample).unwrap());
+    }
+
+    #[test]
+    fn test_csv_quoting() {
+        assert_eq!(csv_field("LTE"), "LTE");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_row(&["1", "x,y", ""]), "1,\"x,y\",");
+    }
+
+    #[test]
+    fn test_yaml_sequence_of_mappings_round_trips() {
+        let value = serde_json::json!([{"id": "1", "read": false}, {"id": "2", "read": true}]);
+        let yaml = serde_yaml::to_string(&value).unwrap();
+        let parsed: serde_json::Value = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed, value);
+    }
+}